@@ -0,0 +1,88 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use clap::Parser;
+use dsi_bitstream::prelude::*;
+use lender::*;
+use std::hint::black_box;
+use std::path::PathBuf;
+use webgraph::prelude::*;
+
+#[derive(Parser, Debug)]
+#[command(about = "Compares the sequential scan speed of a graph with and without sorting every successor list, as the decoder did before sorting only lists made of more than one sorted run.", long_about = None)]
+struct Args {
+    /// The basename of the graph.
+    basename: PathBuf,
+    /// The number of repeats.
+    #[arg(short = 'R', long, default_value = "10")]
+    repeats: usize,
+}
+
+fn bench_impl<E: Endianness + 'static>(args: Args) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.basename)
+        .endianness::<E>()
+        .load()?;
+    let num_arcs = graph.num_arcs_hint().unwrap() as f64;
+    let mut buf = vec![];
+
+    for _ in 0..args.repeats {
+        // The decoder as it is, sorting only when needed
+        let start = std::time::Instant::now();
+        let mut iter = graph.iter();
+        while let Some((_, succ)) = iter.next() {
+            buf.clear();
+            buf.extend(succ);
+            black_box(&buf);
+        }
+        println!(
+            "Sort when needed:{:>20} ns/arc",
+            start.elapsed().as_secs_f64() / num_arcs * 1e9
+        );
+
+        // The decoder plus an unconditional sort of each list, which
+        // reproduces the cost of the previous behavior
+        let start = std::time::Instant::now();
+        let mut iter = graph.iter();
+        while let Some((_, succ)) = iter.next() {
+            buf.clear();
+            buf.extend(succ);
+            buf.sort();
+            black_box(&buf);
+        }
+        println!(
+            "Always sort:     {:>20} ns/arc",
+            start.elapsed().as_secs_f64() / num_arcs * 1e9
+        );
+    }
+
+    Ok(())
+}
+
+pub fn main() -> Result<()> {
+    let args = Args::parse();
+
+    env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .try_init()?;
+
+    match get_endianness(&args.basename)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => bench_impl::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => bench_impl::<LE>(args),
+        e => panic!("Unknown endianness: {}", e),
+    }
+}
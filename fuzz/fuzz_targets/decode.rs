@@ -0,0 +1,5 @@
+#![no_main]
+use webgraph::fuzz::decode::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: FuzzCase| harness(data));
@@ -20,11 +20,32 @@ pub struct BfsOrder<'a, G: RandomAccessGraph> {
     /// This allows initializing the BFS from all orphan nodes without reading
     /// the reverse graph.
     start: usize,
+    /// The node from which the visit starts, if it has not been used yet.
+    seed: Option<usize>,
 }
 
 impl<'a, G: RandomAccessGraph> BfsOrder<'a, G> {
     pub fn new(graph: &G) -> BfsOrder<G> {
+        Self::new_from(graph, 0)
+    }
+
+    /// Creates a BFS visit starting from `seed`.
+    ///
+    /// Once the nodes reachable from `seed` have been exhausted, the visit
+    /// is resumed from the smallest node not yet seen, so that all nodes are
+    /// returned exactly once.
+    ///
+    /// # Panics
+    ///
+    /// If `seed` is not a node of the graph.
+    pub fn new_from(graph: &G, seed: usize) -> BfsOrder<'_, G> {
         let num_nodes = graph.num_nodes();
+        assert!(
+            seed < num_nodes || num_nodes == 0,
+            "Seed node {} does not exist (the graph has {} nodes)",
+            seed,
+            num_nodes
+        );
         let mut pl = ProgressLogger::default();
         pl.display_memory(true)
             .item_name("node")
@@ -37,6 +58,7 @@ impl<'a, G: RandomAccessGraph> BfsOrder<'a, G> {
             seen: BitVec::new(num_nodes),
             queue: VecDeque::new(),
             start: 0,
+            seed: (num_nodes > 0).then_some(seed),
         }
     }
 }
@@ -47,6 +69,11 @@ impl<'a, G: RandomAccessGraph> Iterator for BfsOrder<'a, G> {
     fn next(&mut self) -> Option<usize> {
         self.pl.light_update();
         let current_node = match self.queue.pop_front() {
+            None if self.seed.is_some() => {
+                let seed = self.seed.take().unwrap();
+                self.seen.set(seed, true);
+                seed
+            }
             None => {
                 while self.seen[self.start] {
                     self.start += 1;
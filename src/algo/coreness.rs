@@ -0,0 +1,87 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::RandomAccessGraph;
+use dsi_progress_logger::prelude::*;
+use lender::prelude::*;
+
+/// Computes the coreness (a.k.a. core number) of each node of an undirected
+/// graph.
+///
+/// The `k`-core of a graph is its maximal subgraph in which all nodes have
+/// degree at least `k`, and the coreness of a node is the largest `k` such
+/// that the node belongs to the `k`-core.
+///
+/// The graph must be symmetric and must not contain duplicate arcs; directed
+/// graphs should be [symmetrized](crate::transform::symmetrize) first.
+/// Self-loops are ignored.
+///
+/// This is the bucket-based peeling algorithm by Batagelj and Zaveršnik,
+/// which needs time linear in the number of arcs and four integers of memory
+/// per node, besides the graph: nodes are removed in order of current degree
+/// using a bucket sort kept up to date in constant time per arc.
+pub fn coreness(graph: &impl RandomAccessGraph) -> Vec<usize> {
+    let num_nodes = graph.num_nodes();
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Computing degrees...");
+
+    let mut deg = vec![0; num_nodes];
+    for_!( (node, succ) in graph.iter() {
+        deg[node] = succ.into_iter().filter(|&succ| succ != node).count();
+        pl.light_update();
+    });
+    pl.done();
+
+    // Sort nodes by degree, recording for each degree where its bucket starts
+    let max_deg = deg.iter().copied().max().unwrap_or(0);
+    let mut bin = vec![0; max_deg + 1];
+    for &d in &deg {
+        bin[d] += 1;
+    }
+    let mut start = 0;
+    for b in bin.iter_mut() {
+        let count = *b;
+        *b = start;
+        start += count;
+    }
+    let mut pos = vec![0; num_nodes];
+    let mut vert = vec![0; num_nodes];
+    for (node, &d) in deg.iter().enumerate() {
+        pos[node] = bin[d];
+        vert[pos[node]] = node;
+        bin[d] += 1;
+    }
+    bin.rotate_right(1);
+    bin[0] = 0;
+
+    pl.start("Peeling...");
+    for i in 0..num_nodes {
+        let node = vert[i];
+        for succ in graph.successors(node) {
+            if deg[succ] > deg[node] {
+                // Move succ to the beginning of its bucket, and then shift
+                // the bucket boundary past it, decreasing its degree
+                let d = deg[succ];
+                let first = vert[bin[d]];
+                if succ != first {
+                    vert.swap(pos[succ], bin[d]);
+                    pos[first] = pos[succ];
+                    pos[succ] = bin[d];
+                }
+                bin[d] += 1;
+                deg[succ] -= 1;
+            }
+        }
+        pl.light_update();
+    }
+    pl.done();
+
+    deg
+}
@@ -0,0 +1,105 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::RandomAccessGraph;
+use dsi_progress_logger::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+
+/// A lower bound on the diameter of a graph, as returned by
+/// [`diameter_lower_bound`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiameterBound {
+    /// The lower bound, that is, the distance from `source` to `target`.
+    pub lower_bound: usize,
+    /// The source of a shortest path of length `lower_bound`.
+    pub source: usize,
+    /// The target of a shortest path of length `lower_bound`.
+    pub target: usize,
+}
+
+/// Visits the graph in breadth-first order starting from `source`, and
+/// returns the farthest node reachable from it together with its distance.
+///
+/// `dist` is used to mark visited nodes, and it is reset before returning.
+fn farthest(graph: &impl RandomAccessGraph, source: usize, dist: &mut [usize]) -> (usize, usize) {
+    let mut queue = VecDeque::new();
+    let mut visited = vec![source];
+    let mut far = (source, 0);
+    dist[source] = 0;
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        let d = dist[node];
+        if d > far.1 {
+            far = (node, d);
+        }
+        for succ in graph.successors(node) {
+            if dist[succ] == usize::MAX {
+                dist[succ] = d + 1;
+                visited.push(succ);
+                queue.push_back(succ);
+            }
+        }
+    }
+    for node in visited {
+        dist[node] = usize::MAX;
+    }
+    far
+}
+
+/// Computes a lower bound on the diameter of a graph using `num_sweeps`
+/// double sweeps, or returns `None` if the graph has no nodes.
+///
+/// Each double sweep performs a breadth-first visit from a random node to
+/// find a farthest node `source`, and then a breadth-first visit from
+/// `source` to find a farthest node `target`: the distance from `source` to
+/// `target` is a lower bound on the diameter, which is often tight in
+/// practice, and so is the distance from the random node to `source`. The
+/// best bound over all sweeps is returned. Distances are computed following
+/// the arcs of the graph, so on a directed graph this is a lower bound on
+/// the largest finite distance.
+///
+/// The result depends only on the graph, on `num_sweeps`, and on `seed`.
+pub fn diameter_lower_bound(
+    graph: &impl RandomAccessGraph,
+    num_sweeps: usize,
+    seed: u64,
+) -> Option<DiameterBound> {
+    let num_nodes = graph.num_nodes();
+    if num_nodes == 0 {
+        return None;
+    }
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut dist = vec![usize::MAX; num_nodes];
+    let mut best: Option<DiameterBound> = None;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("sweep")
+        .expected_updates(Some(num_sweeps.max(1)));
+    pl.start("Computing double sweeps...");
+
+    for _ in 0..num_sweeps.max(1) {
+        let start = rng.gen_range(0..num_nodes);
+        let (source, first) = farthest(graph, start, &mut dist);
+        let (target, second) = farthest(graph, source, &mut dist);
+        // on directed graphs the first visit might give a better bound
+        for (lower_bound, source, target) in [(first, start, source), (second, source, target)] {
+            if best.is_none_or(|best| lower_bound > best.lower_bound) {
+                best = Some(DiameterBound {
+                    lower_bound,
+                    source,
+                    target,
+                });
+            }
+        }
+        pl.update();
+    }
+    pl.done();
+
+    best
+}
@@ -0,0 +1,34 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::SequentialGraph;
+use dsi_progress_logger::prelude::*;
+use lender::prelude::*;
+
+/// Returns the indegree of each node of a graph.
+///
+/// The arcs are scanned once, incrementing a counter for each destination,
+/// which is much cheaper than [transposing](crate::transform::transpose) the
+/// graph and computing its outdegrees. The counters are kept in memory, so
+/// this function needs a `usize` per node.
+pub fn in_degrees(graph: &impl SequentialGraph) -> Vec<usize> {
+    let mut in_degrees = vec![0; graph.num_nodes()];
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(graph.num_nodes()));
+    pl.start("Computing indegrees...");
+    for_!( (_node, succ) in graph.iter() {
+        for dst in succ {
+            in_degrees[dst] += 1;
+        }
+        pl.light_update();
+    });
+    pl.done();
+
+    in_degrees
+}
@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::LabeledSequentialGraph;
+use lender::prelude::*;
+
+/// Summary statistics on the labels of the arcs of a graph, as returned by
+/// [`label_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelStats {
+    /// The number of arcs.
+    pub count: u64,
+    /// The smallest value.
+    pub min: f64,
+    /// The largest value.
+    pub max: f64,
+    /// The mean of the values.
+    pub mean: f64,
+}
+
+/// Computes the number of arcs and the minimum, maximum and mean of the
+/// values associated with their labels by `value`, or returns `None` if the
+/// graph has no arcs.
+///
+/// The arcs are scanned once, in the order of the graph. If some value is
+/// NaN, the mean is NaN, whereas the minimum and the maximum are those of
+/// the other values.
+pub fn label_stats<L>(
+    graph: &impl LabeledSequentialGraph<L>,
+    mut value: impl FnMut(L) -> f64,
+) -> Option<LabelStats> {
+    let mut count = 0;
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut sum = 0.0;
+    for_!( (_src, succ) in graph.iter() {
+        for (_dst, label) in succ {
+            let value = value(label);
+            count += 1;
+            min = min.min(value);
+            max = max.max(value);
+            sum += value;
+        }
+    });
+
+    if count == 0 {
+        return None;
+    }
+    Some(LabelStats {
+        count,
+        min,
+        max,
+        mean: sum / count as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Payload(f64);
+
+    #[test]
+    fn test_label_stats() {
+        let graph = VecGraph::from_labeled_arc_list([
+            (0, 1, Payload(1.0)),
+            (0, 2, Payload(-2.0)),
+            (1, 2, Payload(4.5)),
+            (3, 0, Payload(0.5)),
+        ]);
+        assert_eq!(
+            label_stats(&graph, |Payload(x)| x),
+            Some(LabelStats {
+                count: 4,
+                min: -2.0,
+                max: 4.5,
+                mean: 1.0
+            })
+        );
+        assert_eq!(
+            label_stats(&VecGraph::<Payload>::empty(3), |Payload(x)| x),
+            None
+        );
+    }
+}
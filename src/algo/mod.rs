@@ -10,5 +10,34 @@
 mod bfs_order;
 pub use bfs_order::BfsOrder;
 
+mod coreness;
+pub use coreness::coreness;
+
+mod diameter;
+pub use diameter::{diameter_lower_bound, DiameterBound};
+
+mod in_degrees;
+pub use in_degrees::in_degrees;
+
+mod label_stats;
+pub use label_stats::{label_stats, LabelStats};
+
+mod reachable;
+pub use reachable::{reachable_from, reachable_from_any};
+
+mod sample_arcs;
+pub use sample_arcs::sample_arcs;
+
+mod self_loops;
+pub use self_loops::count_self_loops;
+
+mod triangles;
+pub use triangles::count_triangles;
+
+mod wcc;
+pub use wcc::{
+    component_sizes, large_components, partition_by_component, partition_large_components, wcc,
+};
+
 pub mod llp;
 pub use llp::*;
@@ -0,0 +1,76 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::RandomAccessGraph;
+use dsi_progress_logger::prelude::*;
+use sux::prelude::BitVec;
+
+/// Returns the set of nodes reachable from `seed` by a directed path, and
+/// its cardinality.
+///
+/// The set is returned as a bit vector of length `graph.num_nodes()`, and it
+/// always contains `seed`. See [`reachable_from_any`] for details.
+///
+/// # Panics
+///
+/// If `seed` is not a node of the graph.
+pub fn reachable_from(graph: &impl RandomAccessGraph, seed: usize) -> (BitVec, usize) {
+    reachable_from_any(graph, [seed])
+}
+
+/// Returns the set of nodes reachable from any of the given `seeds` by a
+/// directed path, and its cardinality.
+///
+/// The set is returned as a bit vector of length `graph.num_nodes()`, and it
+/// always contains the seeds. The nodes are visited in depth-first order
+/// using an explicit stack, so the additional memory is proportional to the
+/// number of reached nodes in the worst case.
+///
+/// # Panics
+///
+/// If a seed is not a node of the graph.
+pub fn reachable_from_any(
+    graph: &impl RandomAccessGraph,
+    seeds: impl IntoIterator<Item = usize>,
+) -> (BitVec, usize) {
+    let num_nodes = graph.num_nodes();
+    let mut reached = BitVec::new(num_nodes);
+    let mut count = 0;
+    let mut stack = vec![];
+
+    for seed in seeds {
+        assert!(
+            seed < num_nodes,
+            "Seed node {} does not exist (the graph has {} nodes)",
+            seed,
+            num_nodes
+        );
+        if !reached[seed] {
+            reached.set(seed, true);
+            count += 1;
+            stack.push(seed);
+        }
+    }
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Computing reachable nodes...");
+    while let Some(node) = stack.pop() {
+        for succ in graph.successors(node) {
+            if !reached[succ] {
+                reached.set(succ, true);
+                count += 1;
+                stack.push(succ);
+            }
+        }
+        pl.light_update();
+    }
+    pl.done();
+
+    (reached, count)
+}
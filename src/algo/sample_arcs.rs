@@ -0,0 +1,54 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::SequentialGraph;
+use dsi_progress_logger::prelude::*;
+use lender::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+/// Samples uniformly at random `k` arcs of a graph in a single sequential
+/// pass.
+///
+/// The arcs are streamed once from
+/// [`iter`](crate::traits::SequentialLabeling::iter) using reservoir
+/// sampling (Vitter's Algorithm R), so memory usage is proportional to `k`
+/// and not to the number of arcs. Each subset of `k` arcs has the same
+/// probability of being returned; if the graph has fewer than `k` arcs, all
+/// arcs are returned.
+///
+/// The arcs are returned in the order of the reservoir, which is not
+/// sorted. The result depends only on the graph, on `k`, and on `seed`.
+pub fn sample_arcs(graph: &impl SequentialGraph, k: usize, seed: u64) -> Vec<(usize, usize)> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut reservoir = Vec::with_capacity(k);
+    let mut seen = 0_u64;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(graph.num_nodes()));
+    pl.start("Sampling arcs...");
+
+    for_!( (src, succ) in graph.iter() {
+        for dst in succ {
+            if reservoir.len() < k {
+                reservoir.push((src, dst));
+            } else {
+                // Replace an element with probability k / (seen + 1)
+                let j = rng.gen_range(0..=seen);
+                if j < k as u64 {
+                    reservoir[j as usize] = (src, dst);
+                }
+            }
+            seen += 1;
+        }
+        pl.light_update();
+    });
+    pl.done();
+
+    reservoir
+}
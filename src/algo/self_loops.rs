@@ -0,0 +1,35 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::SequentialGraph;
+use dsi_progress_logger::prelude::*;
+use lender::prelude::*;
+
+/// Returns the number of nodes with a self-loop and the number of
+/// self-loops of a graph, that is, of arcs of the form `(v, v)`.
+///
+/// Duplicate self-loops are counted with their multiplicity, so the two
+/// numbers are equal on a graph without duplicate arcs (e.g., a
+/// [`BVGraph`](crate::graphs::bvgraph::BVGraph)). The graph is scanned once.
+pub fn count_self_loops(graph: &impl SequentialGraph) -> (usize, usize) {
+    let mut nodes = 0;
+    let mut self_loops = 0;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(graph.num_nodes()));
+    pl.start("Counting self-loops...");
+    for_!( (node, succ) in graph.iter() {
+        let count = succ.into_iter().filter(|&dst| dst == node).count();
+        nodes += (count != 0) as usize;
+        self_loops += count;
+        pl.light_update();
+    });
+    pl.done();
+
+    (nodes, self_loops)
+}
@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::SequentialGraph;
+use dsi_progress_logger::prelude::*;
+use lender::prelude::*;
+
+/// Counts the triangles of an undirected graph.
+///
+/// The graph must be symmetric, loopless or not, with sorted successors and
+/// no duplicate arcs (e.g., the result of
+/// [`simplify`](crate::transform::simplify)); self-loops are ignored.
+///
+/// This is the node-iterator algorithm adapted to a single sequential pass:
+/// when visiting node `u`, the successors `v < u` of `u` are intersected, by
+/// a linear merge of sorted lists, with the successors `w < v` of `v`, which
+/// were stored when `v` was visited. Each triangle `w < v < u` is thus
+/// counted exactly once, and memory usage is proportional to the number of
+/// arcs.
+///
+/// Returns the number of triangles and, if `per_node` is true, a vector
+/// containing for each node the number of triangles it belongs to.
+pub fn count_triangles(graph: &impl SequentialGraph, per_node: bool) -> (u64, Option<Vec<u64>>) {
+    let num_nodes = graph.num_nodes();
+    // The successors smaller than each visited node, concatenated
+    let mut lower = Vec::new();
+    let mut offsets = Vec::with_capacity(num_nodes + 1);
+    offsets.push(0);
+    let mut counts = if per_node {
+        Some(vec![0; num_nodes])
+    } else {
+        None
+    };
+    let mut triangles = 0;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Counting triangles...");
+
+    for_!( (u, succ) in graph.iter() {
+        debug_assert_eq!(u, offsets.len() - 1);
+        let start = lower.len();
+        lower.extend(succ.into_iter().take_while(|&v| v < u));
+        let (prev, cur) = lower.split_at(start);
+
+        for &v in cur {
+            let mut other = prev[offsets[v]..offsets[v + 1]].iter().peekable();
+            for &w in cur.iter().take_while(|&&w| w < v) {
+                while other.next_if(|&&x| x < w).is_some() {}
+                if other.next_if_eq(&&w).is_some() {
+                    triangles += 1;
+                    if let Some(counts) = &mut counts {
+                        counts[u] += 1;
+                        counts[v] += 1;
+                        counts[w] += 1;
+                    }
+                }
+            }
+        }
+
+        offsets.push(lower.len());
+        pl.light_update();
+    });
+    pl.done();
+
+    (triangles, counts)
+}
@@ -0,0 +1,146 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::SequentialGraph;
+use dsi_progress_logger::prelude::*;
+use lender::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Returns the root of the tree containing `node`, halving the path on the way.
+#[inline(always)]
+fn find(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+/// Computes the weakly connected components of a graph.
+///
+/// The arcs are streamed once from [`iter`](crate::traits::SequentialLabeling::iter)
+/// into a union-find structure, so neither the transpose nor a symmetrized
+/// version of the graph is needed.
+///
+/// The returned vector maps each node to the representative of its component,
+/// which is the smallest node of the component: two nodes are in the same
+/// component if and only if they have the same representative, and the result
+/// does not depend on the order in which arcs are enumerated.
+pub fn wcc(graph: &impl SequentialGraph) -> Vec<usize> {
+    let num_nodes = graph.num_nodes();
+    let mut parent: Vec<usize> = (0..num_nodes).collect();
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Computing weakly connected components...");
+
+    for_!( (src, succ) in graph.iter() {
+        for dst in succ {
+            let (a, b) = (find(&mut parent, src), find(&mut parent, dst));
+            // Always link below the smaller root, so that roots are minima
+            if a < b {
+                parent[b] = a;
+            } else if b < a {
+                parent[a] = b;
+            }
+        }
+        pl.light_update();
+    });
+    pl.done();
+
+    for node in 0..num_nodes {
+        // Parents precede their children, so this is already the root
+        parent[node] = parent[parent[node]];
+    }
+    parent
+}
+
+/// Returns the pairs `(representative, size)` of the components described by
+/// `labels`, as returned by [`wcc`], in decreasing order of size.
+///
+/// Components of the same size are sorted by representative, so the first
+/// pair, if any, describes the giant component.
+pub fn component_sizes(labels: &[usize]) -> Vec<(usize, usize)> {
+    let mut sizes = vec![0_usize; labels.len()];
+    for &label in labels {
+        sizes[label] += 1;
+    }
+    let mut sizes: Vec<_> = sizes
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, size)| size != 0)
+        .collect();
+    sizes.sort_by(|(a, size_a), (b, size_b)| size_b.cmp(size_a).then(a.cmp(b)));
+    sizes
+}
+
+/// Returns a vector telling, for each node, whether its component, as
+/// described by `labels`, has at least `min_size` nodes.
+///
+/// The result can be passed to
+/// [`induced_subgraph`](crate::transform::induced_subgraph) to restrict a
+/// graph to its large components.
+pub fn large_components(labels: &[usize], min_size: usize) -> Vec<bool> {
+    let mut sizes = vec![0_usize; labels.len()];
+    for &label in labels {
+        sizes[label] += 1;
+    }
+    labels
+        .iter()
+        .map(|&label| sizes[label] >= min_size)
+        .collect()
+}
+
+/// Partitions the nodes in `num_shards` shards without splitting components.
+///
+/// `labels` describes the components, as returned by [`wcc`]. Components are
+/// assigned greedily, in the order of [`component_sizes`] (i.e., by
+/// decreasing size, and then by representative), each to the shard with the
+/// fewest nodes so far (the one with the smallest index in case of ties).
+/// This is the classical longest-processing-time heuristic, which keeps the
+/// largest shard within 4/3 of the optimum; of course, no partition can be
+/// better balanced than the giant component allows.
+///
+/// Returns a vector mapping each node to its shard. The result depends only
+/// on `labels` and `num_shards`.
+///
+/// # Panics
+///
+/// If `num_shards` is zero.
+pub fn partition_by_component(labels: &[usize], num_shards: usize) -> Vec<usize> {
+    partition_large_components(labels, num_shards, 0)
+}
+
+/// Partitions in `num_shards` shards the nodes of the components with at
+/// least `min_size` nodes, without splitting components.
+///
+/// Components are assigned as in [`partition_by_component`]; nodes of
+/// smaller components are mapped to `usize::MAX`.
+///
+/// # Panics
+///
+/// If `num_shards` is zero.
+pub fn partition_large_components(
+    labels: &[usize],
+    num_shards: usize,
+    min_size: usize,
+) -> Vec<usize> {
+    assert!(num_shards > 0, "The number of shards must be positive");
+    let mut shard_of = vec![usize::MAX; labels.len()];
+    let mut loads: BinaryHeap<_> = (0..num_shards).map(|shard| Reverse((0, shard))).collect();
+    for (label, size) in component_sizes(labels)
+        .into_iter()
+        .take_while(|&(_, size)| size >= min_size)
+    {
+        let Reverse((load, shard)) = loads.pop().unwrap();
+        shard_of[label] = shard;
+        loads.push(Reverse((load + size, shard)));
+    }
+    labels.iter().map(|&label| shard_of[label]).collect()
+}
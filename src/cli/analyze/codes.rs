@@ -6,7 +6,7 @@
  */
 
 use crate::prelude::*;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{ArgMatches, Args, Command, FromArgMatches};
 use dsi_bitstream::prelude::*;
 use dsi_progress_logger::prelude::*;
@@ -20,6 +20,11 @@ pub const COMMAND_NAME: &str = "codes";
 pub struct CliArgs {
     /// The basename of the graph.
     pub src: PathBuf,
+
+    #[arg(short, long)]
+    /// Print instead, as TSV, the bits currently spent on each field of the
+    /// graph with the codes it was compressed with.
+    pub tsv: bool,
 }
 
 pub fn cli(command: Command) -> Command {
@@ -40,7 +45,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => optimize_codes::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
@@ -48,8 +53,10 @@ pub fn optimize_codes<E: Endianness + 'static>(args: CliArgs) -> Result<()>
 where
     for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
 {
+    let properties = GraphProperties::load(args.src.with_extension(PROPERTIES_EXTENSION))?;
+
     // TODO!: speed it up by using random access graph if possible
-    let graph = BVGraphSeq::with_basename(args.src)
+    let graph = BVGraphSeq::with_basename(&args.src)
         .endianness::<E>()
         .load()?
         .map_factory(StatsDecoderFactory::new);
@@ -70,6 +77,10 @@ where
     drop(iter); // This releases the decoder and updates the global stats
     let stats = graph.into_inner().stats();
 
+    if args.tsv {
+        return print_field_bits(&stats, &properties);
+    }
+
     macro_rules! impl_best_code {
         ($new_bits:expr, $old_bits:expr, $stats:expr, $($code:ident - $old:expr),*) => {
             println!("{:>17} {:>16} {:>12} {:>8} {:>10} {:>16}",
@@ -130,6 +141,69 @@ where
     Ok(())
 }
 
+/// Returns the number of bits used by a stream with the given code, or an
+/// error if the code is not tracked by [`CodesStats`].
+fn code_bits(stats: &CodesStats, code: Code) -> Result<u64> {
+    match code {
+        Code::Unary => Ok(stats.unary),
+        Code::Gamma => Ok(stats.gamma),
+        Code::Delta => Ok(stats.delta),
+        Code::Zeta { k } => k
+            .checked_sub(1)
+            .and_then(|i| stats.zeta.get(i))
+            .copied()
+            .with_context(|| {
+                format!(
+                    "ζ{} codes are not supported: k must be between 1 and {}",
+                    k,
+                    stats.zeta.len()
+                )
+            }),
+    }
+}
+
+/// Prints as TSV the bits used by each field with the codes of the graph,
+/// in total and per arc.
+fn print_field_bits(stats: &DecoderStats, properties: &GraphProperties) -> Result<()> {
+    let cf = &properties.comp_flags;
+    let fields = [
+        ("outdegrees", code_bits(&stats.outdegrees, cf.outdegrees)?),
+        (
+            "references",
+            code_bits(&stats.reference_offsets, cf.references)?,
+        ),
+        (
+            "blocks",
+            code_bits(&stats.block_counts, cf.blocks)? + code_bits(&stats.blocks, cf.blocks)?,
+        ),
+        (
+            "intervals",
+            code_bits(&stats.interval_counts, cf.intervals)?
+                + code_bits(&stats.interval_starts, cf.intervals)?
+                + code_bits(&stats.interval_lens, cf.intervals)?,
+        ),
+        (
+            "residuals",
+            code_bits(&stats.first_residuals, cf.residuals)?
+                + code_bits(&stats.residuals, cf.residuals)?,
+        ),
+    ];
+    let total = fields.iter().map(|(_, bits)| bits).sum::<u64>();
+    let num_arcs = properties.num_arcs.max(1) as f64;
+
+    println!("field\tbits\tfraction\tbits/arc");
+    for (field, bits) in fields.iter().chain([("total", total)].iter()) {
+        println!(
+            "{}\t{}\t{:.6}\t{:.3}",
+            field,
+            bits,
+            *bits as f64 / total.max(1) as f64,
+            *bits as f64 / num_arcs
+        );
+    }
+    Ok(())
+}
+
 fn normalize(mut value: f64) -> String {
     let mut uom = ' ';
     if value > 1000.0 {
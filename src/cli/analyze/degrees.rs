@@ -0,0 +1,176 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{ensure, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "degrees";
+
+#[derive(Args, Debug)]
+#[command(about = "Prints statistics and the distribution of the outdegrees (or indegrees) of a graph as TSV. Statistics are printed first, on lines starting with '#', followed by a histogram with the smallest degree, the largest degree and the number of nodes of each bin.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    #[arg(short, long)]
+    /// Use powers-of-two bins for all degrees.
+    pub log_bins: bool,
+
+    #[arg(short, long, default_value_t = 1 << 16)]
+    /// Degrees smaller than this threshold have a bin of their own; larger
+    /// degrees are grouped in powers-of-two bins.
+    pub threshold: usize,
+
+    #[arg(short, long)]
+    /// Use indegrees instead of outdegrees; they are computed by a scan of the
+    /// arcs, which needs a counter per node in memory.
+    pub indegree: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => degrees::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => degrees::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// The minimum number of degrees that are counted exactly, independently of
+/// the binning.
+const MIN_EXACT: usize = 1 << 16;
+
+/// Returns the smallest and largest degree of the bin containing `degree`.
+///
+/// Degree zero and degrees smaller than `threshold` have a bin of their own;
+/// the other degrees are grouped by their most significant bit, with bins
+/// starting no earlier than `threshold`.
+fn bin(degree: usize, threshold: usize) -> (usize, usize) {
+    if degree < threshold.max(1) {
+        return (degree, degree);
+    }
+    let log = degree.ilog2();
+    let upper = if log == usize::BITS - 1 {
+        usize::MAX
+    } else {
+        (1 << (log + 1)) - 1
+    };
+    ((1 << log).max(threshold), upper)
+}
+
+pub fn degrees<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let threshold = if args.log_bins { 0 } else { args.threshold };
+    // Degrees are counted exactly up to this bound even when using
+    // powers-of-two bins, so that the larger degrees stored below are few
+    let exact = args.threshold.max(MIN_EXACT);
+
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+    let num_nodes = graph.num_nodes();
+    ensure!(num_nodes > 0, "The graph has no nodes");
+
+    // Exact counts for degrees below the bound, and the larger degrees
+    // themselves, which are at most num_arcs / exact, so that the
+    // statistics are exact
+    let mut counts = vec![0_u64; exact.min(num_nodes + 1)];
+    let mut large = vec![];
+    let mut num_arcs = 0_u64;
+
+    let mut record = |degree: usize| {
+        match counts.get_mut(degree) {
+            Some(count) => *count += 1,
+            None => large.push(degree),
+        }
+        num_arcs += degree as u64;
+    };
+
+    if args.indegree {
+        crate::algo::in_degrees(&graph)
+            .into_iter()
+            .for_each(&mut record);
+    } else {
+        let mut pl = ProgressLogger::default();
+        pl.display_memory(true)
+            .item_name("node")
+            .expected_updates(Some(num_nodes));
+        pl.start("Scanning outdegrees...");
+        for (_offset, degree) in graph.offset_deg_iter() {
+            record(degree);
+            pl.light_update();
+        }
+        pl.done();
+    }
+    large.sort_unstable();
+
+    // Degrees in increasing order, with their multiplicity
+    let sorted = || {
+        counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count != 0)
+            .map(|(degree, &count)| (degree, count))
+            .chain(large.iter().map(|&degree| (degree, 1)))
+    };
+
+    let min = sorted().next().unwrap().0;
+    let max = sorted().last().unwrap().0;
+    let mut median = 0;
+    let mut seen = 0;
+    for (degree, count) in sorted() {
+        seen += count;
+        if seen > (num_nodes as u64 - 1) / 2 {
+            median = degree;
+            break;
+        }
+    }
+
+    println!("# nodes\t{}", num_nodes);
+    println!("# arcs\t{}", num_arcs);
+    println!("# min\t{}", min);
+    println!("# max\t{}", max);
+    println!("# mean\t{}", num_arcs as f64 / num_nodes as f64);
+    println!("# median\t{}", median);
+
+    let mut current: Option<((usize, usize), u64)> = None;
+    for (degree, count) in sorted() {
+        let b = bin(degree, threshold);
+        match &mut current {
+            Some((range, total)) if *range == b => *total += count,
+            _ => {
+                if let Some(((lower, upper), total)) = current {
+                    println!("{}\t{}\t{}", lower, upper, total);
+                }
+                current = Some((b, count));
+            }
+        }
+    }
+    if let Some(((lower, upper), total)) = current {
+        println!("{}\t{}\t{}", lower, upper, total);
+    }
+
+    Ok(())
+}
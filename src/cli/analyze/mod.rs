@@ -8,6 +8,12 @@ use anyhow::Result;
 use clap::{ArgMatches, Command};
 
 pub mod codes;
+pub mod degrees;
+pub mod references;
+pub mod sample_arcs;
+pub mod self_loops;
+pub mod topk_degree;
+pub mod wcc;
 
 pub const COMMAND_NAME: &str = "analyze";
 
@@ -18,12 +24,24 @@ pub fn cli(command: Command) -> Command {
         .arg_required_else_help(true)
         .allow_external_subcommands(true);
     let sub_command = codes::cli(sub_command);
+    let sub_command = degrees::cli(sub_command);
+    let sub_command = references::cli(sub_command);
+    let sub_command = sample_arcs::cli(sub_command);
+    let sub_command = self_loops::cli(sub_command);
+    let sub_command = topk_degree::cli(sub_command);
+    let sub_command = wcc::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
 }
 
 pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
         Some((codes::COMMAND_NAME, sub_m)) => codes::main(sub_m),
+        Some((degrees::COMMAND_NAME, sub_m)) => degrees::main(sub_m),
+        Some((references::COMMAND_NAME, sub_m)) => references::main(sub_m),
+        Some((sample_arcs::COMMAND_NAME, sub_m)) => sample_arcs::main(sub_m),
+        Some((self_loops::COMMAND_NAME, sub_m)) => self_loops::main(sub_m),
+        Some((topk_degree::COMMAND_NAME, sub_m)) => topk_degree::main(sub_m),
+        Some((wcc::COMMAND_NAME, sub_m)) => wcc::main(sub_m),
         Some((command_name, _)) => {
             eprintln!("Unknown command: {:?}", command_name);
             std::process::exit(1);
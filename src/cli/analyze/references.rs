@@ -0,0 +1,105 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{ensure, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "references";
+
+#[derive(Args, Debug)]
+#[command(about = "Prints the distribution of the reference offsets of a graph, that is, of the distances from the nodes whose successors are copied, as TSV. Statistics are printed first, on lines starting with '#', followed by the number of nodes and their fraction for each distance in the compression window. If many nodes use distances close to the compression window, a larger window might improve compression.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => references::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => references::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+pub fn references<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+    let num_nodes = graph.num_nodes();
+    ensure!(num_nodes > 0, "The graph has no nodes");
+
+    // The reference offsets are decoded by the degree scan, so there is
+    // no need to materialize successors
+    let mut iter = graph.offset_deg_iter();
+    let compression_window = iter.compression_window();
+    // counts[d] is the number of nodes with reference offset d
+    let mut counts = vec![0_u64; compression_window + 1];
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Scanning reference offsets...");
+    for _ in 0..num_nodes {
+        let (_degree, ref_delta) = iter.next_degree_and_ref_delta()?;
+        counts[ref_delta] += 1;
+        pl.light_update();
+    }
+    pl.done();
+
+    let referencing = num_nodes as u64 - counts[0];
+    let total_distance = counts
+        .iter()
+        .enumerate()
+        .map(|(distance, &count)| distance as u64 * count)
+        .sum::<u64>();
+
+    println!("# nodes\t{}", num_nodes);
+    println!("# compression window\t{}", compression_window);
+    println!("# nodes with a reference\t{}", referencing);
+    println!(
+        "# fraction of nodes without a reference\t{}",
+        counts[0] as f64 / num_nodes as f64
+    );
+    if referencing > 0 {
+        println!(
+            "# mean distance\t{}",
+            total_distance as f64 / referencing as f64
+        );
+    }
+
+    for (distance, &count) in counts.iter().enumerate().skip(1) {
+        println!(
+            "{}\t{}\t{}",
+            distance,
+            count,
+            count as f64 / num_nodes as f64
+        );
+    }
+
+    Ok(())
+}
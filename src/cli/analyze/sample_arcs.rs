@@ -0,0 +1,68 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::Result;
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "sample-arcs";
+
+#[derive(Args, Debug)]
+#[command(about = "Prints as TSV k arcs of a graph sampled uniformly at random in a single pass.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    #[arg(short, long, default_value_t = 1000)]
+    /// The number of arcs to sample.
+    pub k: usize,
+
+    #[arg(short, long, default_value_t = 0)]
+    /// The seed of the pseudorandom number generator.
+    pub seed: u64,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => sample::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => sample::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+pub fn sample<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+
+    let mut stdout = BufWriter::new(std::io::stdout().lock());
+    for (src, dst) in sample_arcs(&graph, args.k, args.seed) {
+        writeln!(stdout, "{}\t{}", src, dst)?;
+    }
+    stdout.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::Result;
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "self-loops";
+
+#[derive(Args, Debug)]
+#[command(about = "Prints the number of nodes with a self-loop and the overall number of self-loops.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => self_loops::<BE>(args).map(|_| ()),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => self_loops::<LE>(args).map(|_| ()),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// Prints, and returns, the number of nodes with a self-loop and the number
+/// of self-loops of the graph.
+pub fn self_loops<E: Endianness + 'static>(args: CliArgs) -> Result<(usize, usize)>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+
+    let (nodes, self_loops) = crate::algo::count_self_loops(&graph);
+
+    println!("nodes with a self-loop\t{}", nodes);
+    println!("self-loops\t{}", self_loops);
+
+    Ok((nodes, self_loops))
+}
@@ -0,0 +1,125 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::Result;
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "topk-degree";
+
+#[derive(Args, Debug)]
+#[command(about = "Prints the k nodes of largest degree, in decreasing order of degree.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    #[arg(short, long, default_value_t = 10)]
+    /// The number of nodes to print.
+    pub k: usize,
+
+    #[arg(short, long)]
+    /// Use indegrees instead of outdegrees.
+    pub indegree: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => topk_degree::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => topk_degree::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// A bounded min-heap keeping the `k` largest pairs `(degree, node)`.
+///
+/// Ties on the degree are broken in favor of smaller nodes.
+struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<(usize, Reverse<usize>)>>,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn push(&mut self, node: usize, degree: usize) {
+        let item = Reverse((degree, Reverse(node)));
+        if self.heap.len() < self.k {
+            self.heap.push(item);
+        } else if let Some(min) = self.heap.peek() {
+            if item < *min {
+                self.heap.pop();
+                self.heap.push(item);
+            }
+        }
+    }
+
+    /// Returns the pairs `(node, degree)` in decreasing order of degree.
+    fn into_sorted_vec(self) -> Vec<(usize, usize)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((degree, Reverse(node)))| (node, degree))
+            .collect()
+    }
+}
+
+pub fn topk_degree<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+
+    let mut top_k = TopK::new(args.k);
+
+    if args.indegree {
+        for (node, degree) in crate::algo::in_degrees(&graph).into_iter().enumerate() {
+            top_k.push(node, degree);
+        }
+    } else {
+        let mut pl = ProgressLogger::default();
+        pl.display_memory(true)
+            .item_name("node")
+            .expected_updates(Some(graph.num_nodes()));
+        pl.start("Scanning outdegrees...");
+        for (node, (_offset, degree)) in graph.offset_deg_iter().enumerate() {
+            top_k.push(node, degree);
+            pl.light_update();
+        }
+        pl.done();
+    }
+
+    for (node, degree) in top_k.into_sorted_vec() {
+        println!("{}\t{}", node, degree);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::algo::{component_sizes, large_components};
+use crate::cli::common::*;
+use crate::prelude::*;
+use anyhow::Result;
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use std::path::PathBuf;
+use tempfile::Builder;
+
+pub const COMMAND_NAME: &str = "wcc";
+
+#[derive(Args, Debug)]
+#[command(about = "Prints the representative and the size of the weakly connected components of a graph, in decreasing order of size.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    #[arg(short, long, default_value_t = 1)]
+    /// Report only components with at least this many nodes.
+    pub min_size: usize,
+
+    #[arg(long)]
+    /// Compress to this basename the subgraph induced by the reported
+    /// components, with nodes renumbered preserving their order.
+    pub induced: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub num_threads: NumThreadsArg,
+
+    #[clap(flatten)]
+    pub batch_size: BatchSizeArg,
+
+    #[clap(flatten)]
+    pub ca: CompressArgs,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => wcc::<BE>(args).map(|_| ()),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => wcc::<LE>(args).map(|_| ()),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// Prints, and returns, the pairs `(representative, size)` of the components
+/// with at least `min_size` nodes, as returned by
+/// [`component_sizes`](crate::algo::component_sizes).
+pub fn wcc<E: Endianness + Send + Sync + 'static>(args: CliArgs) -> Result<Vec<(usize, usize)>>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+
+    let labels = crate::algo::wcc(&graph);
+    let sizes: Vec<_> = component_sizes(&labels)
+        .into_iter()
+        .take_while(|&(_, size)| size >= args.min_size)
+        .collect();
+
+    for &(representative, size) in &sizes {
+        println!("{}\t{}", representative, size);
+    }
+
+    if let Some(dst) = args.induced {
+        let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
+        let target_endianness = args.ca.endianness.clone();
+        let dir = Builder::new().prefix("CompressInduced").tempdir()?;
+
+        let keep = large_components(&labels, args.min_size);
+        let (induced, _) =
+            crate::transform::induced_subgraph(&graph, &keep, args.batch_size.batch_size)?;
+
+        BVComp::parallel_endianness(
+            dst,
+            &induced,
+            induced.num_nodes(),
+            args.ca.into(),
+            thread_pool,
+            dir,
+            &target_endianness.unwrap_or_else(|| E::NAME.into()),
+        )?;
+    }
+
+    Ok(sizes)
+}
@@ -72,7 +72,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
                 )?,
                 false => visit(config.clone().endianness::<LE>().load()?)?,
             },
-            e => panic!("Unknown endianness: {}", e),
+            e => return Err(crate::cli::unknown_endianness(e)),
         };
     }
     Ok(())
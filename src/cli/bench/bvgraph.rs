@@ -6,10 +6,10 @@
  */
 
 use crate::prelude::*;
-use anyhow::Result;
-use clap::{ArgMatches, Args, Command, FromArgMatches};
+use anyhow::{ensure, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches, ValueEnum};
 use dsi_bitstream::prelude::*;
-use itertools::Itertools;
+use epserde::prelude::MemCase;
 use lender::*;
 use rand::rngs::SmallRng;
 use rand::Rng;
@@ -45,9 +45,26 @@ pub struct CliArgs {
     #[arg(short = 'd', long)]
     pub degrees: bool,
 
-    /// Do not test speed, but check that the sequential and random-access successor lists are the same.
+    /// Do not test speed, but check that the sequential and random-access successor lists are the same (as `check graph`).
     #[arg(short = 'c', long)]
     pub check: bool,
+
+    /// In random-access test, store each successor list in a reused buffer.
+    #[arg(long)]
+    pub reuse_buffer: bool,
+
+    /// The bit reader to use for speed tests (the unbuffered reader requires dynamic dispatch).
+    #[arg(long, value_enum, default_value_t = Reader::Buffered)]
+    pub reader: Reader,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// The kind of bit reader used to decode the graph.
+pub enum Reader {
+    /// A reader buffering one 64-bit word ahead.
+    Buffered,
+    /// A reader accessing the underlying words directly at each read.
+    Unbuffered,
 }
 
 pub fn cli(command: Command) -> Command {
@@ -74,18 +91,30 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             true => bench_webgraph::<LE, Static>(args),
             false => bench_webgraph::<LE, Dynamic>(args),
         },
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
-fn bench_random(graph: impl RandomAccessGraph, samples: usize, repeats: usize, first: bool) {
+fn bench_random(
+    graph: impl RandomAccessGraph,
+    samples: usize,
+    repeats: usize,
+    first: bool,
+    reuse_buffer: bool,
+) {
     // Random-access speed test
+    let mut buf = vec![];
     for _ in 0..repeats {
         let mut rng = SmallRng::seed_from_u64(0);
         let mut c: u64 = 0;
         let num_nodes = graph.num_nodes();
         let start = std::time::Instant::now();
-        if first {
+        if reuse_buffer {
+            for _ in 0..samples {
+                let node = rng.gen_range(0..num_nodes);
+                c += black_box(graph.successors_into(node, &mut buf) as u64);
+            }
+        } else if first {
             for _ in 0..samples {
                 black_box(
                     graph
@@ -135,23 +164,10 @@ fn bench_seq(graph: impl SequentialGraph, repeats: usize) {
 fn bench_webgraph<E: Endianness, D: Dispatch>(args: CliArgs) -> Result<()>
 where
     for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+    for<'a> BitReader<E, MemWordReader<u64, &'a [u64]>>: CodeRead<E> + BitSeek,
 {
     if args.check {
-        let graph = BVGraph::with_basename(&args.src).endianness::<E>().load()?;
-
-        let seq_graph = BVGraphSeq::with_basename(&args.src)
-            .endianness::<E>()
-            .load()?;
-
-        let mut deg_reader = seq_graph.offset_deg_iter();
-
-        // Check that sequential and random-access interfaces return the same result
-        for_![ (node, seq_succ) in seq_graph {
-            let succ = graph.successors(node);
-
-            assert_eq!(deg_reader.next_degree()?, seq_succ.len());
-            assert_eq!(succ.collect_vec(), seq_succ.collect_vec());
-        }];
+        crate::cli::check::graph::check_graph::<E>(&args.src)?;
     } else if args.degrees {
         let seq_graph = BVGraphSeq::with_basename(&args.src)
             .endianness::<E>()
@@ -172,6 +188,52 @@ where
 
             assert_eq!(c, seq_graph.num_arcs_hint().unwrap());
         }
+    } else if args.reader == Reader::Unbuffered {
+        ensure!(
+            std::any::TypeId::of::<D>() == std::any::TypeId::of::<Dynamic>(),
+            "The unbuffered reader is available only with dynamic dispatch"
+        );
+        let (num_nodes, num_arcs, comp_flags) =
+            parse_properties::<E>(args.src.with_extension(PROPERTIES_EXTENSION))?;
+        let graph_path = args.src.with_extension(GRAPH_EXTENSION);
+        match args.random {
+            Some(samples) => {
+                let flags = MemoryFlags::TRANSPARENT_HUGE_PAGES | MemoryFlags::RANDOM_ACCESS;
+                let data = MmapHelper::<u64>::mmap(&graph_path, flags.into())?;
+                let offsets = Mmap::load_offsets(args.src.with_extension(EF_EXTENSION), flags)?;
+                bench_random(
+                    BVGraph::new(
+                        DynCodesDecoderFactory::new(data, offsets, comp_flags)?,
+                        num_nodes,
+                        num_arcs,
+                        comp_flags.compression_window,
+                        comp_flags.min_interval_length,
+                    ),
+                    samples,
+                    args.repeats,
+                    args.first,
+                    args.reuse_buffer,
+                );
+            }
+            None => {
+                let flags = MemoryFlags::TRANSPARENT_HUGE_PAGES | MemoryFlags::SEQUENTIAL;
+                let data = MmapHelper::<u64>::mmap(&graph_path, flags.into())?;
+                bench_seq(
+                    BVGraphSeq::new(
+                        DynCodesDecoderFactory::new(
+                            data,
+                            MemCase::from(EmptyDict::default()),
+                            comp_flags,
+                        )?,
+                        num_nodes,
+                        Some(num_arcs),
+                        comp_flags.compression_window,
+                        comp_flags.min_interval_length,
+                    ),
+                    args.repeats,
+                );
+            }
+        }
     } else {
         match (
             args.random,
@@ -188,6 +250,7 @@ where
                     samples,
                     args.repeats,
                     args.first,
+                    args.reuse_buffer,
                 );
             }
             (Some(samples), false) => {
@@ -201,6 +264,7 @@ where
                     samples,
                     args.repeats,
                     args.first,
+                    args.reuse_buffer,
                 );
             }
             (None, true) => {
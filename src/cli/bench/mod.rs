@@ -9,6 +9,7 @@ use clap::{ArgMatches, Command};
 
 pub mod bf_visit;
 pub mod bvgraph;
+pub mod warm;
 
 pub const COMMAND_NAME: &str = "bench";
 
@@ -20,6 +21,7 @@ pub fn cli(command: Command) -> Command {
         .allow_external_subcommands(true);
     let sub_command = bvgraph::cli(sub_command);
     let sub_command = bf_visit::cli(sub_command);
+    let sub_command = warm::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
 }
 
@@ -27,6 +29,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
         Some((bf_visit::COMMAND_NAME, sub_m)) => bf_visit::main(sub_m),
         Some((bvgraph::COMMAND_NAME, sub_m)) => bvgraph::main(sub_m),
+        Some((warm::COMMAND_NAME, sub_m)) => warm::main(sub_m),
         Some((command_name, _)) => {
             eprintln!("Unknown command: {:?}", command_name);
             std::process::exit(1);
@@ -0,0 +1,55 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use mmap_rs::MmapFlags;
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "warm";
+
+#[derive(Args, Debug)]
+#[command(about = "Brings the .graph and .ef files of a graph into the page cache, so that subsequent benchmarks measure steady-state access times.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    /// Advise the kernel with MADV_WILLNEED before touching the pages.
+    #[arg(short, long)]
+    pub will_need: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    warm(CliArgs::from_arg_matches(submatches)?)
+}
+
+pub fn warm(args: CliArgs) -> Result<()> {
+    for extension in [GRAPH_EXTENSION, EF_EXTENSION] {
+        let path = args.src.with_extension(extension);
+        if !path.exists() {
+            log::warn!("{} does not exist, skipping", path.display());
+            continue;
+        }
+        let start = std::time::Instant::now();
+        let mmap = MmapHelper::<u8>::mmap(&path, MmapFlags::SEQUENTIAL)
+            .with_context(|| format!("Could not mmap {}", path.display()))?;
+        let pages = mmap
+            .warm(args.will_need)
+            .with_context(|| format!("Could not warm {}", path.display()))?;
+        log::info!(
+            "Touched {} pages of {} in {:.3} seconds",
+            pages,
+            path.display(),
+            start.elapsed().as_secs_f64()
+        );
+    }
+    Ok(())
+}
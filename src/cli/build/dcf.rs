@@ -13,7 +13,7 @@ use dsi_progress_logger::prelude::*;
 use epserde::prelude::*;
 use log::info;
 use std::fs::File;
-use std::io::{BufReader, BufWriter};
+use std::io::BufWriter;
 use std::path::PathBuf;
 use sux::prelude::*;
 
@@ -44,7 +44,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => build_dcf::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
@@ -53,16 +53,9 @@ where
     for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
 {
     let basename = args.src;
-    let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
-    let f = File::open(&properties_path).with_context(|| {
-        format!(
-            "Could not open properties file: {}",
-            properties_path.display()
-        )
-    })?;
-    let map = java_properties::read(BufReader::new(f))?;
-    let num_nodes = map.get("nodes").unwrap().parse::<usize>()?;
-    let num_arcs = map.get("arcs").unwrap().parse::<usize>()?;
+    let properties = GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION))?;
+    let num_nodes = properties.num_nodes;
+    let num_arcs = properties.num_arcs as usize;
 
     // TODO : not +1
     let mut efb = EliasFanoBuilder::new(num_nodes + 1, num_arcs + 1);
@@ -28,6 +28,15 @@ pub struct CliArgs {
     /// starting from a label offset file. It is usually one more than
     /// the number of nodes in the graph.
     pub n: Option<usize>,
+    /// After building, check that the .ef file contains the offsets of the
+    /// nodes of the graph.
+    #[arg(short, long)]
+    pub check: bool,
+    /// The number of threads used to decode the .offsets file. Decoding
+    /// with more than one thread requires mapping the .offsets file in
+    /// memory.
+    #[arg(short = 'j', long, default_value_t = 1)]
+    pub num_threads: usize,
 }
 
 pub fn cli(command: Command) -> Command {
@@ -48,7 +57,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => build_eliasfano::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
@@ -68,7 +77,31 @@ where
                 .seek(std::io::SeekFrom::End(0))
                 .with_context(|| format!("Could not seek to end of {}", labels_path.display()))?;
 
-            let mut efb = EliasFanoBuilder::new(num_nodes, file_len as usize);
+            let mut efb = EliasFanoBuilder::new(
+                num_nodes,
+                usize::try_from(file_len).with_context(|| {
+                    format!("{} is too large for this platform", labels_path.display())
+                })?,
+            );
+
+            // the code of the label offsets is specified in the
+            // .properties file of the labels, as for graphs
+            let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
+            let offsets_code = if properties_path.exists() {
+                let f = File::open(&properties_path)
+                    .with_context(|| format!("Could not open {}", properties_path.display()))?;
+                let map = java_properties::read(BufReader::new(f))
+                    .with_context(|| format!("Could not parse {}", properties_path.display()))?;
+                CompFlags::offsets_code_from_properties(&map).with_context(|| {
+                    format!("Invalid property file {}", properties_path.display())
+                })?
+            } else {
+                info!(
+                    "{} does not exist, assuming γ-coded label offsets",
+                    properties_path.display()
+                );
+                Code::Gamma
+            };
 
             info!("The offsets file exists, reading it to build Elias-Fano");
             let of_file = BufReader::with_capacity(
@@ -77,20 +110,20 @@ where
                     .with_context(|| format!("Could not open {}", of_file_path.display()))?,
             );
             // create a bit reader on the file
-            let mut reader = BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(of_file));
+            let reader = BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(of_file));
             // progress bar
             let mut pl = ProgressLogger::default();
             pl.display_memory(true)
                 .item_name("offset")
                 .expected_updates(Some(num_nodes));
             pl.start("Translating offsets to EliasFano...");
-            // read the graph a write the offsets
-            let mut offset = 0;
-            for _node_id in 0..num_nodes {
-                // write where
-                offset += reader.read_gamma().context("Could not read gamma")?;
-                efb.push(offset as _);
-                // decode the next nodes so we know where the next node_id starts
+            // read the offsets as u64, so that overflows are detected
+            for offset in OffsetsIter::new(reader, offsets_code, num_nodes) {
+                let offset = offset?;
+                efb.push(
+                    usize::try_from(offset)
+                        .with_context(|| format!("Offset {} does not fit in a usize", offset))?,
+                );
                 pl.light_update();
             }
             let ef = efb.build();
@@ -113,19 +146,15 @@ where
             ef.serialize(&mut ef_file)
                 .with_context(|| format!("Could not serialize EF to {}", ef_path.display()))?;
             pl.done();
+            if args.check {
+                log::warn!("Label offsets cannot be checked");
+            }
             return Ok(());
         }
     }
 
-    let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
-    let f = File::open(&properties_path).with_context(|| {
-        format!(
-            "Could not open properties file: {}",
-            properties_path.display()
-        )
-    })?;
-    let map = java_properties::read(BufReader::new(f))?;
-    let num_nodes = map.get("nodes").unwrap().parse::<usize>()?;
+    let properties = GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION))?;
+    let num_nodes = properties.num_nodes;
 
     let graph_path = basename.with_extension(GRAPH_EXTENSION);
     let mut file = File::open(&graph_path)
@@ -133,80 +162,97 @@ where
     let file_len = 8 * file
         .seek(std::io::SeekFrom::End(0))
         .with_context(|| format!("Could not seek in {}", graph_path.display()))?;
-
-    let mut efb = EliasFanoBuilder::new(num_nodes + 1, file_len as usize);
-
-    let ef_path = basename.with_extension(EF_EXTENSION);
-    let mut ef_file = BufWriter::new(
-        File::create(&ef_path)
-            .with_context(|| format!("Could not create {}", ef_path.display()))?,
-    );
+    let file_len = usize::try_from(file_len)
+        .with_context(|| format!("{} is too large for this platform", graph_path.display()))?;
 
     // Create the offsets file
     let of_file_path = basename.with_extension(OFFSETS_EXTENSION);
 
-    let mut pl = ProgressLogger::default();
-    pl.display_memory(true)
-        .item_name("offset")
-        .expected_updates(Some(num_nodes));
-
     // if the offset files exists, read it to build elias-fano
-    if of_file_path.exists() {
+    let ef = if of_file_path.exists() {
         info!("The offsets file exists, reading it to build Elias-Fano");
-        let of_file = BufReader::with_capacity(
-            1 << 20,
-            File::open(&of_file_path)
-                .with_context(|| format!("Could not open {}", of_file_path.display()))?,
-        );
-        // create a bit reader on the file
-        let mut reader = BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(of_file));
-        // progress bar
-        pl.start("Translating offsets to EliasFano...");
-        // read the graph a write the offsets
-        let mut offset = 0;
-        for _node_id in 0..num_nodes + 1 {
-            // write where
-            offset += reader.read_gamma().context("Could not read gamma")?;
-            efb.push(offset as _);
-            // decode the next nodes so we know where the next node_id starts
-            pl.light_update();
-        }
+        let num_threads = args.num_threads;
+        crate::cli::get_thread_pool(num_threads)
+            .install(|| par_ef_from_offsets(&basename, num_threads))?
     } else {
         info!("The offsets file does not exists, reading the graph to build Elias-Fano");
         let seq_graph = crate::graphs::bvgraph::sequential::BVGraphSeq::with_basename(&basename)
             .endianness::<E>()
             .load()
             .with_context(|| format!("Could not load graph at {}", basename.display()))?;
-        // otherwise directly read the graph
-        // progress bar
-        pl.start("Building EliasFano...");
-        // read the graph a write the offsets
-        let mut iter = seq_graph.offset_deg_iter();
-        for (new_offset, _degree) in iter.by_ref() {
-            // write where
-            efb.push(new_offset as _);
-            // decode the next nodes so we know where the next node_id starts
-            pl.light_update();
-        }
-        efb.push(iter.get_pos() as _);
-    }
-    pl.done();
-
-    let ef = efb.build();
-
-    let mut pl = ProgressLogger::default();
-    pl.display_memory(true);
-    pl.start("Building the Index over the ones in the high-bits...");
-    let ef: EF = unsafe { ef.map_high_bits(SelectAdaptConst::<_, _, 12, 4>::new) };
-    pl.done();
+        ef_from_graph(&seq_graph, file_len)?
+    };
 
     let mut pl = ProgressLogger::default();
     pl.display_memory(true);
     pl.start("Writing to disk...");
     // serialize and dump the schema to disk
+    let ef_path = basename.with_extension(EF_EXTENSION);
+    let mut ef_file = BufWriter::new(
+        File::create(&ef_path)
+            .with_context(|| format!("Could not create {}", ef_path.display()))?,
+    );
     ef.serialize(&mut ef_file)
         .with_context(|| format!("Could not serialize EliasFano to {}", ef_path.display()))?;
+    drop(ef_file);
+    pl.done();
+
+    if args.check {
+        check_eliasfano::<E>(&basename, num_nodes)?;
+    }
+    Ok(())
+}
+
+/// Checks that the .ef file of a graph contains the offsets of its nodes,
+/// as computed by scanning the graph.
+pub fn check_eliasfano<E: Endianness + 'static>(
+    basename: &std::path::Path,
+    num_nodes: usize,
+) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let ef_path = basename.with_extension(EF_EXTENSION);
+    let ef = <EF>::mmap(&ef_path, deser::Flags::empty())
+        .with_context(|| format!("Could not map {}", ef_path.display()))?;
+    anyhow::ensure!(
+        ef.len() == num_nodes + 1,
+        "The Elias-Fano in {} has {} elements instead of {}",
+        ef_path.display(),
+        ef.len(),
+        num_nodes + 1
+    );
+
+    let seq_graph = crate::graphs::bvgraph::sequential::BVGraphSeq::with_basename(basename)
+        .endianness::<E>()
+        .load()
+        .with_context(|| format!("Could not load graph at {}", basename.display()))?;
 
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("offset")
+        .expected_updates(Some(num_nodes));
+    pl.start("Checking EliasFano...");
+    let mut iter = seq_graph.offset_deg_iter();
+    for (node_id, (offset, _degree)) in iter.by_ref().enumerate() {
+        anyhow::ensure!(
+            ef.get(node_id) as u64 == offset,
+            "The offset of node {} is {} but {} contains {}",
+            node_id,
+            offset,
+            ef_path.display(),
+            ef.get(node_id)
+        );
+        pl.light_update();
+    }
+    anyhow::ensure!(
+        ef.get(num_nodes) as u64 == iter.get_pos(),
+        "The length of the graph bitstream is {} but {} contains {}",
+        iter.get_pos(),
+        ef_path.display(),
+        ef.get(num_nodes)
+    );
     pl.done();
+    info!("The offsets in {} are correct", ef_path.display());
     Ok(())
 }
@@ -43,7 +43,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => build_offsets::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
@@ -0,0 +1,153 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{bail, ensure, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use lender::*;
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "diff";
+
+#[derive(Args, Debug)]
+#[command(about = "Check that two graphs have the same arcs, reporting their differences.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the first graph.
+    pub a: PathBuf,
+    /// The basename of the second graph.
+    pub b: PathBuf,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.a)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => diff_with::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => diff_with::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// Dispatches on the endianness of the second graph.
+fn diff_with<A: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<A, MemWordReader<u32, &'a [u32]>>: CodeRead<A> + BitSeek,
+{
+    match get_endianness(&args.b)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => diff::<A, BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => diff::<A, LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+pub fn diff<A: Endianness + 'static, B: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<A, MemWordReader<u32, &'a [u32]>>: CodeRead<A> + BitSeek,
+    for<'a> BufBitReader<B, MemWordReader<u32, &'a [u32]>>: CodeRead<B> + BitSeek,
+{
+    let graph_a = BVGraphSeq::with_basename(&args.a)
+        .endianness::<A>()
+        .load()?;
+    let graph_b = BVGraphSeq::with_basename(&args.b)
+        .endianness::<B>()
+        .load()?;
+    let num_nodes = graph_a.num_nodes();
+    ensure!(
+        num_nodes == graph_b.num_nodes(),
+        "The graphs have a different number of nodes: {} != {}",
+        num_nodes,
+        graph_b.num_nodes()
+    );
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Comparing graphs...");
+
+    let mut first_diff = None;
+    let mut diff_nodes = 0_usize;
+    let mut only_a = 0_u64;
+    let mut only_b = 0_u64;
+
+    let mut iter_a = graph_a.iter();
+    let mut iter_b = graph_b.iter();
+    while let Some((node, succ_a)) = iter_a.next() {
+        let (_, succ_b) = iter_b.next().unwrap();
+        // Successors are sorted, so we can merge them
+        let mut succ_a = succ_a.into_iter().peekable();
+        let mut succ_b = succ_b.into_iter().peekable();
+        let (prev_a, prev_b) = (only_a, only_b);
+        loop {
+            match (succ_a.peek(), succ_b.peek()) {
+                (None, None) => break,
+                (Some(_), None) => {
+                    only_a += succ_a.by_ref().count() as u64;
+                }
+                (None, Some(_)) => {
+                    only_b += succ_b.by_ref().count() as u64;
+                }
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        only_a += 1;
+                        succ_a.next();
+                    }
+                    Ordering::Greater => {
+                        only_b += 1;
+                        succ_b.next();
+                    }
+                    Ordering::Equal => {
+                        succ_a.next();
+                        succ_b.next();
+                    }
+                },
+            }
+        }
+        if (prev_a, prev_b) != (only_a, only_b) {
+            first_diff.get_or_insert(node);
+            diff_nodes += 1;
+        }
+        pl.light_update();
+    }
+    pl.done();
+
+    match first_diff {
+        None => {
+            println!("The graphs are identical");
+            Ok(())
+        }
+        Some(node) => {
+            println!("First differing node: {}", node);
+            println!("Differing nodes: {}", diff_nodes);
+            println!("Arcs only in {}: {}", args.a.display(), only_a);
+            println!("Arcs only in {}: {}", args.b.display(), only_b);
+            bail!("The graphs differ");
+        }
+    }
+}
@@ -5,8 +5,10 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use crate::graphs::bvgraph::{EF, EF_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION};
-use anyhow::{Context, Result};
+use crate::graphs::bvgraph::{
+    GraphProperties, OffsetsIter, EF, EF_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION,
+};
+use anyhow::Result;
 use clap::{ArgMatches, Args, Command, FromArgMatches};
 use dsi_bitstream::prelude::*;
 use dsi_progress_logger::prelude::*;
@@ -35,15 +37,9 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
 }
 
 pub fn check_ef(args: CliArgs) -> Result<()> {
-    let properties_path = args.src.with_extension(PROPERTIES_EXTENSION);
-    let f = File::open(&properties_path).with_context(|| {
-        format!(
-            "Could not load properties file: {}",
-            properties_path.display()
-        )
-    })?;
-    let map = java_properties::read(BufReader::new(f))?;
-    let num_nodes = map.get("nodes").unwrap().parse::<usize>()?;
+    let properties = GraphProperties::load(args.src.with_extension(PROPERTIES_EXTENSION))?;
+    let num_nodes = properties.num_nodes;
+    let offsets_code = properties.offsets_code;
 
     // Create the offsets file
     let of_file_path = args.src.with_extension(OFFSETS_EXTENSION);
@@ -60,18 +56,15 @@ pub fn check_ef(args: CliArgs) -> Result<()> {
         info!("The offsets file exists, reading it to build Elias-Fano");
         let of_file = BufReader::with_capacity(1 << 20, File::open(of_file_path)?);
         // create a bit reader on the file
-        let mut reader = BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(of_file));
+        let reader = BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(of_file));
         // progress bar
         pl.start("Translating offsets to EliasFano...");
-        // read the graph a write the offsets
-        let mut offset = 0;
-        for node_id in 0..num_nodes + 1 {
-            // write where
-            offset += reader.read_gamma()?;
+        // read the offsets, accumulated as u64, and compare them with ef
+        for (node_id, offset) in OffsetsIter::new(reader, offsets_code, num_nodes + 1).enumerate() {
+            let offset = offset?;
             // read ef
             let ef_res = ef.get(node_id as _);
-            assert_eq!(offset, ef_res as _, "node_id: {}", node_id);
-            // decode the next nodes so we know where the next node_id starts
+            assert_eq!(offset, ef_res as u64, "node_id: {}", node_id);
             pl.light_update();
         }
     }
@@ -0,0 +1,109 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{ensure, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use lender::*;
+use std::path::{Path, PathBuf};
+
+pub const COMMAND_NAME: &str = "graph";
+
+#[derive(Args, Debug)]
+#[command(about = "Check that degrees, sequential and random-access successor lists of a graph are coherent (requires the '.ef' file).", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => check_graph::<BE>(&args.src),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => check_graph::<LE>(&args.src),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// Checks that, for each node of the graph with the given basename, the
+/// degree returned by the degree iterator, the length of the sequential
+/// successor list, and the random-access outdegree coincide, and that the
+/// sequential and random-access successor lists are the same.
+pub fn check_graph<E: Endianness + 'static>(basename: impl AsRef<Path>) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let basename = basename.as_ref();
+    let graph = BVGraph::with_basename(basename).endianness::<E>().load()?;
+    let seq_graph = BVGraphSeq::with_basename(basename)
+        .endianness::<E>()
+        .load()?;
+    ensure!(
+        graph.num_nodes() == seq_graph.num_nodes(),
+        "The number of nodes of the random-access graph ({}) differs from that of the sequential graph ({})",
+        graph.num_nodes(),
+        seq_graph.num_nodes()
+    );
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(seq_graph.num_nodes()));
+    pl.start("Checking graph...");
+
+    let mut deg_reader = seq_graph.offset_deg_iter();
+    let mut num_arcs = 0;
+    for_![ (node, seq_succ) in seq_graph.iter() {
+        let seq_succ = seq_succ.collect::<Vec<_>>();
+        let degree = deg_reader.next_degree()?;
+        ensure!(
+            degree == seq_succ.len(),
+            "Node {}: the degree iterator returns {}, but the sequential successor list has length {}",
+            node,
+            degree,
+            seq_succ.len()
+        );
+        ensure!(
+            graph.outdegree(node) == degree,
+            "Node {}: the random-access outdegree is {}, but the sequential outdegree is {}",
+            node,
+            graph.outdegree(node),
+            degree
+        );
+        ensure!(
+            itertools::equal(graph.successors(node), seq_succ.iter().copied()),
+            "Node {}: the random-access successors differ from the sequential successors",
+            node
+        );
+        num_arcs += degree as u64;
+        pl.light_update();
+    }];
+    pl.done();
+
+    ensure!(
+        num_arcs == graph.num_arcs(),
+        "The graph has {} arcs, but its properties state {}",
+        num_arcs,
+        graph.num_arcs()
+    );
+    log::info!("The graph is coherent");
+    Ok(())
+}
@@ -7,7 +7,10 @@
 use anyhow::Result;
 use clap::{ArgMatches, Command};
 
+pub mod diff;
 pub mod ef;
+pub mod graph;
+pub mod properties;
 
 pub const COMMAND_NAME: &str = "check";
 
@@ -17,13 +20,19 @@ pub fn cli(command: Command) -> Command {
         .subcommand_required(true)
         .arg_required_else_help(true)
         .allow_external_subcommands(true);
+    let sub_command = diff::cli(sub_command);
     let sub_command = ef::cli(sub_command);
+    let sub_command = graph::cli(sub_command);
+    let sub_command = properties::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
 }
 
 pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
+        Some((diff::COMMAND_NAME, sub_m)) => diff::main(sub_m),
         Some((ef::COMMAND_NAME, sub_m)) => ef::main(sub_m),
+        Some((graph::COMMAND_NAME, sub_m)) => graph::main(sub_m),
+        Some((properties::COMMAND_NAME, sub_m)) => properties::main(sub_m),
         Some((command_name, _)) => {
             eprintln!("Unknown command: {:?}", command_name);
             std::process::exit(1);
@@ -0,0 +1,148 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{ensure, Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use lender::*;
+use mmap_rs::MmapFlags;
+use std::path::{Path, PathBuf};
+
+pub const COMMAND_NAME: &str = "properties";
+
+#[derive(Args, Debug)]
+#[command(about = "Check that the number of nodes and arcs in the .properties file of a graph match the content of the graph.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => check_properties::<BE>(&args.src),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => check_properties::<LE>(&args.src),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+/// Checks that the number of nodes and arcs stored in the `.properties` file
+/// of the graph with the given basename match the content of the graph.
+///
+/// The nodes are counted by decoding outdegrees until the graph bitstream
+/// ends, that is, until the bits left, which are the padding of the last
+/// word, are all zeros; then, the successor lists are scanned to check that
+/// all successors are smaller than the number of nodes, and to count the
+/// arcs.
+pub fn check_properties<E: Endianness + 'static>(basename: impl AsRef<Path>) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let basename = basename.as_ref();
+    let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
+    let properties = GraphProperties::load(&properties_path)?;
+    let graph_path = basename.with_extension(GRAPH_EXTENSION);
+    let data = MmapHelper::<u32>::mmap(&graph_path, MmapFlags::SEQUENTIAL)
+        .with_context(|| format!("Could not map {}", graph_path.display()))?;
+    let graph_bits = std::fs::metadata(&graph_path)?.len() * 8;
+    // Whether only zeros are left in the graph bitstream from a position
+    let at_end = |pos: u64| -> Result<bool> {
+        let left = graph_bits.saturating_sub(pos);
+        if left >= 64 {
+            return Ok(false);
+        }
+        let mut reader = BufBitReader::<E, _>::new(MemWordReader::new(data.as_ref()));
+        reader.set_bit_pos(pos)?;
+        Ok(left == 0 || reader.read_bits(left as usize)? == 0)
+    };
+
+    let seq_graph = BVGraphSeq::with_basename(basename)
+        .endianness::<E>()
+        .load()?;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(properties.num_nodes));
+    pl.start("Counting nodes...");
+    let mut deg_reader = seq_graph.offset_deg_iter();
+    let mut num_nodes = 0;
+    while num_nodes < properties.num_nodes && !at_end(deg_reader.get_pos())? {
+        deg_reader.next_degree()?;
+        num_nodes += 1;
+        pl.light_update();
+    }
+    pl.done();
+    ensure!(
+        num_nodes == properties.num_nodes,
+        "{} states {} nodes, but the graph contains {} nodes",
+        properties_path.display(),
+        properties.num_nodes,
+        num_nodes
+    );
+    ensure!(
+        at_end(deg_reader.get_pos())?,
+        "{} states {} nodes, but the graph contains more nodes ({} bits left after the last node)",
+        properties_path.display(),
+        properties.num_nodes,
+        graph_bits - deg_reader.get_pos()
+    );
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(properties.num_nodes));
+    pl.start("Counting arcs...");
+    let mut num_arcs = 0_u64;
+    let mut max_succ = None;
+    for_!( (_node, succ) in seq_graph.iter() {
+        for dst in succ {
+            max_succ = max_succ.max(Some(dst));
+            num_arcs += 1;
+        }
+        pl.light_update();
+    });
+    pl.done();
+    if let Some(max_succ) = max_succ {
+        ensure!(
+            max_succ < properties.num_nodes,
+            "{} states {} nodes, but the graph contains the successor {}",
+            properties_path.display(),
+            properties.num_nodes,
+            max_succ
+        );
+    }
+    ensure!(
+        num_arcs == properties.num_arcs,
+        "{} states {} arcs, but the graph contains {} arcs",
+        properties_path.display(),
+        properties.num_arcs,
+        num_arcs
+    );
+
+    log::info!(
+        "The graph has {} nodes and {} arcs, as stated in {}",
+        num_nodes,
+        num_arcs,
+        properties_path.display()
+    );
+    Ok(())
+}
@@ -185,6 +185,11 @@ pub struct CompressArgs {
     /// The code to use for the blocks
     pub blocks: PrivCode,
 
+    #[arg(value_enum)]
+    #[clap(long, default_value = "gamma")]
+    /// The code to use for the intervals
+    pub intervals: PrivCode,
+
     #[arg(value_enum)]
     #[clap(long, default_value = "zeta3")]
     /// The code to use for the residuals
@@ -197,7 +202,7 @@ impl From<CompressArgs> for CompFlags {
             outdegrees: value.outdegrees.into(),
             references: value.references.into(),
             blocks: value.blocks.into(),
-            intervals: PrivCode::Gamma.into(),
+            intervals: value.intervals.into(),
             residuals: value.residuals.into(),
             min_interval_length: value.min_interval_length,
             compression_window: value.compression_window,
@@ -0,0 +1,297 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::cli::common::*;
+use crate::graphs::arc_list_graph::{self, ArcListGraph};
+use crate::labels::proj::LeftIterator;
+use crate::prelude::*;
+use anyhow::{bail, ensure, Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use tempfile::Builder;
+
+pub const COMMAND_NAME: &str = "arcs";
+
+#[derive(Args, Debug)]
+#[command(about = "Compresses a list of arcs, one per line, given as pairs of whitespace-separated numeric node ids. Blank lines and lines starting with '#' are ignored. Arcs need not be sorted, and are sorted out of core, unless --presorted is specified.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the compressed graph.
+    pub dst: PathBuf,
+
+    #[arg(long)]
+    /// The file containing the arcs (default: standard input).
+    pub input: Option<PathBuf>,
+
+    #[arg(short, long)]
+    /// The number of nodes in the graph (default: the largest node id plus one).
+    pub num_nodes: Option<usize>,
+
+    #[clap(flatten)]
+    pub num_threads: NumThreadsArg,
+
+    #[clap(flatten)]
+    pub batch_size: BatchSizeArg,
+
+    #[clap(flatten)]
+    pub ca: CompressArgs,
+
+    #[arg(long, requires = "num_nodes")]
+    /// The arcs are sorted by source and then by target: compress them while
+    /// reading them, without sorting, on a single thread. The order is
+    /// checked, and if it is violated an error is returned and the partial
+    /// output is removed.
+    pub presorted: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+    match &args.input {
+        Some(path) => {
+            let file =
+                File::open(path).with_context(|| format!("Could not open {}", path.display()))?;
+            from_arcs(BufReader::with_capacity(1 << 20, file), args)
+        }
+        None => from_arcs(std::io::stdin().lock(), args),
+    }
+}
+
+/// Parses a line of an arc list, returning `None` if the line is blank or
+/// a comment.
+fn parse_arc(line_num: usize, line: &str) -> Result<Option<(usize, usize)>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    let mut fields = line.split_whitespace();
+    let (Some(src), Some(dst)) = (fields.next(), fields.next()) else {
+        bail!("Line {} does not contain an arc: {:?}", line_num + 1, line);
+    };
+    let src = src
+        .parse::<usize>()
+        .with_context(|| format!("Invalid source node on line {}", line_num + 1))?;
+    let dst = dst
+        .parse::<usize>()
+        .with_context(|| format!("Invalid target node on line {}", line_num + 1))?;
+    Ok(Some((src, dst)))
+}
+
+/// Reads arcs from `reader` and compresses them into a BVGraph.
+pub fn from_arcs(reader: impl BufRead, args: CliArgs) -> Result<()> {
+    if args.presorted {
+        return from_sorted_arcs(reader, args);
+    }
+
+    let dir = Builder::new().prefix("FromArcsPairs").tempdir()?;
+    let mut sort_pairs = SortPairs::new(args.batch_size.batch_size, dir.path())?;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true).item_name("arc");
+    pl.start("Reading arcs...");
+
+    let mut max_node_id = None;
+    let mut num_arcs = 0_u64;
+    for (line_num, line) in reader.lines().enumerate() {
+        let line = line.with_context(|| format!("Could not read line {}", line_num + 1))?;
+        let Some((src, dst)) = parse_arc(line_num, &line)? else {
+            continue;
+        };
+        max_node_id = max_node_id.max(Some(src.max(dst)));
+        sort_pairs.push(src, dst)?;
+        num_arcs += 1;
+        pl.light_update();
+    }
+    pl.done();
+
+    let inferred = max_node_id.map_or(0, |x| x + 1);
+    let num_nodes = match args.num_nodes {
+        Some(num_nodes) => {
+            ensure!(
+                inferred <= num_nodes,
+                "Found node id {} but the graph has {} nodes",
+                inferred - 1,
+                num_nodes
+            );
+            num_nodes
+        }
+        None => inferred,
+    };
+    log::info!("Read {} arcs on {} nodes", num_arcs, num_nodes);
+
+    let g = Left(ArcListGraph::new(
+        num_nodes,
+        sort_pairs
+            .iter_dedup()
+            .context("Could not read sorted arcs")?
+            .map(|(src, dst, _)| (src, dst)),
+    ));
+
+    let target_endianness = args.ca.endianness.clone();
+    let dir = Builder::new().prefix("FromArcsCompress").tempdir()?;
+    let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
+    BVComp::parallel_endianness(
+        &args.dst,
+        &g,
+        num_nodes,
+        args.ca.into(),
+        thread_pool,
+        dir,
+        &target_endianness.unwrap_or_else(|| BE::NAME.into()),
+    )?;
+    Ok(())
+}
+
+/// An iterator over the arcs of a sorted arc list that stops at the first
+/// error, storing it.
+///
+/// Duplicate arcs are skipped.
+struct SortedArcs<'a, L> {
+    lines: L,
+    num_nodes: usize,
+    prev: Option<(usize, usize)>,
+    error: &'a mut Option<anyhow::Error>,
+}
+
+/// Checks that the arc `(src, dst)` on line `line_num` has endpoints smaller
+/// than `num_nodes` and does not precede the previous arc `prev`.
+fn check_sorted_arc(
+    line_num: usize,
+    src: usize,
+    dst: usize,
+    num_nodes: usize,
+    prev: Option<(usize, usize)>,
+) -> Result<()> {
+    ensure!(
+        src < num_nodes && dst < num_nodes,
+        "Arc ({}, {}) on line {} has an endpoint not smaller than the number of nodes ({})",
+        src,
+        dst,
+        line_num + 1,
+        num_nodes
+    );
+    if let Some((prev_src, prev_dst)) = prev {
+        ensure!(
+            (prev_src, prev_dst) <= (src, dst),
+            "Arc ({}, {}) on line {} follows arc ({}, {}): arcs are not sorted",
+            src,
+            dst,
+            line_num + 1,
+            prev_src,
+            prev_dst
+        );
+    }
+    Ok(())
+}
+
+impl<'a, L: Iterator<Item = (usize, std::io::Result<String>)>> Iterator for SortedArcs<'a, L> {
+    type Item = (usize, usize, ());
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        for (line_num, line) in self.lines.by_ref() {
+            let arc = line
+                .with_context(|| format!("Could not read line {}", line_num + 1))
+                .and_then(|line| parse_arc(line_num, &line));
+            let (src, dst) = match arc {
+                Ok(Some(arc)) => arc,
+                Ok(None) => continue,
+                Err(e) => {
+                    *self.error = Some(e);
+                    return None;
+                }
+            };
+            if let Err(e) = check_sorted_arc(line_num, src, dst, self.num_nodes, self.prev) {
+                *self.error = Some(e);
+                return None;
+            }
+            if self.prev == Some((src, dst)) {
+                continue;
+            }
+            self.prev = Some((src, dst));
+            return Some((src, dst, ()));
+        }
+        None
+    }
+}
+
+/// Reads sorted arcs from `reader` and compresses them into a BVGraph while
+/// reading them.
+pub fn from_sorted_arcs(reader: impl BufRead, args: CliArgs) -> Result<()> {
+    let num_nodes = args
+        .num_nodes
+        .context("The number of nodes is required for sorted arcs")?;
+    let target_endianness = args
+        .ca
+        .endianness
+        .clone()
+        .unwrap_or_else(|| BE::NAME.into());
+    let compression_flags: CompFlags = args.ca.into();
+
+    let mut error = None;
+    let arcs = SortedArcs {
+        lines: reader.lines().enumerate(),
+        num_nodes,
+        prev: None,
+        error: &mut error,
+    };
+    let lender = LeftIterator(arc_list_graph::Iter::<(), SortedArcs<_>>::new(
+        num_nodes, arcs,
+    ));
+
+    let result = match target_endianness.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => BVComp::single_thread::<BE, _>(
+            &args.dst,
+            lender,
+            compression_flags,
+            true,
+            Some(num_nodes),
+        ),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => BVComp::single_thread::<LE, _>(
+            &args.dst,
+            lender,
+            compression_flags,
+            true,
+            Some(num_nodes),
+        ),
+        e => return Err(crate::cli::unknown_endianness(e)),
+    };
+
+    // The arcs are checked while compressing them, as they might come from
+    // a pipe, so on error we remove the partial output
+    let error = match (result, error) {
+        (Ok(_), None) => return Ok(()),
+        (_, Some(e)) | (Err(e), None) => e,
+    };
+    for extension in [GRAPH_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION] {
+        let path = args.dst.with_extension(extension);
+        if let Err(e) = std::fs::remove_file(&path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Could not remove {}: {}", path.display(), e);
+            }
+        }
+    }
+    Err(error.context(format!(
+        "Could not compress sorted arcs to {}",
+        args.dst.display()
+    )))
+}
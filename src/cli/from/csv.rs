@@ -8,14 +8,11 @@
 use crate::cli::common::*;
 use crate::graphs::arc_list_graph::ArcListGraph;
 use crate::prelude::*;
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use clap::{ArgMatches, Args, Command, FromArgMatches};
 use dsi_bitstream::prelude::{Endianness, BE};
 use dsi_progress_logger::prelude::*;
-use itertools::Itertools;
-use rayon::prelude::ParallelSliceMut;
-use std::collections::BTreeMap;
-use std::io::{BufRead, Write};
+use std::io::BufRead;
 use std::path::PathBuf;
 use tempfile::Builder;
 pub const COMMAND_NAME: &str = "csv";
@@ -27,8 +24,10 @@ pub struct CliArgs {
     pub src: PathBuf,
 
     #[arg(long)]
-    /// The number of nodes in the graph
-    pub num_nodes: usize,
+    /// The number of nodes in the graph. If not specified, it is the number
+    /// of distinct node identifiers or, if --numeric is given, the largest
+    /// node identifier plus one.
+    pub num_nodes: Option<usize>,
 
     #[arg(long)]
     /// The number of arcs in the graph
@@ -58,8 +57,9 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
 pub fn from_csv(args: CliArgs) -> Result<()> {
     let dir = Builder::new().prefix("FromCsvPairs").tempdir()?;
 
-    let mut group_by = SortPairs::new(args.batch_size.batch_size, dir)?;
-    let mut nodes = BTreeMap::new();
+    let mut group_by = SortPairs::new(args.batch_size.batch_size, dir.path())?;
+    let mut nodes = IdMapper::new();
+    let mut max_node_id = None;
 
     // read the csv and put it inside the sort pairs
     let stdin = std::io::stdin();
@@ -90,23 +90,22 @@ pub fn from_csv(args: CliArgs) -> Result<()> {
 
         // split the csv line into the args
         let vals = line.split(args.csv_args.separator).collect::<Vec<_>>();
-        let src = vals[0];
-        let dst = vals[1];
+        let src = vals[args.csv_args.src_column];
+        let dst = vals[args.csv_args.dst_column];
 
         // parse if numeric, or build a node list
         let src_id = if args.csv_args.numeric {
             src.parse::<usize>().unwrap()
         } else {
-            let node_id = nodes.len();
-            *nodes.entry(src.to_string()).or_insert(node_id)
+            nodes.id(src)
         };
         let dst_id = if args.csv_args.numeric {
             dst.parse::<usize>().unwrap()
         } else {
-            let node_id = nodes.len();
-            *nodes.entry(dst.to_string()).or_insert(node_id)
+            nodes.id(dst)
         };
 
+        max_node_id = max_node_id.max(Some(src_id.max(dst_id)));
         group_by.push(src_id, dst_id).unwrap();
         pl.light_update();
         line_id += 1;
@@ -114,14 +113,37 @@ pub fn from_csv(args: CliArgs) -> Result<()> {
     pl.done();
     log::info!("Arcs read: {}", line_id);
 
+    let num_nodes = if args.csv_args.numeric {
+        let inferred = max_node_id.map_or(0, |x| x + 1);
+        if let Some(num_nodes) = args.num_nodes {
+            ensure!(
+                inferred <= num_nodes,
+                "Found node id {} but the graph has {} nodes",
+                inferred - 1,
+                num_nodes
+            );
+            num_nodes
+        } else {
+            inferred
+        }
+    } else {
+        ensure!(
+            args.num_nodes.unwrap_or(nodes.len()) == nodes.len(),
+            "Found {} distinct node identifiers but the graph has {} nodes",
+            nodes.len(),
+            args.num_nodes.unwrap()
+        );
+        nodes.len()
+    };
+    log::info!("Nodes: {}", num_nodes);
+
     // convert the iter to a graph
     let g = Left(ArcListGraph::new(
-        args.num_nodes,
+        num_nodes,
         group_by
-            .iter()
+            .iter_dedup()
             .unwrap()
-            .map(|(src, dst, _)| (src, dst))
-            .dedup(),
+            .map(|(src, dst, _)| (src, dst)),
     ));
     // compress it
     let target_endianness = args.ca.endianness.clone();
@@ -130,7 +152,7 @@ pub fn from_csv(args: CliArgs) -> Result<()> {
     BVComp::parallel_endianness(
         &args.src,
         &g,
-        args.num_nodes,
+        num_nodes,
         args.ca.into(),
         thread_pool,
         dir,
@@ -140,15 +162,7 @@ pub fn from_csv(args: CliArgs) -> Result<()> {
 
     // save the nodes
     if !args.csv_args.numeric {
-        let mut file = std::fs::File::create(args.src.with_extension("nodes")).unwrap();
-        let mut buf = std::io::BufWriter::new(&mut file);
-        let mut nodes = nodes.into_iter().collect::<Vec<_>>();
-        // sort based on the idx
-        nodes.par_sort_by(|(_, a), (_, b)| a.cmp(b));
-        for (node, _) in nodes {
-            buf.write_all(node.as_bytes()).unwrap();
-            buf.write_all(b"\n").unwrap();
-        }
+        nodes.store(args.src.with_extension("nodes"))?;
     }
     Ok(())
 }
@@ -7,7 +7,9 @@
 use anyhow::Result;
 use clap::{ArgMatches, Command};
 
+pub mod arcs;
 pub mod csv;
+pub mod random;
 
 pub const COMMAND_NAME: &str = "from";
 
@@ -17,13 +19,17 @@ pub fn cli(command: Command) -> Command {
         .subcommand_required(true)
         .arg_required_else_help(true)
         .allow_external_subcommands(true);
+    let sub_command = arcs::cli(sub_command);
     let sub_command = csv::cli(sub_command);
+    let sub_command = random::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
 }
 
 pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
+        Some((arcs::COMMAND_NAME, sub_m)) => arcs::main(sub_m),
         Some((csv::COMMAND_NAME, sub_m)) => csv::main(sub_m),
+        Some((random::COMMAND_NAME, sub_m)) => random::main(sub_m),
         Some((command_name, _)) => {
             eprintln!("Unknown command: {:?}", command_name);
             std::process::exit(1);
@@ -0,0 +1,101 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::cli::common::*;
+use crate::graphs::random::{Model, RandomGraph};
+use crate::prelude::*;
+use anyhow::{ensure, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches, ValueEnum};
+use dsi_bitstream::prelude::{Endianness, BE};
+use std::path::PathBuf;
+use tempfile::Builder;
+
+pub const COMMAND_NAME: &str = "random";
+
+#[derive(Args, Debug)]
+#[command(about = "Generates a random graph without loops and compresses it, for testing and benchmarking. The same seed always yields the same graph.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the compressed graph.
+    pub dst: PathBuf,
+
+    #[arg(short, long)]
+    /// The number of nodes in the graph.
+    pub num_nodes: usize,
+
+    #[arg(short = 'd', long, default_value_t = 10.0)]
+    /// The target average degree.
+    pub avg_degree: f64,
+
+    #[arg(short, long, value_enum, default_value_t = RandomModel::Er)]
+    /// The model used to generate the successors.
+    pub model: RandomModel,
+
+    #[arg(short = 'x', long, default_value_t = 2.5)]
+    /// The exponent of the power-law model, which must be greater than two.
+    pub exponent: f64,
+
+    #[arg(short, long, default_value_t = 0)]
+    /// The seed of the pseudorandom number generator.
+    pub seed: u64,
+
+    #[clap(flatten)]
+    pub num_threads: NumThreadsArg,
+
+    #[clap(flatten)]
+    pub ca: CompressArgs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// The model of a random graph.
+pub enum RandomModel {
+    /// Erdös-Rényi: each arc is present independently with the same probability.
+    Er,
+    /// Power-law outdegrees and uniform successors.
+    Powerlaw,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    from_random(CliArgs::from_arg_matches(submatches)?)
+}
+
+pub fn from_random(args: CliArgs) -> Result<()> {
+    ensure!(
+        args.avg_degree >= 0.0,
+        "The average degree must be nonnegative"
+    );
+    let model = match args.model {
+        RandomModel::Er => Model::ErdosRenyi,
+        RandomModel::Powerlaw => {
+            ensure!(
+                args.exponent > 2.0,
+                "The exponent must be greater than two, got {}",
+                args.exponent
+            );
+            Model::PowerLaw {
+                exponent: args.exponent,
+            }
+        }
+    };
+    let g = RandomGraph::new(args.num_nodes, args.avg_degree, model, args.seed);
+
+    let target_endianness = args.ca.endianness.clone();
+    let dir = Builder::new().prefix("FromRandomCompress").tempdir()?;
+    let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
+    BVComp::parallel_endianness(
+        &args.dst,
+        &g,
+        args.num_nodes,
+        args.ca.into(),
+        thread_pool,
+        dir,
+        &target_endianness.unwrap_or_else(|| BE::NAME.into()),
+    )?;
+    Ok(())
+}
@@ -39,6 +39,44 @@ pub fn get_thread_pool(num_threads: usize) -> rayon::ThreadPool {
         .expect("Failed to create thread pool")
 }
 
+/// Returns an error for an endianness that cannot be handled by the compiled
+/// binaries, listing the supported ones.
+///
+/// If the endianness is valid but was not compiled in, the error names the
+/// feature enabling it.
+pub fn unknown_endianness(endianness: &str) -> anyhow::Error {
+    use dsi_bitstream::traits::Endianness;
+    let supported = [
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        dsi_bitstream::traits::BE::NAME,
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        dsi_bitstream::traits::LE::NAME,
+    ];
+    let feature = match endianness {
+        dsi_bitstream::traits::BE::NAME => "be_bins",
+        dsi_bitstream::traits::LE::NAME => "le_bins",
+        _ => {
+            return anyhow::anyhow!(
+                "Unknown endianness {:?}: the supported endiannesses are {}",
+                endianness,
+                supported.join(", ")
+            )
+        }
+    };
+    anyhow::anyhow!(
+        "Endianness {:?} is not compiled in (enable feature {}): the supported endiannesses are {}",
+        endianness,
+        feature,
+        supported.join(", ")
+    )
+}
+
 /// Appends a string to the filename of a path.
 ///
 /// # Panics
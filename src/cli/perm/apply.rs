@@ -0,0 +1,115 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::cli::common::*;
+use crate::prelude::*;
+use anyhow::{Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use epserde::prelude::*;
+use mmap_rs::MmapFlags;
+use std::path::PathBuf;
+use sux::traits::BitFieldSlice;
+use tempfile::Builder;
+
+pub const COMMAND_NAME: &str = "apply";
+
+#[derive(Args, Debug)]
+#[command(about = "Relabel a BVGraph with a permutation and compress the result", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    /// The filename of the permutation to apply.
+    pub perm: PathBuf,
+
+    /// The basename of the relabeled graph.
+    pub dst: PathBuf,
+
+    #[arg(short, long)]
+    /// Load the permutation in ε-serde format.
+    pub epserde: bool,
+
+    #[clap(flatten)]
+    pub num_threads: NumThreadsArg,
+
+    #[clap(flatten)]
+    pub batch_size: BatchSizeArg,
+
+    #[clap(flatten)]
+    pub ca: CompressArgs,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => apply::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => apply::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+pub fn apply<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let start = std::time::Instant::now();
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+
+    if args.epserde {
+        let perm = <Vec<usize>>::mmap(&args.perm, Flags::RANDOM_ACCESS)
+            .with_context(|| format!("Could not load permutation {}", args.perm.display()))?;
+        compress::<E>(args, &graph, perm.as_ref())?;
+    } else {
+        let perm = JavaPermutation::mmap(&args.perm, MmapFlags::RANDOM_ACCESS)
+            .with_context(|| format!("Could not load permutation {}", args.perm.display()))?;
+        compress::<E>(args, &graph, &perm)?;
+    }
+
+    log::info!(
+        "The relabeling took {:.3} seconds",
+        start.elapsed().as_secs_f64()
+    );
+    Ok(())
+}
+
+fn compress<E: Endianness>(
+    args: CliArgs,
+    graph: &impl SequentialGraph,
+    perm: &impl BitFieldSlice<usize>,
+) -> Result<()> {
+    let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
+    let target_endianness = args.ca.endianness.clone();
+    let dir = Builder::new().prefix("Relabel").tempdir()?;
+
+    let permuted = crate::transform::permute(graph, perm, args.batch_size.batch_size)?;
+
+    BVComp::parallel_endianness(
+        args.dst,
+        &permuted,
+        permuted.num_nodes(),
+        args.ca.into(),
+        &thread_pool,
+        dir,
+        &target_endianness.unwrap_or_else(|| E::NAME.into()),
+    )?;
+    Ok(())
+}
@@ -6,7 +6,7 @@
  */
 
 use crate::prelude::*;
-use anyhow::{Context, Result};
+use anyhow::{ensure, Context, Result};
 use clap::{ArgMatches, Args, Command, FromArgMatches};
 use dsi_bitstream::prelude::*;
 use epserde::prelude::Serialize;
@@ -27,6 +27,12 @@ pub struct CliArgs {
     #[arg(short, long)]
     /// Save the permutation in ε-serde format.
     pub epserde: bool,
+
+    #[arg(short, long, default_value_t = 0)]
+    /// The node from which the visit starts. Nodes that are not reachable
+    /// from the seed are visited afterwards, starting from the smallest
+    /// node not yet seen.
+    pub seed: usize,
 }
 
 pub fn cli(command: Command) -> Command {
@@ -47,7 +53,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => bfs::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
@@ -62,9 +68,16 @@ where
         .endianness::<E>()
         .load()?;
 
+    ensure!(
+        args.seed < graph.num_nodes(),
+        "The seed node {} does not exist: the graph has {} nodes",
+        args.seed,
+        graph.num_nodes()
+    );
+
     // create the permutation
     let mut perm = vec![0; graph.num_nodes()];
-    for (i, node_id) in crate::algo::BfsOrder::new(&graph).enumerate() {
+    for (i, node_id) in crate::algo::BfsOrder::new_from(&graph, args.seed).enumerate() {
         perm[node_id] = i;
     }
 
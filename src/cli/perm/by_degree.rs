@@ -0,0 +1,109 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use epserde::prelude::Serialize;
+use std::cmp::Reverse;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "by-degree";
+
+#[derive(Args, Debug)]
+#[command(about = "Compute a permutation sorting nodes by decreasing degree, breaking ties by node", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    /// A filename for the permutation.
+    pub perm: PathBuf,
+
+    #[arg(short, long)]
+    /// Save the permutation in ε-serde format.
+    pub epserde: bool,
+
+    #[arg(short, long)]
+    /// Sort by indegree instead of outdegree; indegrees are computed by a
+    /// scan of the arcs, which needs a counter per node in memory.
+    pub indegree: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => by_degree::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => by_degree::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+pub fn by_degree<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+
+    let degrees = if args.indegree {
+        crate::algo::in_degrees(&graph)
+    } else {
+        let mut pl = ProgressLogger::default();
+        pl.display_memory(true)
+            .item_name("node")
+            .expected_updates(Some(graph.num_nodes()));
+        pl.start("Scanning outdegrees...");
+        let mut degrees = Vec::with_capacity(graph.num_nodes());
+        for (_offset, degree) in graph.offset_deg_iter() {
+            degrees.push(degree);
+            pl.light_update();
+        }
+        pl.done();
+        degrees
+    };
+
+    // the sort is stable, so nodes with the same degree keep their order
+    let mut nodes = (0..graph.num_nodes()).collect::<Vec<_>>();
+    nodes.sort_by_key(|&node| Reverse(degrees[node]));
+    let mut perm = vec![0; graph.num_nodes()];
+    for (i, node) in nodes.into_iter().enumerate() {
+        perm[node] = i;
+    }
+
+    if args.epserde {
+        perm.store(&args.perm)
+            .with_context(|| format!("Could not write permutation to {}", args.perm.display()))?;
+    } else {
+        let mut file = std::fs::File::create(&args.perm)
+            .with_context(|| format!("Could not create permutation at {}", args.perm.display()))?;
+        let mut buf = BufWriter::new(&mut file);
+        for &word in &perm {
+            buf.write_all(&(word as u64).to_be_bytes())
+                .with_context(|| {
+                    format!("Could not write permutation to {}", args.perm.display())
+                })?;
+        }
+        buf.flush()?;
+    }
+    Ok(())
+}
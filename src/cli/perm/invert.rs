@@ -0,0 +1,89 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{ensure, Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use epserde::prelude::*;
+use mmap_rs::MmapFlags;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use sux::traits::BitFieldSlice;
+
+pub const COMMAND_NAME: &str = "invert";
+
+#[derive(Args, Debug)]
+#[command(about = "Invert a permutation, checking that it is a bijection", long_about = None)]
+pub struct CliArgs {
+    /// The filename of the permutation to invert.
+    pub src: PathBuf,
+
+    /// The path of the resulting permutation.
+    pub dst: PathBuf,
+
+    #[arg(short, long)]
+    /// Load and save the permutations in ε-serde format.
+    pub epserde: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    invert_perm(CliArgs::from_arg_matches(submatches)?)
+}
+
+/// Returns the inverse of the permutation of length `len` given by `perm`,
+/// or an error if `perm` is not a permutation of `0..len`.
+fn invert(len: usize, perm: impl Fn(usize) -> usize) -> Result<Vec<usize>> {
+    let mut inv = vec![usize::MAX; len];
+    for i in 0..len {
+        let v = perm(i);
+        ensure!(
+            v < len,
+            "Not a permutation: element {} is {}, but the length is {}",
+            i,
+            v,
+            len
+        );
+        ensure!(
+            inv[v] == usize::MAX,
+            "Not a permutation: {} appears at positions {} and {}",
+            v,
+            inv[v],
+            i
+        );
+        inv[v] = i;
+    }
+    Ok(inv)
+}
+
+pub fn invert_perm(args: CliArgs) -> Result<()> {
+    let start = std::time::Instant::now();
+
+    if args.epserde {
+        let perm = <Vec<usize>>::mmap(&args.src, Flags::RANDOM_ACCESS)
+            .with_context(|| format!("Could not load permutation {}", args.src.display()))?;
+        let inv = invert(perm.len(), |i| perm[i])?;
+        inv.store(&args.dst)
+            .with_context(|| format!("Could not store permutation to {}", args.dst.display()))?;
+    } else {
+        let perm = JavaPermutation::mmap(&args.src, MmapFlags::RANDOM_ACCESS)
+            .with_context(|| format!("Could not load permutation {}", args.src.display()))?;
+        let inv = invert(perm.as_ref().len(), |i| perm.get(i))?;
+        let mut writer = BufWriter::new(
+            std::fs::File::create(&args.dst)
+                .with_context(|| format!("Could not create {}", args.dst.display()))?,
+        );
+        for v in inv {
+            writer.write_all(&(v as u64).to_be_bytes())?;
+        }
+        writer.flush()?;
+    }
+    log::info!("Completed in {} seconds", start.elapsed().as_secs_f64());
+    Ok(())
+}
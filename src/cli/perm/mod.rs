@@ -7,8 +7,11 @@
 use anyhow::Result;
 use clap::{ArgMatches, Command};
 
+pub mod apply;
 pub mod bfs;
+pub mod by_degree;
 pub mod comp;
+pub mod invert;
 pub mod rand;
 
 pub const COMMAND_NAME: &str = "perm";
@@ -19,16 +22,22 @@ pub fn cli(command: Command) -> Command {
         .subcommand_required(true)
         .arg_required_else_help(true)
         .allow_external_subcommands(true);
+    let sub_command = apply::cli(sub_command);
     let sub_command = bfs::cli(sub_command);
+    let sub_command = by_degree::cli(sub_command);
     let sub_command = comp::cli(sub_command);
+    let sub_command = invert::cli(sub_command);
     let sub_command = rand::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
 }
 
 pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
+        Some((apply::COMMAND_NAME, sub_m)) => apply::main(sub_m),
         Some((bfs::COMMAND_NAME, sub_m)) => bfs::main(sub_m),
+        Some((by_degree::COMMAND_NAME, sub_m)) => by_degree::main(sub_m),
         Some((comp::COMMAND_NAME, sub_m)) => comp::main(sub_m),
+        Some((invert::COMMAND_NAME, sub_m)) => invert::main(sub_m),
         Some((rand::COMMAND_NAME, sub_m)) => rand::main(sub_m),
         Some((command_name, _)) => {
             eprintln!("Unknown command: {:?}", command_name);
@@ -9,6 +9,7 @@ use clap::{ArgMatches, Command};
 
 pub mod llp;
 pub mod pad;
+pub mod partition;
 
 pub const COMMAND_NAME: &str = "run";
 
@@ -20,6 +21,7 @@ pub fn cli(command: Command) -> Command {
         .allow_external_subcommands(true);
     let sub_command = llp::cli(sub_command);
     let sub_command = pad::cli(sub_command);
+    let sub_command = partition::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
 }
 
@@ -27,6 +29,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
         Some((llp::COMMAND_NAME, sub_m)) => llp::main(sub_m),
         Some((pad::COMMAND_NAME, sub_m)) => pad::main(sub_m),
+        Some((partition::COMMAND_NAME, sub_m)) => partition::main(sub_m),
         Some((command_name, _)) => {
             eprintln!("Unknown command: {:?}", command_name);
             std::process::exit(1);
@@ -0,0 +1,91 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::algo::{partition_large_components, wcc};
+use crate::prelude::*;
+use anyhow::{ensure, Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use epserde::prelude::*;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+pub const COMMAND_NAME: &str = "partition";
+
+#[derive(Args, Debug)]
+#[command(about = "Assign the nodes of a graph to balanced shards without splitting weakly connected components", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the graph.
+    pub src: PathBuf,
+
+    /// The path of the resulting node-to-shard array.
+    pub dst: PathBuf,
+
+    #[arg(short, long)]
+    /// The number of shards.
+    pub shards: usize,
+
+    #[arg(short, long, default_value_t = 1)]
+    /// Assign only the nodes of components with at least this many nodes;
+    /// the other nodes are mapped to the maximum value of the array type.
+    pub min_size: usize,
+
+    #[arg(short, long)]
+    /// Save the array in ε-serde format.
+    pub epserde: bool,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    let args = CliArgs::from_arg_matches(submatches)?;
+
+    match get_endianness(&args.src)?.as_str() {
+        #[cfg(any(
+            feature = "be_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        BE::NAME => partition::<BE>(args),
+        #[cfg(any(
+            feature = "le_bins",
+            not(any(feature = "be_bins", feature = "le_bins"))
+        ))]
+        LE::NAME => partition::<LE>(args),
+        e => Err(crate::cli::unknown_endianness(e)),
+    }
+}
+
+pub fn partition<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    ensure!(args.shards > 0, "The number of shards must be positive");
+    let start = std::time::Instant::now();
+
+    let graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+    let shards = partition_large_components(&wcc(&graph), args.shards, args.min_size);
+
+    if args.epserde {
+        shards
+            .store(&args.dst)
+            .with_context(|| format!("Could not store shards to {}", args.dst.display()))?;
+    } else {
+        let mut writer = BufWriter::new(
+            std::fs::File::create(&args.dst)
+                .with_context(|| format!("Could not create {}", args.dst.display()))?,
+        );
+        for shard in shards {
+            writer.write_all(&(shard as u64).to_be_bytes())?;
+        }
+        writer.flush()?;
+    }
+    log::info!("Completed in {} seconds", start.elapsed().as_secs_f64());
+    Ok(())
+}
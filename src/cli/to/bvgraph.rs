@@ -7,7 +7,7 @@
 
 use crate::cli::common::*;
 use crate::prelude::*;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{ArgMatches, Args, Command, FromArgMatches};
 use dsi_bitstream::prelude::*;
 use epserde::deser::DeserializeInner;
@@ -23,7 +23,8 @@ pub struct CliArgs {
     /// The basename of the graph.
     pub src: PathBuf,
     /// The basename for the newly compressed graph.
-    pub dst: PathBuf,
+    #[arg(required_unless_present = "dry_run")]
+    pub dst: Option<PathBuf>,
 
     #[clap(flatten)]
     pub num_threads: NumThreadsArg,
@@ -37,6 +38,11 @@ pub struct CliArgs {
 
     #[clap(flatten)]
     pub ca: CompressArgs,
+
+    #[clap(long)]
+    /// Do not write the graph, but print the estimated size of the .graph
+    /// file and the resulting number of bits per arc.
+    pub dry_run: bool,
 }
 
 pub fn cli(command: Command) -> Command {
@@ -65,7 +71,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => compress::<LE>(args, target_endianness, permutation)?,
-        e => panic!("Unknown endianness: {}", e),
+        e => return Err(crate::cli::unknown_endianness(e)),
     };
 
     log::info!(
@@ -83,6 +89,10 @@ pub fn compress<E: Endianness + Clone + Send + Sync>(
 where
     for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
 {
+    if args.dry_run {
+        return estimate::<E>(args, permutation).map(|_| ());
+    }
+    let dst = args.dst.context("No destination basename")?;
     let dir = Builder::new().prefix("Recompress").tempdir()?;
 
     let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
@@ -111,7 +121,7 @@ where
                 start.elapsed().as_secs_f64()
             );
             BVComp::parallel_endianness(
-                args.dst,
+                dst,
                 &sorted,
                 sorted.num_nodes(),
                 args.ca.into(),
@@ -121,7 +131,7 @@ where
             )?;
         } else {
             BVComp::parallel_endianness(
-                args.dst,
+                dst,
                 &graph,
                 graph.num_nodes(),
                 args.ca.into(),
@@ -148,7 +158,7 @@ where
             );
 
             BVComp::parallel_endianness(
-                args.dst,
+                dst,
                 &permuted,
                 permuted.num_nodes(),
                 args.ca.into(),
@@ -158,7 +168,7 @@ where
             )?;
         } else {
             BVComp::parallel_endianness(
-                args.dst,
+                dst,
                 &seq_graph,
                 seq_graph.num_nodes(),
                 args.ca.into(),
@@ -170,3 +180,44 @@ where
     }
     Ok(())
 }
+
+/// Prints, and returns, the length in bits of the graph bitstream that
+/// would be written by [`compress`].
+///
+/// The estimate is computed by [`BVComp::par_estimate_size`], after applying
+/// the permutation, if any, splitting the graph in the same way as
+/// [`compress`] with the same number of threads.
+pub fn estimate<E: Endianness + Clone + Send + Sync>(
+    args: CliArgs,
+    permutation: Option<JavaPermutation>,
+) -> Result<u64>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
+    let seq_graph = BVGraphSeq::with_basename(&args.src)
+        .endianness::<E>()
+        .load()?;
+    let num_arcs = seq_graph.num_arcs_hint();
+
+    let bits = if let Some(permutation) = permutation {
+        let batch_size = args.batch_size.batch_size;
+        log::info!("Permuting graph with batch size {}", batch_size);
+        let permuted = crate::transform::permute(&seq_graph, &permutation, batch_size)?;
+        BVComp::par_estimate_size(&permuted, args.ca.into(), &thread_pool)?
+    } else {
+        BVComp::par_estimate_size(&seq_graph, args.ca.into(), &thread_pool)?
+    };
+
+    // The .graph file is padded to a multiple of the word size
+    let word_bits = usize::BITS as u64;
+    println!("Estimated bits: {}", bits);
+    println!(
+        "Estimated bytes: {}",
+        bits.div_ceil(word_bits) * word_bits / 8
+    );
+    if let Some(num_arcs) = num_arcs {
+        println!("Bits per arc: {:.3}", bits as f64 / num_arcs as f64);
+    }
+    Ok(bits)
+}
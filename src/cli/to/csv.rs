@@ -7,12 +7,13 @@
 
 use crate::graphs::bvgraph::{get_endianness, CodeRead};
 use crate::traits::SequentialLabeling;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{ArgMatches, Args, Command, FromArgMatches};
 use dsi_bitstream::prelude::*;
 use dsi_progress_logger::prelude::*;
 use lender::*;
-use std::io::Write;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 
 pub const COMMAND_NAME: &str = "csv";
@@ -26,6 +27,14 @@ pub struct CliArgs {
     #[arg(long, default_value_t = ',')]
     /// The character used to separate the fields in the CSV.
     pub separator: char,
+
+    #[arg(short, long)]
+    /// Write the arcs to this file instead of standard output.
+    pub output: Option<PathBuf>,
+
+    #[arg(long)]
+    /// Write node ids starting from one instead of zero.
+    pub one_based: bool,
 }
 
 pub fn cli(command: Command) -> Command {
@@ -46,7 +55,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => to_csv::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
@@ -60,7 +69,13 @@ where
     let num_nodes = graph.num_nodes();
 
     // read the csv and put it inside the sort pairs
-    let mut stdout = std::io::BufWriter::new(std::io::stdout().lock());
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path).with_context(|| {
+            format!("Could not create {}", path.display())
+        })?)),
+        None => Box::new(BufWriter::new(std::io::stdout().lock())),
+    };
+    let base = args.one_based as usize;
     let mut pl = ProgressLogger::default();
     pl.display_memory(true)
         .item_name("nodes")
@@ -69,11 +84,13 @@ where
 
     for_! ( (src, succ) in graph.iter() {
         for dst in succ {
-            writeln!(stdout, "{}{}{}", src, args.separator, dst)?;
+            writeln!(writer, "{}{}{}", src + base, args.separator, dst + base)?;
         }
         pl.light_update();
     });
 
+    writer.flush()?;
+
     pl.done();
     Ok(())
 }
@@ -99,7 +99,7 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
         LE::NAME => {
             impl_convert!(args, LE, BE);
         }
-        e => panic!("Unknown endianness: {}", e),
+        e => return Err(crate::cli::unknown_endianness(e)),
     };
 
     Ok(())
@@ -167,4 +167,58 @@ impl<D: Decode, E: Encode> Decode for Converter<D, E> {
         self.encoder.write_residual(res).unwrap();
         res
     }
+    #[inline(always)]
+    fn try_read_outdegree(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_outdegree()?;
+        self.encoder.write_outdegree(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_reference_offset(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_reference_offset()?;
+        self.encoder.write_reference_offset(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_block_count(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_block_count()?;
+        self.encoder.write_block_count(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_block(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_block()?;
+        self.encoder.write_block(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_interval_count(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_interval_count()?;
+        self.encoder.write_interval_count(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_interval_start(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_interval_start()?;
+        self.encoder.write_interval_start(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_interval_len(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_interval_len()?;
+        self.encoder.write_interval_len(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_first_residual(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_first_residual()?;
+        self.encoder.write_first_residual(res)?;
+        Ok(res)
+    }
+    #[inline(always)]
+    fn try_read_residual(&mut self) -> Result<u64> {
+        let res = self.decoder.try_read_residual()?;
+        self.encoder.write_residual(res)?;
+        Ok(res)
+    }
 }
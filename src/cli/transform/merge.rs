@@ -0,0 +1,130 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::cli::common::*;
+use crate::graphs::arc_list_graph::ArcListGraph;
+use crate::prelude::*;
+use anyhow::{Context, Result};
+use clap::{ArgMatches, Args, Command, FromArgMatches};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use lender::*;
+use std::path::{Path, PathBuf};
+use tempfile::Builder;
+
+pub const COMMAND_NAME: &str = "merge";
+
+#[derive(Args, Debug)]
+#[command(about = "Merges graphs on disjoint sets of nodes into a single graph. The nodes of each graph are shifted by the sum of the number of nodes of the previous graphs.", long_about = None)]
+pub struct CliArgs {
+    /// The basename of the merged graph.
+    pub dst: PathBuf,
+    /// The basenames of the graphs to merge, in order.
+    #[arg(required = true, num_args = 1..)]
+    pub srcs: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    pub num_threads: NumThreadsArg,
+
+    #[clap(flatten)]
+    pub batch_size: BatchSizeArg,
+
+    #[clap(flatten)]
+    pub ca: CompressArgs,
+}
+
+pub fn cli(command: Command) -> Command {
+    command.subcommand(CliArgs::augment_args(Command::new(COMMAND_NAME)).display_order(0))
+}
+
+pub fn main(submatches: &ArgMatches) -> Result<()> {
+    merge(CliArgs::from_arg_matches(submatches)?)
+}
+
+/// Pushes the arcs of the graph with the given basename, shifted by `shift`,
+/// into `sort_pairs`, and returns the number of nodes of the graph.
+fn push_arcs<E: Endianness + 'static>(
+    basename: &Path,
+    shift: usize,
+    sort_pairs: &mut SortPairs,
+    pl: &mut ProgressLogger,
+) -> Result<usize>
+where
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+{
+    let seq_graph = crate::graphs::bvgraph::sequential::BVGraphSeq::with_basename(basename)
+        .endianness::<E>()
+        .load()
+        .with_context(|| format!("Could not load graph at {}", basename.display()))?;
+    for_!((src, succ) in seq_graph.iter() {
+        for dst in succ {
+            sort_pairs.push(src + shift, dst + shift)?;
+        }
+        pl.light_update();
+    });
+    Ok(seq_graph.num_nodes())
+}
+
+/// Merges the graphs in `args.srcs` into a single graph with
+/// basename `args.dst`.
+///
+/// The nodes of the `i`-th graph are shifted by the sum of the number of
+/// nodes of the previous graphs, so the merged graph has as many nodes as
+/// the input graphs together, and no arc crosses the boundary between the
+/// ranges of two input graphs.
+pub fn merge(args: CliArgs) -> Result<()> {
+    let dir = Builder::new().prefix("MergePairs").tempdir()?;
+    let mut sort_pairs = SortPairs::new(args.batch_size.batch_size, dir.path())?;
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true).item_name("node");
+    pl.start("Reading graphs...");
+
+    let mut num_nodes = 0;
+    for src in &args.srcs {
+        let shift = num_nodes;
+        let src_num_nodes = match get_endianness(src)?.as_str() {
+            #[cfg(any(
+                feature = "be_bins",
+                not(any(feature = "be_bins", feature = "le_bins"))
+            ))]
+            BE::NAME => push_arcs::<BE>(src, shift, &mut sort_pairs, &mut pl)?,
+            #[cfg(any(
+                feature = "le_bins",
+                not(any(feature = "be_bins", feature = "le_bins"))
+            ))]
+            LE::NAME => push_arcs::<LE>(src, shift, &mut sort_pairs, &mut pl)?,
+            e => return Err(crate::cli::unknown_endianness(e)),
+        };
+        num_nodes = shift
+            .checked_add(src_num_nodes)
+            .with_context(|| format!("Too many nodes after merging {}", src.display()))?;
+        log::info!("Nodes of {} start at {}", src.display(), shift);
+    }
+    pl.done();
+
+    let g = Left(ArcListGraph::new(
+        num_nodes,
+        sort_pairs
+            .iter()
+            .context("Could not read sorted arcs")?
+            .map(|(src, dst, _)| (src, dst)),
+    ));
+
+    let target_endianness = args.ca.endianness.clone();
+    let dir = Builder::new().prefix("MergeCompress").tempdir()?;
+    let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
+    BVComp::parallel_endianness(
+        &args.dst,
+        &g,
+        num_nodes,
+        args.ca.into(),
+        thread_pool,
+        dir,
+        &target_endianness.unwrap_or_else(|| BE::NAME.into()),
+    )?;
+    Ok(())
+}
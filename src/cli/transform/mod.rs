@@ -7,6 +7,7 @@
 use anyhow::Result;
 use clap::{ArgMatches, Command};
 
+pub mod merge;
 pub mod simplify;
 pub mod transpose;
 
@@ -18,6 +19,7 @@ pub fn cli(command: Command) -> Command {
         .subcommand_required(true)
         .arg_required_else_help(true)
         .allow_external_subcommands(true);
+    let sub_command = merge::cli(sub_command);
     let sub_command = simplify::cli(sub_command);
     let sub_command = transpose::cli(sub_command);
     command.subcommand(sub_command.display_order(0))
@@ -25,6 +27,7 @@ pub fn cli(command: Command) -> Command {
 
 pub fn main(submatches: &ArgMatches) -> Result<()> {
     match submatches.subcommand() {
+        Some((merge::COMMAND_NAME, sub_m)) => merge::main(sub_m),
         Some((simplify::COMMAND_NAME, sub_m)) => simplify::main(sub_m),
         Some((transpose::COMMAND_NAME, sub_m)) => transpose::main(sub_m),
         Some((command_name, _)) => {
@@ -16,7 +16,7 @@ use tempfile::Builder;
 pub const COMMAND_NAME: &str = "transpose";
 
 #[derive(Args, Debug)]
-#[command(about = "Transpose a BVGraph", long_about = None)]
+#[command(about = "Transpose a BVGraph. If the .ef file of the graph is available, the graph is split in as many parts as the number of threads (see --num-threads), and each thread sorts the transposed arcs of its part in batches of --batch-size arcs.", long_about = None)]
 pub struct CliArgs {
     /// The basename of the graph.
     pub src: PathBuf,
@@ -51,18 +51,44 @@ pub fn main(submatches: &ArgMatches) -> Result<()> {
             not(any(feature = "be_bins", feature = "le_bins"))
         ))]
         LE::NAME => transpose::<LE>(args),
-        e => panic!("Unknown endianness: {}", e),
+        e => Err(crate::cli::unknown_endianness(e)),
     }
 }
 
-pub fn transpose<E: Endianness + 'static>(args: CliArgs) -> Result<()>
+pub fn transpose<E: Endianness + Send + Sync + 'static>(args: CliArgs) -> Result<()>
 where
     for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
 {
     let thread_pool = crate::cli::get_thread_pool(args.num_threads.num_threads);
 
-    // TODO!: speed it up by using random access graph if possible
     let transposed = args.dst.unwrap_or_else(|| append(&args.src, "-t"));
+    let target_endianness = args.ca.endianness.clone();
+    let dir = Builder::new().prefix("CompressTransposed").tempdir()?;
+
+    // if the .ef file exists, we can create batches in parallel
+    if std::fs::metadata(args.src.with_extension(EF_EXTENSION)).is_ok_and(|x| x.is_file()) {
+        log::info!(".ef file found, using transpose split");
+        let graph = crate::graphs::bvgraph::random_access::BVGraph::with_basename(&args.src)
+            .endianness::<E>()
+            .load()?;
+
+        let sorted =
+            crate::transform::transpose_split(&graph, args.batch_size.batch_size, &thread_pool)?;
+
+        BVComp::parallel_endianness(
+            transposed,
+            &sorted,
+            sorted.num_nodes(),
+            args.ca.into(),
+            &thread_pool,
+            dir,
+            &target_endianness.unwrap_or_else(|| E::NAME.into()),
+        )?;
+
+        return Ok(());
+    }
+
+    log::warn!("The .ef file was not found so the transposition will proceed sequentially. This may be slow. To speed it up, you can use `webgraph build ef {}` which would allow us create batches in parallel", args.src.display());
 
     let seq_graph = crate::graphs::bvgraph::sequential::BVGraphSeq::with_basename(&args.src)
         .endianness::<E>()
@@ -71,8 +97,6 @@ where
     // transpose the graph
     let sorted = crate::transform::transpose(&seq_graph, args.batch_size.batch_size).unwrap();
 
-    let target_endianness = args.ca.endianness.clone();
-    let dir = Builder::new().prefix("CompressTransposed").tempdir()?;
     BVComp::parallel_endianness(
         transposed,
         &sorted,
@@ -0,0 +1,77 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::bvcomp_and_read::CompFlagsFuzz;
+use crate::graphs::bvgraph::sequential::Iter;
+use crate::prelude::*;
+use anyhow::Result;
+use arbitrary::Arbitrary;
+use dsi_bitstream::prelude::*;
+
+/// The type of the iterators returned by [`iter_from_bytes`].
+pub type BytesIter<E> = Iter<DynCodesDecoder<E, BufBitReader<E, MemWordReader<u32, Vec<u32>>>>>;
+
+/// Returns a sequential iterator decoding a graph with `num_nodes` nodes
+/// from a bitstream in memory, without any file.
+///
+/// The bitstream is padded with enough ones that the iterator can always
+/// decode all nodes, either successfully or with an error: as codes made of
+/// ones represent small values, on the padding the checks on the decoded
+/// values end each node after at most `3 * num_nodes + 8` codes of at most
+/// four bits. A zero-padded bitstream would make unary codes, and thus the
+/// iterator, loop forever.
+pub fn iter_from_bytes<E: Endianness>(
+    bytes: &[u8],
+    comp_flags: &CompFlags,
+    num_nodes: usize,
+) -> Result<BytesIter<E>>
+where
+    BufBitReader<E, MemWordReader<u32, Vec<u32>>>: CodeRead<E>,
+{
+    let padding = (4 * num_nodes * (3 * num_nodes + 8)).div_ceil(32) + 1;
+    let mut words = Vec::with_capacity(bytes.len().div_ceil(4) + padding);
+    for chunk in bytes.chunks(4) {
+        // Missing bytes of the last word are ones, too
+        let mut word = [u8::MAX; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        words.push(u32::from_ne_bytes(word));
+    }
+    words.extend(std::iter::repeat_n(u32::MAX, padding));
+
+    let decoder = DynCodesDecoder::new(
+        BufBitReader::<E, _>::new(MemWordReader::new(words)),
+        comp_flags,
+    )?;
+    Ok(Iter::new(
+        decoder,
+        num_nodes,
+        comp_flags.compression_window,
+        comp_flags.min_interval_length,
+    ))
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct FuzzCase {
+    pub compression_flags: CompFlagsFuzz,
+    pub num_nodes: u8,
+    pub data: Vec<u8>,
+}
+
+pub fn harness(data: FuzzCase) {
+    let comp_flags: CompFlags = data.compression_flags.into();
+    let num_nodes = data.num_nodes as usize;
+    let mut iter = iter_from_bytes::<BE>(&data.data, &comp_flags, num_nodes).unwrap();
+    // Each node must either be decoded correctly or result in an error
+    for _ in 0..num_nodes {
+        match iter.next_successors() {
+            Ok(succ) => {
+                assert!(succ.len() <= num_nodes);
+                assert!(succ.iter().all(|&succ| succ < num_nodes));
+            }
+            Err(_) => break,
+        }
+    }
+}
@@ -5,3 +5,4 @@
  */
 
 pub mod bvcomp_and_read;
+pub mod decode;
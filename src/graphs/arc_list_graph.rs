@@ -13,6 +13,13 @@ use lender::*;
 ///
 /// If for every source the arcs are sorted by destination, the
 /// successors of the graph will be sorted.
+///
+/// The order of the arcs is not checked, and arcs that are not sorted by
+/// source are silently skipped or returned as successors of the wrong node.
+/// Use [`new_checked`](ArcListGraph::new_checked),
+/// [`new_labeled_checked`](ArcListGraph::new_labeled_checked) or
+/// [`validate`](ArcListGraph::validate) to check the order of the arcs at the
+/// cost of an additional pass.
 #[derive(Clone)]
 pub struct ArcListGraph<I: Clone> {
     num_nodes: usize,
@@ -30,6 +37,52 @@ impl<L: Clone + Copy + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clon
     }
 }
 
+impl<L, I: IntoIterator<Item = (usize, usize, L)> + Clone> ArcListGraph<I> {
+    /// Checks that the arcs are sorted in lexicographical order, that is, by
+    /// source and then by destination, and that their endpoints are smaller
+    /// than the number of nodes.
+    ///
+    /// The check is performed by a full pass on a clone of the iterator, so
+    /// it is cheap only if the arcs have already been collected, as in the
+    /// case of a slice or vector.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let mut prev = None;
+        for (i, (src, dst, _)) in self.into_iter.clone().into_iter().enumerate() {
+            anyhow::ensure!(
+                src < self.num_nodes && dst < self.num_nodes,
+                "Arc {} ({}, {}) has an endpoint not smaller than the number of nodes ({})",
+                i,
+                src,
+                dst,
+                self.num_nodes
+            );
+            if let Some((prev_src, prev_dst)) = prev {
+                anyhow::ensure!(
+                    (prev_src, prev_dst) <= (src, dst),
+                    "Arc {} ({}, {}) follows arc ({}, {}): arcs are not sorted",
+                    i,
+                    src,
+                    dst,
+                    prev_src,
+                    prev_dst
+                );
+            }
+            prev = Some((src, dst));
+        }
+        Ok(())
+    }
+}
+
+impl<L: Clone + Copy + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> ArcListGraph<I> {
+    /// Creates a new arc list graph from the given [`IntoIterator`], checking
+    /// with [`validate`](ArcListGraph::validate) that the arcs are sorted.
+    pub fn new_labeled_checked(num_nodes: usize, iter: I) -> anyhow::Result<Self> {
+        let graph = Self::new_labeled(num_nodes, iter);
+        graph.validate()?;
+        Ok(graph)
+    }
+}
+
 impl<I: Iterator<Item = (usize, usize)> + Clone>
     ArcListGraph<std::iter::Map<I, fn((usize, usize)) -> (usize, usize, ())>>
 {
@@ -44,6 +97,17 @@ impl<I: Iterator<Item = (usize, usize)> + Clone>
             into_iter: iter.into_iter().map(|(src, dst)| (src, dst, ())),
         }
     }
+
+    /// Creates a new arc list graph from the given [`IntoIterator`], checking
+    /// with [`validate`](ArcListGraph::validate) that the arcs are sorted.
+    pub fn new_checked(
+        num_nodes: usize,
+        iter: impl IntoIterator<IntoIter = I>,
+    ) -> anyhow::Result<Self> {
+        let graph = Self::new(num_nodes, iter);
+        graph.validate()?;
+        Ok(graph)
+    }
 }
 
 impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> SplitLabeling
@@ -60,6 +124,11 @@ where
     }
 }
 
+/// The lender returned by [`ArcListGraph`].
+///
+/// Differently from [`ArcListGraph`], the iterator on the arcs needs not be
+/// [`Clone`], so this lender can be used directly to compress a stream of
+/// sorted arcs that can be read only once, such as standard input.
 #[derive(Clone)]
 pub struct Iter<L, I: IntoIterator<Item = (usize, usize, L)>> {
     num_nodes: usize,
@@ -68,7 +137,7 @@ pub struct Iter<L, I: IntoIterator<Item = (usize, usize, L)>> {
     iter: I::IntoIter,
 }
 
-unsafe impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> SortedLender
+unsafe impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)>> SortedLender
     for Iter<L, I>
 {
 }
@@ -88,20 +157,20 @@ impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)>> Iter<L, I> {
     }
 }
 
-impl<'succ, L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone>
-    NodeLabelsLender<'succ> for Iter<L, I>
+impl<'succ, L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)>> NodeLabelsLender<'succ>
+    for Iter<L, I>
 {
     type Label = (usize, L);
     type IntoIterator = Succ<'succ, L, I>;
 }
 
-impl<'succ, L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> Lending<'succ>
+impl<'succ, L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)>> Lending<'succ>
     for Iter<L, I>
 {
     type Lend = (usize, <Self as NodeLabelsLender<'succ>>::IntoIterator);
 }
 
-impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> Lender for Iter<L, I> {
+impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)>> Lender for Iter<L, I> {
     fn next(&mut self) -> Option<Lend<'_, Self>> {
         self.curr_node = self.curr_node.wrapping_add(1);
         if self.curr_node == self.num_nodes {
@@ -121,9 +190,7 @@ impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> Lend
     }
 }
 
-impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> ExactSizeLender
-    for Iter<L, I>
-{
+impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)>> ExactSizeLender for Iter<L, I> {
     fn len(&self) -> usize {
         self.num_nodes - self.curr_node.wrapping_add(1)
     }
@@ -164,9 +231,8 @@ impl<L: Clone + 'static, I: IntoIterator<Item = (usize, usize, L)> + Clone> Sequ
     #[inline(always)]
     fn iter_from(&self, from: usize) -> Self::Lender<'_> {
         let mut iter = Iter::new(self.num_nodes, self.into_iter.clone().into_iter());
-        for _ in 0..from {
-            iter.next();
-        }
+        // stops early if from is past the last node
+        let _ = iter.advance_by(from);
 
         iter
     }
@@ -221,3 +287,21 @@ fn test() -> anyhow::Result<()> {
     assert_eq!(g, g2);
     Ok(())
 }
+
+#[cfg(test)]
+#[cfg_attr(test, test)]
+fn test_validate() {
+    let arcs = [(0, 1), (0, 2), (0, 2), (1, 0), (3, 3)];
+    assert!(ArcListGraph::new_checked(4, arcs).is_ok());
+    // Not sorted by source
+    assert!(ArcListGraph::new_checked(4, [(1, 0), (0, 1)]).is_err());
+    // Not sorted by destination
+    assert!(ArcListGraph::new_checked(4, [(0, 2), (0, 1)]).is_err());
+    // Out of range
+    assert!(ArcListGraph::new_checked(3, arcs).is_err());
+    assert!(ArcListGraph::new_checked(4, [(0, 4)]).is_err());
+
+    assert!(ArcListGraph::new_labeled_checked(3, [(0, 1, 'a'), (2, 0, 'b')]).is_ok());
+    let g = ArcListGraph::new_labeled(3, [(2, 0, 'b'), (0, 1, 'a')]);
+    assert!(g.validate().is_err());
+}
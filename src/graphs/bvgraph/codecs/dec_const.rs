@@ -120,12 +120,12 @@ impl<
 macro_rules! select_code_read {
     ($self:ident, $code:expr, $k: expr) => {
         match $code {
-            const_codes::UNARY => $self.code_reader.read_unary().unwrap(),
-            const_codes::GAMMA => $self.code_reader.read_gamma().unwrap(),
-            const_codes::DELTA => $self.code_reader.read_delta().unwrap(),
-            const_codes::ZETA if $k == 1 => $self.code_reader.read_gamma().unwrap(),
-            const_codes::ZETA if $k == 3 => $self.code_reader.read_zeta3().unwrap(),
-            const_codes::ZETA => $self.code_reader.read_zeta(K as u64).unwrap(),
+            const_codes::UNARY => $self.code_reader.read_unary(),
+            const_codes::GAMMA => $self.code_reader.read_gamma(),
+            const_codes::DELTA => $self.code_reader.read_delta(),
+            const_codes::ZETA if $k == 1 => $self.code_reader.read_gamma(),
+            const_codes::ZETA if $k == 3 => $self.code_reader.read_zeta3(),
+            const_codes::ZETA => $self.code_reader.read_zeta(K as u64),
             _ => panic!("Only values in the range [0..4) are allowed to represent codes"),
         }
     };
@@ -144,43 +144,80 @@ impl<
 {
     #[inline(always)]
     fn read_outdegree(&mut self) -> u64 {
-        select_code_read!(self, OUTDEGREES, K)
+        select_code_read!(self, OUTDEGREES, K).unwrap()
     }
 
     #[inline(always)]
     fn read_reference_offset(&mut self) -> u64 {
-        select_code_read!(self, REFERENCES, K)
+        select_code_read!(self, REFERENCES, K).unwrap()
     }
 
     #[inline(always)]
     fn read_block_count(&mut self) -> u64 {
-        select_code_read!(self, BLOCKS, K)
+        select_code_read!(self, BLOCKS, K).unwrap()
     }
     #[inline(always)]
     fn read_block(&mut self) -> u64 {
-        select_code_read!(self, BLOCKS, K)
+        select_code_read!(self, BLOCKS, K).unwrap()
     }
 
     #[inline(always)]
     fn read_interval_count(&mut self) -> u64 {
-        select_code_read!(self, INTERVALS, K)
+        select_code_read!(self, INTERVALS, K).unwrap()
     }
     #[inline(always)]
     fn read_interval_start(&mut self) -> u64 {
-        select_code_read!(self, INTERVALS, K)
+        select_code_read!(self, INTERVALS, K).unwrap()
     }
     #[inline(always)]
     fn read_interval_len(&mut self) -> u64 {
-        select_code_read!(self, INTERVALS, K)
+        select_code_read!(self, INTERVALS, K).unwrap()
     }
 
     #[inline(always)]
     fn read_first_residual(&mut self) -> u64 {
-        select_code_read!(self, RESIDUALS, K)
+        select_code_read!(self, RESIDUALS, K).unwrap()
     }
     #[inline(always)]
     fn read_residual(&mut self) -> u64 {
-        select_code_read!(self, RESIDUALS, K)
+        select_code_read!(self, RESIDUALS, K).unwrap()
+    }
+
+    #[inline(always)]
+    fn try_read_outdegree(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, OUTDEGREES, K)?)
+    }
+    #[inline(always)]
+    fn try_read_reference_offset(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, REFERENCES, K)?)
+    }
+    #[inline(always)]
+    fn try_read_block_count(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, BLOCKS, K)?)
+    }
+    #[inline(always)]
+    fn try_read_block(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, BLOCKS, K)?)
+    }
+    #[inline(always)]
+    fn try_read_interval_count(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, INTERVALS, K)?)
+    }
+    #[inline(always)]
+    fn try_read_interval_start(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, INTERVALS, K)?)
+    }
+    #[inline(always)]
+    fn try_read_interval_len(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, INTERVALS, K)?)
+    }
+    #[inline(always)]
+    fn try_read_first_residual(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, RESIDUALS, K)?)
+    }
+    #[inline(always)]
+    fn try_read_residual(&mut self) -> Result<u64> {
+        Ok(select_code_read!(self, RESIDUALS, K)?)
     }
 }
 
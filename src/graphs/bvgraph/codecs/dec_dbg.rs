@@ -74,4 +74,58 @@ impl<CR: Decode> Decode for DebugDecoder<CR> {
         eprintln!("residual: {}", residual);
         residual
     }
+
+    fn try_read_outdegree(&mut self) -> anyhow::Result<u64> {
+        let outdegree = self.cr.try_read_outdegree()?;
+        eprintln!("outdegree: {}", outdegree);
+        Ok(outdegree)
+    }
+
+    fn try_read_reference_offset(&mut self) -> anyhow::Result<u64> {
+        let reference_offset = self.cr.try_read_reference_offset()?;
+        eprintln!("reference_offset: {}", reference_offset);
+        Ok(reference_offset)
+    }
+
+    fn try_read_block_count(&mut self) -> anyhow::Result<u64> {
+        let block_count = self.cr.try_read_block_count()?;
+        eprintln!("block_count: {}", block_count);
+        Ok(block_count)
+    }
+
+    fn try_read_block(&mut self) -> anyhow::Result<u64> {
+        let blocks = self.cr.try_read_block()?;
+        eprintln!("blocks: {}", blocks);
+        Ok(blocks)
+    }
+
+    fn try_read_interval_count(&mut self) -> anyhow::Result<u64> {
+        let interval_count = self.cr.try_read_interval_count()?;
+        eprintln!("interval_count: {}", interval_count);
+        Ok(interval_count)
+    }
+
+    fn try_read_interval_start(&mut self) -> anyhow::Result<u64> {
+        let interval_start = self.cr.try_read_interval_start()?;
+        eprintln!("interval_start: {}", interval_start);
+        Ok(interval_start)
+    }
+
+    fn try_read_interval_len(&mut self) -> anyhow::Result<u64> {
+        let interval_len = self.cr.try_read_interval_len()?;
+        eprintln!("interval_len: {}", interval_len);
+        Ok(interval_len)
+    }
+
+    fn try_read_first_residual(&mut self) -> anyhow::Result<u64> {
+        let first_residual = self.cr.try_read_first_residual()?;
+        eprintln!("first_residual: {}", first_residual);
+        Ok(first_residual)
+    }
+
+    fn try_read_residual(&mut self) -> anyhow::Result<u64> {
+        let residual = self.cr.try_read_residual()?;
+        eprintln!("residual: {}", residual);
+        Ok(residual)
+    }
 }
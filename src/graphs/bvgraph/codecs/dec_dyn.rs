@@ -16,15 +16,15 @@ use sux::traits::IndexedSeq;
 #[derive(Debug)]
 pub struct DynCodesDecoder<E: Endianness, CR: CodeRead<E>> {
     pub(crate) code_reader: CR,
-    pub(crate) read_outdegree: fn(&mut CR) -> u64,
-    pub(crate) read_reference_offset: fn(&mut CR) -> u64,
-    pub(crate) read_block_count: fn(&mut CR) -> u64,
-    pub(crate) read_block: fn(&mut CR) -> u64,
-    pub(crate) read_interval_count: fn(&mut CR) -> u64,
-    pub(crate) read_interval_start: fn(&mut CR) -> u64,
-    pub(crate) read_interval_len: fn(&mut CR) -> u64,
-    pub(crate) read_first_residual: fn(&mut CR) -> u64,
-    pub(crate) read_residual: fn(&mut CR) -> u64,
+    pub(crate) read_outdegree: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_reference_offset: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_block_count: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_block: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_interval_count: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_interval_start: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_interval_len: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_first_residual: fn(&mut CR) -> anyhow::Result<u64>,
+    pub(crate) read_residual: fn(&mut CR) -> anyhow::Result<u64>,
     pub(crate) _marker: core::marker::PhantomData<E>,
 }
 
@@ -48,16 +48,16 @@ impl<E: Endianness, CR: CodeRead<E> + Clone> Clone for DynCodesDecoder<E, CR> {
 }
 
 impl<E: Endianness, CR: CodeRead<E>> DynCodesDecoder<E, CR> {
-    const READ_UNARY: fn(&mut CR) -> u64 = |cr| cr.read_unary().unwrap();
-    const READ_GAMMA: fn(&mut CR) -> u64 = |cr| cr.read_gamma().unwrap();
-    const READ_DELTA: fn(&mut CR) -> u64 = |cr| cr.read_delta().unwrap();
-    const READ_ZETA2: fn(&mut CR) -> u64 = |cr| cr.read_zeta(2).unwrap();
-    const READ_ZETA3: fn(&mut CR) -> u64 = |cr| cr.read_zeta3().unwrap();
-    const READ_ZETA4: fn(&mut CR) -> u64 = |cr| cr.read_zeta(4).unwrap();
-    const READ_ZETA5: fn(&mut CR) -> u64 = |cr| cr.read_zeta(5).unwrap();
-    const READ_ZETA6: fn(&mut CR) -> u64 = |cr| cr.read_zeta(6).unwrap();
-    const READ_ZETA7: fn(&mut CR) -> u64 = |cr| cr.read_zeta(7).unwrap();
-    const READ_ZETA1: fn(&mut CR) -> u64 = Self::READ_GAMMA;
+    const READ_UNARY: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_unary()?);
+    const READ_GAMMA: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_gamma()?);
+    const READ_DELTA: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_delta()?);
+    const READ_ZETA2: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(2)?);
+    const READ_ZETA3: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta3()?);
+    const READ_ZETA4: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(4)?);
+    const READ_ZETA5: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(5)?);
+    const READ_ZETA6: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(6)?);
+    const READ_ZETA7: fn(&mut CR) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(7)?);
+    const READ_ZETA1: fn(&mut CR) -> anyhow::Result<u64> = Self::READ_GAMMA;
 
     pub fn new(code_reader: CR, cf: &CompFlags) -> anyhow::Result<Self> {
         macro_rules! select_code {
@@ -112,42 +112,79 @@ impl<E: Endianness, CR: CodeRead<E> + BitSeek> BitSeek for DynCodesDecoder<E, CR
 impl<E: Endianness, CR: CodeRead<E>> Decode for DynCodesDecoder<E, CR> {
     #[inline(always)]
     fn read_outdegree(&mut self) -> u64 {
-        (self.read_outdegree)(&mut self.code_reader)
+        (self.read_outdegree)(&mut self.code_reader).unwrap()
     }
 
     #[inline(always)]
     fn read_reference_offset(&mut self) -> u64 {
-        (self.read_reference_offset)(&mut self.code_reader)
+        (self.read_reference_offset)(&mut self.code_reader).unwrap()
     }
 
     #[inline(always)]
     fn read_block_count(&mut self) -> u64 {
-        (self.read_block_count)(&mut self.code_reader)
+        (self.read_block_count)(&mut self.code_reader).unwrap()
     }
     #[inline(always)]
     fn read_block(&mut self) -> u64 {
-        (self.read_block)(&mut self.code_reader)
+        (self.read_block)(&mut self.code_reader).unwrap()
     }
 
     #[inline(always)]
     fn read_interval_count(&mut self) -> u64 {
-        (self.read_interval_count)(&mut self.code_reader)
+        (self.read_interval_count)(&mut self.code_reader).unwrap()
     }
     #[inline(always)]
     fn read_interval_start(&mut self) -> u64 {
-        (self.read_interval_start)(&mut self.code_reader)
+        (self.read_interval_start)(&mut self.code_reader).unwrap()
     }
     #[inline(always)]
     fn read_interval_len(&mut self) -> u64 {
-        (self.read_interval_len)(&mut self.code_reader)
+        (self.read_interval_len)(&mut self.code_reader).unwrap()
     }
 
     #[inline(always)]
     fn read_first_residual(&mut self) -> u64 {
-        (self.read_first_residual)(&mut self.code_reader)
+        (self.read_first_residual)(&mut self.code_reader).unwrap()
     }
     #[inline(always)]
     fn read_residual(&mut self) -> u64 {
+        (self.read_residual)(&mut self.code_reader).unwrap()
+    }
+
+    #[inline(always)]
+    fn try_read_outdegree(&mut self) -> anyhow::Result<u64> {
+        (self.read_outdegree)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_reference_offset(&mut self) -> anyhow::Result<u64> {
+        (self.read_reference_offset)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_block_count(&mut self) -> anyhow::Result<u64> {
+        (self.read_block_count)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_block(&mut self) -> anyhow::Result<u64> {
+        (self.read_block)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_interval_count(&mut self) -> anyhow::Result<u64> {
+        (self.read_interval_count)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_interval_start(&mut self) -> anyhow::Result<u64> {
+        (self.read_interval_start)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_interval_len(&mut self) -> anyhow::Result<u64> {
+        (self.read_interval_len)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_first_residual(&mut self) -> anyhow::Result<u64> {
+        (self.read_first_residual)(&mut self.code_reader)
+    }
+    #[inline(always)]
+    fn try_read_residual(&mut self) -> anyhow::Result<u64> {
         (self.read_residual)(&mut self.code_reader)
     }
 }
@@ -164,15 +201,23 @@ pub struct DynCodesDecoderFactory<
     /// The compression flags.
     compression_flags: CompFlags,
     // The cached functions to read the codes.
-    read_outdegree: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_reference_offset: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_block_count: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_blocks: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_interval_count: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_interval_start: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_interval_len: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_first_residual: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
-    read_residual: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64,
+    read_outdegree:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_reference_offset:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_block_count:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_blocks: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_interval_count:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_interval_start:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_interval_len:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_first_residual:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
+    read_residual:
+        for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> anyhow::Result<u64>,
     /// Tell the compiler that's Ok that we don't store `E` but we need it
     /// for typing.
     _marker: core::marker::PhantomData<E>,
@@ -186,26 +231,36 @@ where
     // Const cached functions we use to decode the data. These could be general
     // functions, but this way we have better visibility and we ensure that
     // they are compiled once!
-    const READ_UNARY: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_unary().unwrap();
-    const READ_GAMMA: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_gamma().unwrap();
-    const READ_DELTA: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_delta().unwrap();
-    const READ_ZETA2: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_zeta(2).unwrap();
-    const READ_ZETA3: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_zeta3().unwrap();
-    const READ_ZETA4: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_zeta(4).unwrap();
-    const READ_ZETA5: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_zeta(5).unwrap();
-    const READ_ZETA6: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_zeta(6).unwrap();
-    const READ_ZETA7: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        |cr| cr.read_zeta(7).unwrap();
-    const READ_ZETA1: for<'a> fn(&mut <F as BitReaderFactory<E>>::BitReader<'a>) -> u64 =
-        Self::READ_GAMMA;
+    const READ_UNARY: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_unary()?);
+    const READ_GAMMA: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_gamma()?);
+    const READ_DELTA: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_delta()?);
+    const READ_ZETA2: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(2)?);
+    const READ_ZETA3: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta3()?);
+    const READ_ZETA4: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(4)?);
+    const READ_ZETA5: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(5)?);
+    const READ_ZETA6: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(6)?);
+    const READ_ZETA7: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = |cr| Ok(cr.read_zeta(7)?);
+    const READ_ZETA1: for<'a> fn(
+        &mut <F as BitReaderFactory<E>>::BitReader<'a>,
+    ) -> anyhow::Result<u64> = Self::READ_GAMMA;
 
     #[inline(always)]
     /// Return a clone of the compression flags.
@@ -191,4 +191,76 @@ impl<'a, F: SequentialDecoderFactory> Decode for StatsDecoder<'a, F> {
             .residuals
             .update(self.codes_reader.read_residual())
     }
+
+    #[inline(always)]
+    fn try_read_outdegree(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .outdegrees
+            .update(self.codes_reader.try_read_outdegree()?))
+    }
+
+    #[inline(always)]
+    fn try_read_reference_offset(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .reference_offsets
+            .update(self.codes_reader.try_read_reference_offset()?))
+    }
+
+    #[inline(always)]
+    fn try_read_block_count(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .block_counts
+            .update(self.codes_reader.try_read_block_count()?))
+    }
+
+    #[inline(always)]
+    fn try_read_block(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .blocks
+            .update(self.codes_reader.try_read_block()?))
+    }
+
+    #[inline(always)]
+    fn try_read_interval_count(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .interval_counts
+            .update(self.codes_reader.try_read_interval_count()?))
+    }
+
+    #[inline(always)]
+    fn try_read_interval_start(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .interval_starts
+            .update(self.codes_reader.try_read_interval_start()?))
+    }
+
+    #[inline(always)]
+    fn try_read_interval_len(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .interval_lens
+            .update(self.codes_reader.try_read_interval_len()?))
+    }
+
+    #[inline(always)]
+    fn try_read_first_residual(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .first_residuals
+            .update(self.codes_reader.try_read_first_residual()?))
+    }
+
+    #[inline(always)]
+    fn try_read_residual(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .stats
+            .residuals
+            .update(self.codes_reader.try_read_residual()?))
+    }
 }
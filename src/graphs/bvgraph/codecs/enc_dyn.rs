@@ -89,6 +89,11 @@ impl<E: Endianness, CW: CodeWrite<E>> DynCodesEncoder<E, CW> {
             _marker: core::marker::PhantomData,
         }
     }
+
+    /// Consumes the encoder and returns the underlying code writer.
+    pub fn into_inner(self) -> CW {
+        self.code_writer
+    }
 }
 
 impl<E: Endianness, CW: CodeWrite<E> + BitSeek + Clone> BitSeek for DynCodesEncoder<E, CW> {
@@ -288,3 +293,15 @@ impl Encode for DynCodesEstimator {
         Ok(0)
     }
 }
+
+/// A [`DynCodesEstimator`] can be used as an encoder by
+/// [`BVComp`](crate::graphs::bvgraph::BVComp) to compute the length of a
+/// compressed graph without writing it, as it is its own estimator.
+impl EncodeAndEstimate for DynCodesEstimator {
+    type Estimator<'a> = &'a mut DynCodesEstimator
+        where Self: 'a;
+
+    fn estimator(&mut self) -> Self::Estimator<'_> {
+        self
+    }
+}
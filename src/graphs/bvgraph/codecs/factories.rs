@@ -15,7 +15,9 @@ bit readers accessing a graph data using different techniques.
 - [`FileFactory`] uses a [std::fs::File] to create a bit reader.
 - [`MemoryFactory`] creates bit readers from a slice of memory,
   either [allocated](MemoryFactory::new_mem) or [mapped](MemoryFactory::new_mmap).
-- [`MmapHelper`] can be used to create a bit reader from a memory-mapped file.
+- [`MmapHelper`] can be used to create a bit reader from a memory-mapped file;
+  an `MmapHelper<u32>` creates buffered readers, whereas an `MmapHelper<u64>`
+  creates unbuffered [`BitReader`]s, which is mainly useful for benchmarking.
 
 Any factory can be plugged either into a
 [`SequentialDecoderFactory`](super::SequentialDecoderFactory)
@@ -27,7 +29,7 @@ use anyhow::{ensure, Context};
 use bitflags::bitflags;
 use common_traits::UnsignedInt;
 use dsi_bitstream::{
-    impls::{BufBitReader, MemWordReader, WordAdapter},
+    impls::{BitReader, BufBitReader, MemWordReader, WordAdapter},
     traits::Endianness,
 };
 use std::{
@@ -277,3 +279,11 @@ impl<E: Endianness> BitReaderFactory<E> for MmapHelper<u32> {
         BufBitReader::<E, _>::new(MemWordReader::new(self.as_ref()))
     }
 }
+
+impl<E: Endianness> BitReaderFactory<E> for MmapHelper<u64> {
+    type BitReader<'a> = BitReader<E, MemWordReader<u64, &'a [u64]>>;
+
+    fn new_reader(&self) -> Self::BitReader<'_> {
+        BitReader::<E, _>::new(MemWordReader::new(self.as_ref()))
+    }
+}
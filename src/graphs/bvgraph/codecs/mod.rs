@@ -57,6 +57,38 @@ pub trait Decode {
     fn read_interval_len(&mut self) -> u64;
     fn read_first_residual(&mut self) -> u64;
     fn read_residual(&mut self) -> u64;
+
+    // Fallible versions of the methods above, returning the errors of the
+    // underlying reader rather than panicking. The default implementations
+    // delegate to the infallible methods.
+
+    fn try_read_outdegree(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_outdegree())
+    }
+    fn try_read_reference_offset(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_reference_offset())
+    }
+    fn try_read_block_count(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_block_count())
+    }
+    fn try_read_block(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_block())
+    }
+    fn try_read_interval_count(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_interval_count())
+    }
+    fn try_read_interval_start(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_interval_start())
+    }
+    fn try_read_interval_len(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_interval_len())
+    }
+    fn try_read_first_residual(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_first_residual())
+    }
+    fn try_read_residual(&mut self) -> anyhow::Result<u64> {
+        Ok(self.read_residual())
+    }
 }
 
 use impl_tools::autoimpl;
@@ -109,6 +109,11 @@ impl Compressor {
                 }
             }
         }
+        // decoders do not read intervals at all if they are disabled
+        debug_assert!(
+            min_interval_length != Self::NO_INTERVALS || self.left_interval.is_empty(),
+            "Intervals computed with min_interval_length = 0"
+        );
         // write the intervals
         if !self.extra_nodes.is_empty() && min_interval_length != Self::NO_INTERVALS {
             written_bits += writer.write_interval_count(self.left_interval.len() as _)? as u64;
@@ -458,6 +463,11 @@ impl<E: EncodeAndEstimate> BVComp<E> {
     pub fn flush(mut self) -> Result<usize, E::Error> {
         self.encoder.flush()
     }
+
+    /// Consumes the compressor and returns the encoder, without flushing it.
+    pub fn into_inner(self) -> E {
+        self.encoder
+    }
 }
 
 #[cfg(test)]
@@ -18,7 +18,7 @@ pub enum Code {
     Zeta { k: usize },
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 /// The compression flags for reading or compressing a graph.
 ///
@@ -104,46 +104,34 @@ impl CompFlags {
         s.push_str(&format!("maxrefcount={}\n", self.max_ref_count));
         s.push_str(&format!("windowsize={}\n", self.compression_window));
         s.push_str("compressionflags=");
-        let mut cflags = false;
         if self.outdegrees != Code::Gamma {
             s.push_str(&format!(
                 "OUTDEGREES_{}|",
                 Self::code_to_str(self.outdegrees).unwrap()
             ));
-            cflags = true;
         }
         if self.references != Code::Unary {
             s.push_str(&format!(
                 "REFERENCES_{}|",
                 Self::code_to_str(self.references).unwrap()
             ));
-            cflags = true;
         }
         if self.blocks != Code::Gamma {
             s.push_str(&format!(
                 "BLOCKS_{}|",
                 Self::code_to_str(self.blocks).unwrap()
             ));
-            cflags = true;
         }
         if self.intervals != Code::Gamma {
             s.push_str(&format!(
                 "INTERVALS_{}|",
                 Self::code_to_str(self.intervals).unwrap()
             ));
-            cflags = true;
         }
-        if matches!(self.residuals, Code::Zeta { k: _ }) {
-            s.push_str(&format!(
-                "RESIDUALS_{}|",
-                Self::code_to_str(self.residuals).unwrap()
-            ));
-            cflags = true;
-        }
-        if cflags {
-            s.pop();
-        }
-        s.push('\n');
+        s.push_str(&format!(
+            "RESIDUALS_{}\n",
+            Self::code_to_str(self.residuals).unwrap()
+        ));
         // check that if a k is specified, it is the same for all codes
         let mut k = None;
         macro_rules! check_and_set_k {
@@ -169,6 +157,27 @@ impl CompFlags {
         Ok(s)
     }
 
+    /// Returns the code used to write the gaps of the `.offsets` file
+    /// described by the decoded `.properties` file.
+    ///
+    /// The code is specified by the `OFFSETS` compression flag, as in the
+    /// Java implementation. If the flag is absent the code is γ; the only
+    /// other supported code is δ.
+    pub fn offsets_code_from_properties(map: &HashMap<String, String>) -> Result<Code> {
+        let mut code = Code::Gamma;
+        if let Some(comp_flags) = map.get("compressionflags") {
+            for flag in comp_flags.split('|') {
+                if let Some(s) = flag.strip_prefix("OFFSETS_") {
+                    code = match CompFlags::code_from_str(s, 3) {
+                        Some(c @ (Code::Gamma | Code::Delta)) => c,
+                        _ => bail!("Only γ and δ codes are supported for offsets, got {}", s),
+                    };
+                }
+            }
+        }
+        Ok(code)
+    }
+
     /// Convert the decoded `.properties` file into a `CompFlags` struct.
     /// Also check that the endianness is correct.
     pub fn from_properties<E: Endianness>(map: &HashMap<String, String>) -> Result<Self> {
@@ -176,7 +185,7 @@ impl CompFlags {
         let endianness = map
             .get("endianness")
             .map(|x| x.to_string())
-            .unwrap_or_else(|| BigEndian::NAME.to_string());
+            .unwrap_or_else(|| crate::graphs::bvgraph::DEFAULT_ENDIANNESS.to_string());
 
         anyhow::ensure!(
             endianness == E::NAME,
@@ -195,7 +204,8 @@ impl CompFlags {
 
         let mut cf = CompFlags::default();
         let mut k = 3;
-        if let Some(spec_k) = map.get("zeta_k") {
+        // zetak is the key used by the Java implementation
+        if let Some(spec_k) = map.get("zetak").or_else(|| map.get("zeta_k")) {
             let spec_k = spec_k.parse::<usize>()?;
             if !(1..=7).contains(&spec_k) {
                 bail!("Only ζ₁-ζ₇ are supported");
@@ -215,9 +225,10 @@ impl CompFlags {
                         "BLOCKS" => cf.blocks = code,
                         "INTERVALS" => cf.intervals = code,
                         "RESIDUALS" => cf.residuals = code,
-                        "OFFSETS" => {
-                            ensure!(code == Code::Gamma, "Only γ code is supported for offsets")
-                        }
+                        "OFFSETS" => ensure!(
+                            matches!(code, Code::Gamma | Code::Delta),
+                            "Only γ and δ codes are supported for offsets"
+                        ),
                         _ => bail!("Unknown compression flag {}", flag),
                     }
                 }
@@ -229,6 +240,13 @@ impl CompFlags {
         if let Some(min_interval_length) = map.get("minintervallength") {
             cf.min_interval_length = min_interval_length.parse()?;
         }
+        if let Some(max_ref_count) = map.get("maxrefcount") {
+            // -1 means infinite recursion depth, as in the CLI
+            cf.max_ref_count = match max_ref_count.parse::<isize>() {
+                Ok(-1) => usize::MAX,
+                _ => max_ref_count.parse()?,
+            };
+        }
         Ok(cf)
     }
 }
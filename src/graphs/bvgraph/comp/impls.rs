@@ -12,7 +12,7 @@ use dsi_progress_logger::prelude::*;
 use lender::prelude::*;
 use std::borrow::Borrow;
 use std::fs::File;
-use std::io::{BufReader, BufWriter};
+use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
 /// A queue that pulls jobs with ids in a contiguous initial segment of the
@@ -87,9 +87,28 @@ impl JobId for Job {
     }
 }
 
+/// Returns an error if `expected` is present and differs from the number of
+/// arcs actually written.
+fn check_num_arcs(written: u64, expected: Option<u64>) -> Result<()> {
+    if let Some(expected) = expected {
+        ensure!(
+            written == expected,
+            "Wrote {} arcs, but the graph has {} arcs",
+            written,
+            expected
+        );
+    }
+    Ok(())
+}
+
 impl BVComp<()> {
     /// Compresses s [`NodeLabelsLender`] and returns the length in bits of the
     /// graph bitstream.
+    ///
+    /// If `build_offsets` is true, the `.offsets` file is written in the same
+    /// pass: before compressing each node the number of bits written for the
+    /// previous node is appended to it as a γ-coded gap, so the offsets are
+    /// always consistent with the graph bitstream.
     pub fn single_thread<E, L>(
         basename: impl AsRef<Path>,
         iter: L,
@@ -112,11 +131,7 @@ impl BVComp<()> {
                 .with_context(|| format!("Could not create {}", graph_path.display()))?,
         )));
 
-        let comp_flags = CompFlags {
-            ..Default::default()
-        };
-
-        let codes_writer = DynCodesEncoder::new(bit_write, &comp_flags);
+        let codes_writer = DynCodesEncoder::new(bit_write, &compression_flags);
 
         let mut bvcomp = BVComp::new(
             codes_writer,
@@ -153,6 +168,7 @@ impl BVComp<()> {
                 pl.update();
                 real_num_nodes += 1;
             });
+            writer.flush().context("Could not flush offsets")?;
         } else {
             for_! ( (_node_id, successors) in iter {
                 result += bvcomp.push(successors).context("Could not push successors")?;
@@ -174,7 +190,7 @@ impl BVComp<()> {
 
         log::info!("Writing the .properties file");
         let properties = compression_flags
-            .to_properties::<BE>(real_num_nodes, bvcomp.arcs)
+            .to_properties::<E>(real_num_nodes, bvcomp.arcs)
             .context("Could not serialize properties")?;
         let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
         std::fs::write(&properties_path, properties)
@@ -184,6 +200,178 @@ impl BVComp<()> {
         Ok(result)
     }
 
+    /// Returns the length in bits of the graph bitstream that
+    /// [`single_thread`](Self::single_thread) would write compressing `iter`
+    /// with the given compression flags, without writing anything.
+    ///
+    /// The whole compression logic is run, including the choice of
+    /// references, but the encoder is a [`DynCodesEstimator`], which returns
+    /// the length of each field using the codes specified by
+    /// `compression_flags` instead of writing it. Since all codes are
+    /// instantaneous, the estimate is exact. Note that the `.graph` file
+    /// will be padded to a multiple of the word size.
+    pub fn estimate_size<L>(iter: L, compression_flags: CompFlags) -> Result<u64>
+    where
+        L: IntoLender,
+        L::Lender: for<'next> NodeLabelsLender<'next, Label = usize>,
+    {
+        let mut bvcomp = BVComp::new(
+            DynCodesEstimator::new(&compression_flags),
+            compression_flags.compression_window,
+            compression_flags.max_ref_count,
+            compression_flags.min_interval_length,
+            0,
+        );
+
+        let mut pl = ProgressLogger::default();
+        pl.display_memory(true).item_name("node");
+        pl.start("Estimating compressed size...");
+        let mut result = 0;
+        for_! ( (_node_id, successors) in iter {
+            result += bvcomp.push(successors).context("Could not push successors")?;
+            pl.light_update();
+        });
+        pl.done();
+
+        Ok(result)
+    }
+
+    /// Returns the length in bits of the graph bitstream that
+    /// [`parallel_graph`](Self::parallel_graph) would write compressing
+    /// `graph` with the given compression flags and thread pool, without
+    /// writing anything.
+    ///
+    /// The graph is split as in [`parallel_graph`](Self::parallel_graph),
+    /// and the length of the bitstream of each part is computed in parallel
+    /// as in [`estimate_size`](Self::estimate_size). Since the compression
+    /// of each part starts with an empty window of references, the result
+    /// is usually larger than that of [`estimate_size`](Self::estimate_size).
+    pub fn par_estimate_size<G: SplitLabeling + SequentialGraph>(
+        graph: &G,
+        compression_flags: CompFlags,
+        threads: impl Borrow<rayon::ThreadPool>,
+    ) -> Result<u64>
+    where
+        for<'a> <G as SplitLabeling>::SplitLender<'a>: Send + Sync,
+    {
+        use rayon::prelude::*;
+        let thread_pool = threads.borrow();
+        let lenders = graph
+            .split_iter(thread_pool.current_num_threads())
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        thread_pool.install(|| {
+            lenders
+                .into_par_iter()
+                .map(|mut lender| {
+                    let Some((node_id, successors)) = lender.next() else {
+                        return Ok(0);
+                    };
+                    let mut bvcomp = BVComp::new(
+                        DynCodesEstimator::new(&compression_flags),
+                        compression_flags.compression_window,
+                        compression_flags.max_ref_count,
+                        compression_flags.min_interval_length,
+                        node_id,
+                    );
+                    let mut result = bvcomp.push(successors)?;
+                    for_! ( (_node_id, successors) in lender {
+                        result += bvcomp.push(successors).context("Could not push successors")?;
+                    });
+                    Ok(result)
+                })
+                .sum()
+        })
+    }
+
+    /// Compresses a graph entirely in memory and returns the length in bits
+    /// of the graph bitstream.
+    ///
+    /// The graph and offsets bitstreams are accumulated in memory and written
+    /// to `basename` with, respectively, extensions
+    /// [`GRAPH_EXTENSION`] and [`OFFSETS_EXTENSION`] only at the end, together
+    /// with the `.properties` file. No temporary file is used, so this method is
+    /// suitable for small and medium graphs in environments without a
+    /// writable temporary directory. The output is the same as that of
+    /// [`parallel_graph`](Self::parallel_graph).
+    ///
+    /// As with the other compression methods, the successors of each node
+    /// must be returned in increasing order.
+    pub fn in_memory<E: Endianness>(
+        basename: impl AsRef<Path>,
+        graph: &impl SequentialGraph,
+        compression_flags: CompFlags,
+    ) -> Result<u64>
+    where
+        BufBitWriter<E, MemWordWriterVec<usize, Vec<usize>>>: CodeWrite<E>,
+    {
+        let basename = basename.as_ref();
+
+        let bit_write = <BufBitWriter<E, _>>::new(MemWordWriterVec::new(Vec::<usize>::new()));
+        let codes_writer = DynCodesEncoder::new(bit_write, &compression_flags);
+        let mut bvcomp = BVComp::new(
+            codes_writer,
+            compression_flags.compression_window,
+            compression_flags.max_ref_count,
+            compression_flags.min_interval_length,
+            0,
+        );
+        let mut offsets_writer =
+            <BufBitWriter<BE, _>>::new(MemWordWriterVec::new(Vec::<usize>::new()));
+
+        let mut pl = ProgressLogger::default();
+        pl.display_memory(true)
+            .item_name("node")
+            .expected_updates(Some(graph.num_nodes()));
+        pl.start("Compressing successors in memory...");
+
+        let mut written_bits = 0;
+        let mut num_nodes = 0;
+        offsets_writer.write_gamma(0)?;
+        for_! ( (_node_id, successors) in graph.iter() {
+            let delta = bvcomp.push(successors).context("Could not push successors")?;
+            written_bits += delta;
+            offsets_writer.write_gamma(delta)?;
+            pl.light_update();
+            num_nodes += 1;
+        });
+        pl.done();
+
+        let num_arcs = bvcomp.arcs;
+        check_num_arcs(num_arcs, graph.num_arcs_hint())?;
+        let graph_words = bvcomp.into_inner().into_inner().into_inner()?.into_inner();
+        let offsets_words = offsets_writer.into_inner()?.into_inner();
+
+        // Words are written as a WordAdapter would, so that the files are the
+        // same as those written by the other compression methods
+        let write_words = |path: PathBuf, words: Vec<usize>| -> Result<()> {
+            let mut writer = BufWriter::new(
+                File::create(&path)
+                    .with_context(|| format!("Could not create {}", path.display()))?,
+            );
+            for word in words {
+                writer
+                    .write_all(&word.to_ne_bytes())
+                    .with_context(|| format!("Could not write {}", path.display()))?;
+            }
+            writer
+                .flush()
+                .with_context(|| format!("Could not write {}", path.display()))
+        };
+        write_words(basename.with_extension(GRAPH_EXTENSION), graph_words)?;
+        write_words(basename.with_extension(OFFSETS_EXTENSION), offsets_words)?;
+
+        let properties = compression_flags
+            .to_properties::<E>(num_nodes, num_arcs)
+            .context("Could not serialize properties")?;
+        let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
+        std::fs::write(&properties_path, properties)
+            .with_context(|| format!("Could not write {}", properties_path.display()))?;
+
+        Ok(written_bits)
+    }
+
     /// A wrapper over [`parallel_graph`](Self::parallel_graph) that takes the
     /// endianness as a string.
     ///
@@ -211,12 +399,13 @@ impl BVComp<()> {
             ))]
             BE::NAME => {
                 // compress the transposed graph
-                Self::parallel_iter::<BigEndian, _>(
+                Self::parallel_iter_checked::<BigEndian, _>(
                     basename,
                     graph
                         .split_iter(threads.borrow().current_num_threads())
                         .into_iter(),
                     num_nodes,
+                    graph.num_arcs_hint(),
                     compression_flags,
                     threads,
                     tmp_dir,
@@ -228,12 +417,13 @@ impl BVComp<()> {
             ))]
             LE::NAME => {
                 // compress the transposed graph
-                Self::parallel_iter::<LittleEndian, _>(
+                Self::parallel_iter_checked::<LittleEndian, _>(
                     basename,
                     graph
                         .split_iter(threads.borrow().current_num_threads())
                         .into_iter(),
                     num_nodes,
+                    graph.num_arcs_hint(),
                     compression_flags,
                     threads,
                     tmp_dir,
@@ -255,12 +445,13 @@ impl BVComp<()> {
         BufBitWriter<E, WordAdapter<usize, BufWriter<std::fs::File>>>: CodeWrite<E>,
         BufBitReader<E, WordAdapter<u32, BufReader<std::fs::File>>>: BitRead<E>,
     {
-        Self::parallel_iter(
+        Self::parallel_iter_checked(
             basename,
             graph
                 .split_iter(threads.borrow().current_num_threads())
                 .into_iter(),
             graph.num_nodes(),
+            graph.num_arcs_hint(),
             compression_flags,
             threads,
             tmp_dir,
@@ -280,6 +471,42 @@ impl BVComp<()> {
         threads: impl Borrow<rayon::ThreadPool>,
         tmp_dir: impl AsRef<Path>,
     ) -> Result<u64>
+    where
+        BufBitWriter<E, WordAdapter<usize, BufWriter<std::fs::File>>>: CodeWrite<E>,
+        BufBitReader<E, WordAdapter<u32, BufReader<std::fs::File>>>: BitRead<E>,
+    {
+        Self::parallel_iter_checked(
+            basename,
+            iter,
+            num_nodes,
+            None,
+            compression_flags,
+            threads,
+            tmp_dir,
+        )
+    }
+
+    /// Compresses multiple [`NodeLabelsLender`] in parallel, like
+    /// [`parallel_iter`](Self::parallel_iter), checking that the number of
+    /// arcs written is `num_arcs`, if present.
+    ///
+    /// If the check fails an error is returned, and the `.properties` file is
+    /// not written. The methods compressing a graph use this method with the
+    /// [number of arcs](SequentialLabeling::num_arcs_hint) of the graph, if
+    /// known, so that arcs lost (or duplicated) by a faulty lender cannot go
+    /// unnoticed.
+    pub fn parallel_iter_checked<
+        E: Endianness,
+        L: Lender + for<'next> NodeLabelsLender<'next, Label = usize> + Send,
+    >(
+        basename: impl AsRef<Path> + Send + Sync,
+        iter: impl Iterator<Item = L>,
+        num_nodes: usize,
+        num_arcs: Option<u64>,
+        compression_flags: CompFlags,
+        threads: impl Borrow<rayon::ThreadPool>,
+        tmp_dir: impl AsRef<Path>,
+    ) -> Result<u64>
     where
         BufBitWriter<E, WordAdapter<usize, BufWriter<std::fs::File>>>: CodeWrite<E>,
         BufBitReader<E, WordAdapter<u32, BufReader<std::fs::File>>>: BitRead<E>,
@@ -466,9 +693,11 @@ impl BVComp<()> {
             graph_writer.flush()?;
             offsets_writer.flush()?;
 
+            check_num_arcs(total_arcs, num_arcs)?;
+
             log::info!("Writing the .properties file");
             let properties = compression_flags
-                .to_properties::<BE>(num_nodes, total_arcs)
+                .to_properties::<E>(num_nodes, total_arcs)
                 .context("Could not serialize properties")?;
             let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
             std::fs::write(&properties_path, properties).with_context(|| {
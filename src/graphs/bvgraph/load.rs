@@ -93,6 +93,18 @@ pub trait LoadMode: 'static {
         offsets: P,
         flags: MemoryFlags,
     ) -> Result<MemCase<Self::Offsets>>;
+
+    /// Turns offsets built in memory into offsets of this mode.
+    ///
+    /// Only [`File`], whose offsets are fully deserialized in memory,
+    /// supports this; the other modes return an error, as their offsets
+    /// are ε-serde deserialized types, which can be obtained only from a
+    /// file.
+    fn offsets_from_ef(_ef: EF) -> Result<MemCase<Self::Offsets>> {
+        anyhow::bail!(
+            "Offsets built in memory require the File offsets mode (see LoadConfig::offsets_from_graph)"
+        )
+    }
 }
 
 /// The graph is read from a file; offsets are fully deserialized in memory.
@@ -122,6 +134,10 @@ impl LoadMode for File {
             .with_context(|| format!("Cannot load Elias-Fano pointer list {}", path.display()))?
             .into())
     }
+
+    fn offsets_from_ef(ef: EF) -> Result<MemCase<Self::Offsets>> {
+        Ok(ef.into())
+    }
 }
 
 /// The graph and offsets are memory mapped.
@@ -209,9 +225,25 @@ pub struct LoadConfig<E: Endianness, A: Access, D: Dispatch, GLM: LoadMode, OLM:
     pub(crate) basename: PathBuf,
     pub(crate) graph_load_flags: MemoryFlags,
     pub(crate) offsets_load_flags: MemoryFlags,
+    pub(crate) offsets_from_graph: bool,
     pub(crate) _marker: std::marker::PhantomData<(E, A, D, GLM, OLM)>,
 }
 
+impl<A: Access> LoadConfig<BE, A, Dynamic, Mmap, Mmap> {
+    /// Returns the default configuration for the graph with the given
+    /// basename, as returned by [`BVGraph::with_basename`] and
+    /// [`BVGraphSeq::with_basename`].
+    pub(crate) fn new(basename: impl AsRef<Path>) -> Self {
+        LoadConfig {
+            basename: PathBuf::from(basename.as_ref()),
+            graph_load_flags: MemoryFlags::empty(),
+            offsets_load_flags: MemoryFlags::empty(),
+            offsets_from_graph: false,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
 impl<E: Endianness, A: Access, D: Dispatch, GLM: LoadMode, OLM: LoadMode>
     LoadConfig<E, A, D, GLM, OLM>
 {
@@ -221,6 +253,7 @@ impl<E: Endianness, A: Access, D: Dispatch, GLM: LoadMode, OLM: LoadMode>
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -235,6 +268,7 @@ impl<E: Endianness, A: Access, D: Dispatch, GLM: LoadMode, OLM: LoadMode>
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -249,6 +283,7 @@ impl<E: Endianness, A: Access, D: Dispatch, GLM: LoadMode, OLM: LoadMode>
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -261,6 +296,7 @@ impl<E: Endianness, A: Access, D: Dispatch> LoadConfig<E, A, D, Mmap, Mmap> {
             basename: self.basename,
             graph_load_flags: flags,
             offsets_load_flags: flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -273,6 +309,7 @@ impl<E: Endianness, A: Access, D: Dispatch> LoadConfig<E, A, D, LoadMmap, LoadMm
             basename: self.basename,
             graph_load_flags: flags,
             offsets_load_flags: flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -287,6 +324,7 @@ impl<E: Endianness, A: Access, D: Dispatch, GLM: LoadMode, OLM: LoadMode>
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -299,6 +337,7 @@ impl<E: Endianness, A: Access, D: Dispatch, OLM: LoadMode> LoadConfig<E, A, D, M
             basename: self.basename,
             graph_load_flags: flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -311,6 +350,7 @@ impl<E: Endianness, A: Access, D: Dispatch, OLM: LoadMode> LoadConfig<E, A, D, L
             basename: self.basename,
             graph_load_flags: flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -323,11 +363,94 @@ impl<E: Endianness, D: Dispatch, GLM: LoadMode, OLM: LoadMode> LoadConfig<E, Ran
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
 }
 
+impl<E: Endianness, D: Dispatch, GLM: LoadMode, OLM: LoadMode> LoadConfig<E, Random, D, GLM, OLM> {
+    /// Build the offsets in memory if the `.ef` file is missing.
+    ///
+    /// The offsets are decoded from the `.offsets` file, if present, or
+    /// built by scanning the graph once with its
+    /// [offset/degree iterator](BVGraphSeq::offset_deg_iter) otherwise, so
+    /// that random access is possible on a graph for which no offsets have
+    /// been built. No file is written; to build the `.ef` file once and for
+    /// all use `webgraph build ef`.
+    ///
+    /// Since offsets built in memory are fully deserialized, this method
+    /// sets the [`File`] offsets mode; setting another offsets mode
+    /// afterwards makes loading fail if the offsets must be built.
+    pub fn offsets_from_graph(self) -> LoadConfig<E, Random, D, GLM, File> {
+        LoadConfig {
+            basename: self.basename,
+            graph_load_flags: self.graph_load_flags,
+            offsets_load_flags: self.offsets_load_flags,
+            offsets_from_graph: true,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Loads the offsets from the `.ef` file or builds them, depending
+    /// on [`offsets_from_graph`](Self::offsets_from_graph).
+    ///
+    /// If the `.ef` file is missing, an up-to-date `.obl` file written by
+    /// [`load_or_build_ef`] is used in its place.
+    fn offsets(
+        &self,
+        num_nodes: usize,
+        num_arcs: u64,
+        comp_flags: CompFlags,
+    ) -> Result<MemCase<OLM::Offsets>>
+    where
+        for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
+    {
+        let ef_path = self.basename.with_extension(EF_EXTENSION);
+        if !ef_path.exists() && obl_is_fresh(&self.basename) {
+            return OLM::load_offsets(
+                self.basename.with_extension(OBL_EXTENSION),
+                self.offsets_load_flags,
+            );
+        }
+        if !self.offsets_from_graph || ef_path.exists() {
+            return OLM::load_offsets(&ef_path, self.offsets_load_flags);
+        }
+
+        let ef = if self.basename.with_extension(OFFSETS_EXTENSION).exists() {
+            ef_from_offsets(&self.basename)?
+        } else {
+            let graph_path = self.basename.with_extension(GRAPH_EXTENSION);
+            let graph_len = 8 * std::fs::metadata(&graph_path)
+                .with_context(|| format!("Could not stat {}", graph_path.display()))?
+                .len();
+            // The graph is always scanned in memory, as bit positions are
+            // needed, and they are not reliable on file readers
+            let graph = BVGraphSeq::new(
+                DynCodesDecoderFactory::new(
+                    MmapHelper::<u32>::mmap(&graph_path, MemoryFlags::SEQUENTIAL.into())?,
+                    MemCase::from(EmptyDict::default()),
+                    comp_flags,
+                )?,
+                num_nodes,
+                Some(num_arcs),
+                comp_flags.compression_window,
+                comp_flags.min_interval_length,
+            );
+            ef_from_graph(
+                &graph,
+                usize::try_from(graph_len).with_context(|| {
+                    format!(
+                        "The length in bits of {} does not fit in a usize",
+                        graph_path.display()
+                    )
+                })?,
+            )?
+        };
+        OLM::offsets_from_ef(ef)
+    }
+}
+
 impl<E: Endianness, D: Dispatch, GLM: LoadMode> LoadConfig<E, Random, D, GLM, Mmap> {
     /// Set flags for memory-mapping the offsets.
     pub fn offsets_flags(self, flags: MemoryFlags) -> LoadConfig<E, Random, D, GLM, Mmap> {
@@ -335,6 +458,7 @@ impl<E: Endianness, D: Dispatch, GLM: LoadMode> LoadConfig<E, Random, D, GLM, Mm
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -347,6 +471,7 @@ impl<E: Endianness, D: Dispatch, GLM: LoadMode> LoadConfig<E, Random, D, GLM, Lo
             basename: self.basename,
             graph_load_flags: self.graph_load_flags,
             offsets_load_flags: flags,
+            offsets_from_graph: self.offsets_from_graph,
             _marker: std::marker::PhantomData,
         }
     }
@@ -361,13 +486,13 @@ impl<E: Endianness, GLM: LoadMode, OLM: LoadMode> LoadConfig<E, Random, Dynamic,
     where
         for<'a> <<GLM as LoadMode>::Factory<E> as BitReaderFactory<E>>::BitReader<'a>:
             CodeRead<E> + BitSeek,
+        for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
     {
         self.basename.set_extension(PROPERTIES_EXTENSION);
         let (num_nodes, num_arcs, comp_flags) = parse_properties::<E>(&self.basename)?;
         self.basename.set_extension(GRAPH_EXTENSION);
         let factory = GLM::new_factory(&self.basename, self.graph_load_flags)?;
-        self.basename.set_extension(EF_EXTENSION);
-        let offsets = OLM::load_offsets(&self.basename, self.offsets_load_flags)?;
+        let offsets = self.offsets(num_nodes, num_arcs, comp_flags)?;
 
         Ok(BVGraph::new(
             DynCodesDecoderFactory::new(factory, offsets, comp_flags)?,
@@ -440,13 +565,13 @@ impl<
     where
         for<'a> <<GLM as LoadMode>::Factory<E> as BitReaderFactory<E>>::BitReader<'a>:
             CodeRead<E> + BitSeek,
+        for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: CodeRead<E> + BitSeek,
     {
         self.basename.set_extension(PROPERTIES_EXTENSION);
         let (num_nodes, num_arcs, comp_flags) = parse_properties::<E>(&self.basename)?;
         self.basename.set_extension(GRAPH_EXTENSION);
         let factory = GLM::new_factory(&self.basename, self.graph_load_flags)?;
-        self.basename.set_extension(EF_EXTENSION);
-        let offsets = OLM::load_offsets(&self.basename, self.offsets_load_flags)?;
+        let offsets = self.offsets(num_nodes, num_arcs, comp_flags)?;
 
         Ok(BVGraph::new(
             ConstCodesDecoderFactory::new(factory, offsets, comp_flags)?,
@@ -518,7 +643,19 @@ impl<
     }
 }
 
-/// Read the .properties file and return the endianness
+/// The endianness assumed when a `.properties` file has no `endianness` key,
+/// as it might happen for graphs compressed by the Java implementation.
+///
+/// It is big endian, unless only the `le_bins` feature is enabled, in which
+/// case it is little endian.
+pub const DEFAULT_ENDIANNESS: &str = if cfg!(all(feature = "le_bins", not(feature = "be_bins"))) {
+    LittleEndian::NAME
+} else {
+    BigEndian::NAME
+};
+
+/// Read the .properties file and return the endianness, or
+/// [`DEFAULT_ENDIANNESS`] if the file does not specify it.
 pub fn get_endianness<P: AsRef<Path>>(basename: P) -> Result<String> {
     let path = basename.as_ref().with_extension(PROPERTIES_EXTENSION);
     let f = std::fs::File::open(&path)
@@ -529,32 +666,23 @@ pub fn get_endianness<P: AsRef<Path>>(basename: P) -> Result<String> {
     let endianness = map
         .get("endianness")
         .map(|x| x.to_string())
-        .unwrap_or_else(|| BigEndian::NAME.to_string());
+        .unwrap_or_else(|| DEFAULT_ENDIANNESS.to_string());
 
     Ok(endianness)
 }
 
 /// Read the .properties file and return the number of nodes, number of arcs and compression flags
 /// for the graph. The endianness is checked against the expected one.
+///
+/// See [`GraphProperties`] for access to all properties.
 pub fn parse_properties<E: Endianness>(path: impl AsRef<Path>) -> Result<(usize, u64, CompFlags)> {
-    let name = path.as_ref().display();
-    let f = std::fs::File::open(&path)
-        .with_context(|| format!("Cannot open property file {}", name))?;
-    let map = java_properties::read(BufReader::new(f))
-        .with_context(|| format!("cannot parse {} as a java properties file", name))?;
-
-    let num_nodes = map
-        .get("nodes")
-        .with_context(|| format!("Missing 'nodes' property in {}", name))?
-        .parse::<usize>()
-        .with_context(|| format!("Cannot parse 'nodes' as usize in {}", name))?;
-    let num_arcs = map
-        .get("arcs")
-        .with_context(|| format!("Missing 'arcs' property in {}", name))?
-        .parse::<u64>()
-        .with_context(|| format!("Cannot parse arcs as usize in {}", name))?;
-
-    let comp_flags = CompFlags::from_properties::<E>(&map)
-        .with_context(|| format!("Cannot parse compression flags from {}", name))?;
-    Ok((num_nodes, num_arcs, comp_flags))
+    let properties = GraphProperties::load(&path)?;
+    properties
+        .check_endianness::<E>()
+        .with_context(|| format!("Cannot load {}", path.as_ref().display()))?;
+    Ok((
+        properties.num_nodes,
+        properties.num_arcs,
+        properties.comp_flags,
+    ))
 }
@@ -11,12 +11,13 @@ pub const GRAPH_EXTENSION: &str = "graph";
 pub const PROPERTIES_EXTENSION: &str = "properties";
 pub const OFFSETS_EXTENSION: &str = "offsets";
 pub const EF_EXTENSION: &str = "ef";
+pub const OBL_EXTENSION: &str = "obl";
 pub const LABELS_EXTENSION: &str = "labels";
 pub const LABELOFFSETS_EXTENSION: &str = "labeloffsets";
 pub const DEG_CUMUL_EXTENSION: &str = "dcf";
 
 mod offset_deg_iter;
-pub use offset_deg_iter::OffsetDegIter;
+pub use offset_deg_iter::{DegreesIter, OffsetDegIter};
 
 pub mod sequential;
 pub use sequential::BVGraphSeq;
@@ -36,6 +37,12 @@ pub use comp::*;
 mod load;
 pub use load::*;
 
+mod properties;
+pub use properties::*;
+
+mod offsets;
+pub use offsets::*;
+
 /// The default version of EliasFano we use for the CLI.
 pub type EF = sux::dict::EliasFano<
     sux::rank_sel::SelectAdaptConst<sux::bits::BitVec<Box<[usize]>>, Box<[usize]>, 12, 4>,
@@ -6,14 +6,15 @@
  */
 
 use super::*;
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use dsi_bitstream::prelude::*;
 
 /// Fast iterator over the offsets and degrees of a [`BVGraph`].
 ///
 /// This iterator is faster than scanning the graph. In particular, it can be
 /// used to build the offsets of a graph or to enumerate the graph degrees when
-/// the offsets are not available.
+/// the offsets are not available: the offset returned with the degree of a
+/// node is the position in bits at which its encoding begins.
 #[derive(Debug, Clone)]
 pub struct OffsetDegIter<D: Decode> {
     number_of_nodes: usize,
@@ -29,6 +30,29 @@ impl<D: Decode + BitSeek> OffsetDegIter<D> {
     pub fn get_pos(&mut self) -> u64 {
         self.decoder.bit_pos().unwrap()
     }
+
+    /// Returns the degree of the next node without advancing the iterator,
+    /// so that the next call to [`next_degree`](OffsetDegIter::next_degree)
+    /// will return the same value.
+    ///
+    /// The next node is decoded as in
+    /// [`next_degree`](OffsetDegIter::next_degree), and then the bit position
+    /// and the state of the iterator are restored.
+    pub fn peek_degree(&mut self) -> Result<usize> {
+        let pos = self.decoder.bit_pos()?;
+        let node_id = self.node_id;
+        // next_degree overwrites the degree of the node at distance
+        // compression_window, which might still be needed as a reference
+        let backref = (self.compression_window != 0)
+            .then(|| self.backrefs[node_id % self.compression_window]);
+        let degree = self.next_degree()?;
+        self.decoder.set_bit_pos(pos)?;
+        self.node_id = node_id;
+        if let Some(backref) = backref {
+            self.backrefs[node_id % self.compression_window] = backref;
+        }
+        Ok(degree)
+    }
 }
 
 impl<D: Decode + BitSeek> Iterator for OffsetDegIter<D> {
@@ -48,6 +72,49 @@ impl<D: Decode + BitSeek> ExactSizeIterator for OffsetDegIter<D> {
     }
 }
 
+/// Fast iterator over the offsets, node ids, and degrees of a [`BVGraph`].
+///
+/// This iterator wraps an [`OffsetDegIter`] and returns triples
+/// `(offset, node_id, degree)`. The offset is the position in bits in the
+/// graph bitstream at which the encoding of `node_id` begins, that is, the
+/// same value stored for `node_id` in the `.offsets` and `.ef` files. Thus,
+/// this iterator can be used to build custom offset indices directly from
+/// the graph bitstream, without the `.offsets` file. After the last node has
+/// been returned, [`get_pos`](DegreesIter::get_pos) returns the length in
+/// bits of the graph bitstream.
+///
+/// Use [`BVGraphSeq::iter_degrees`], [`BVGraph::iter_degrees`], or
+/// [`OffsetDegIter::with_node_ids`] to create one.
+#[derive(Debug, Clone)]
+pub struct DegreesIter<D: Decode>(OffsetDegIter<D>);
+
+impl<D: Decode + BitSeek> DegreesIter<D> {
+    /// Get the current bit offset in the bitstream.
+    pub fn get_pos(&mut self) -> u64 {
+        self.0.get_pos()
+    }
+}
+
+impl<D: Decode + BitSeek> Iterator for DegreesIter<D> {
+    type Item = (u64, usize, usize);
+    fn next(&mut self) -> Option<(u64, usize, usize)> {
+        let node_id = self.0.node_id;
+        let (offset, degree) = self.0.next()?;
+        Some((offset, node_id, degree))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<D: Decode + BitSeek> ExactSizeIterator for DegreesIter<D> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
 impl<D: Decode> OffsetDegIter<D> {
     /// Creates a new iterator over the degrees of the graph.
     pub fn new(
@@ -92,6 +159,30 @@ impl<D: Decode> OffsetDegIter<D> {
         self.number_of_nodes
     }
 
+    /// Get the compression window of the graph.
+    #[inline(always)]
+    pub fn compression_window(&self) -> usize {
+        self.compression_window
+    }
+
+    /// Get the id of the next node whose degree will be returned.
+    #[inline(always)]
+    pub fn node_id(&self) -> usize {
+        self.node_id
+    }
+
+    /// Converts this iterator into a [`DegreesIter`], which returns also the
+    /// node ids.
+    pub fn with_node_ids(self) -> DegreesIter<D> {
+        DegreesIter(self)
+    }
+
+    /// Consumes the iterator and returns the underlying decoder, which is
+    /// positioned at the start of the encoding of the next node.
+    pub(crate) fn into_decoder(self) -> D {
+        self.decoder
+    }
+
     /// Convert the decoder to another one.
     pub fn map_decoder<D2: Decode, F: FnOnce(D) -> D2>(self, f: F) -> OffsetDegIter<D2> {
         OffsetDegIter {
@@ -109,54 +200,90 @@ impl<D: Decode> OffsetDegIter<D> {
     /// but it calls `.unwrap()` on it because the trait Graph doesn't allows
     /// errors.
     pub fn next_degree(&mut self) -> Result<usize> {
-        let degree = self.decoder.read_outdegree() as usize;
+        self.next_degree_and_ref_delta().map(|(degree, _)| degree)
+    }
+
+    /// Skips the encoding of the next node, including its successors,
+    /// without building them: only the fields needed to find the end of the
+    /// encoding are read.
+    ///
+    /// After skipping `k` nodes from the start of the graph, the decoder is
+    /// positioned at the offset of node `k`.
+    #[inline(always)]
+    pub fn skip_successors(&mut self) -> Result<()> {
+        self.next_degree_and_ref_delta().map(|_| ())
+    }
+
+    /// Returns the degree of the next node and its reference offset, that is,
+    /// the distance from the node whose successors are copied, or zero if the
+    /// node does not use a reference (in particular, if its degree is zero).
+    ///
+    /// This method is as fast as [`next_degree`](OffsetDegIter::next_degree),
+    /// as the reference offset is decoded anyway.
+    #[inline(always)]
+    pub fn next_degree_and_ref_delta(&mut self) -> Result<(usize, usize)> {
+        let degree = self.decoder.try_read_outdegree()? as usize;
         // no edges, we are done!
         if degree == 0 {
             if self.compression_window != 0 {
                 self.backrefs[self.node_id % self.compression_window] = degree;
             }
             self.node_id += 1;
-            return Ok(degree);
+            return Ok((degree, 0));
         }
 
         let mut nodes_left_to_decode = degree;
 
         // read the reference offset
         let ref_delta = if self.compression_window != 0 {
-            self.decoder.read_reference_offset() as usize
+            self.decoder.try_read_reference_offset()? as usize
         } else {
             0
         };
         // if we copy nodes from a previous one
         if ref_delta != 0 {
             // compute the node id of the reference
+            ensure!(
+                ref_delta <= self.node_id.min(self.compression_window),
+                "Node {}: reference offset {} out of range (the compression window is {})",
+                self.node_id,
+                ref_delta,
+                self.compression_window
+            );
             let reference_node_id = self.node_id - ref_delta;
             // retrieve the data
             let ref_degree = self.backrefs[reference_node_id % self.compression_window];
             // get the info on which destinations to copy
-            let number_of_blocks = self.decoder.read_block_count() as usize;
+            let number_of_blocks = self.decoder.try_read_block_count()? as usize;
 
             // no blocks, we copy everything
             if number_of_blocks == 0 {
-                nodes_left_to_decode -= ref_degree;
+                self.consume(&mut nodes_left_to_decode, ref_degree)?;
             } else {
                 // otherwise we copy only the blocks of even index
 
                 // the first block could be zero
-                let mut idx = self.decoder.read_block() as usize;
-                nodes_left_to_decode -= idx;
+                let mut idx = self.decoder.try_read_block()? as usize;
+                self.consume(&mut nodes_left_to_decode, idx)?;
 
                 // while the other can't
                 for block_id in 1..number_of_blocks {
-                    let block = self.decoder.read_block() as usize;
+                    let block = self.decoder.try_read_block()? as usize;
                     let end = idx + block + 1;
                     if block_id % 2 == 0 {
-                        nodes_left_to_decode -= block + 1;
+                        self.consume(&mut nodes_left_to_decode, block + 1)?;
                     }
                     idx = end;
                 }
                 if number_of_blocks & 1 == 0 {
-                    nodes_left_to_decode -= ref_degree - idx;
+                    ensure!(
+                        idx <= ref_degree,
+                        "Node {}: blocks of total length {} longer than the outdegree {} of the reference",
+                        self.node_id,
+                        idx,
+                        ref_degree
+                    );
+                    self.consume(&mut nodes_left_to_decode, ref_degree - idx)?;
                 }
             }
         };
@@ -164,21 +291,21 @@ impl<D: Decode> OffsetDegIter<D> {
         // if we still have to read nodes
         if nodes_left_to_decode != 0 && self.min_interval_length != 0 {
             // read the number of intervals
-            let number_of_intervals = self.decoder.read_interval_count() as usize;
+            let number_of_intervals = self.decoder.try_read_interval_count()? as usize;
             if number_of_intervals != 0 {
                 // pre-allocate with capacity for efficiency
-                let _ = self.decoder.read_interval_start();
-                let mut delta = self.decoder.read_interval_len() as usize;
+                self.decoder.try_read_interval_start()?;
+                let mut delta = self.decoder.try_read_interval_len()? as usize;
                 delta += self.min_interval_length;
                 // save the first interval
-                nodes_left_to_decode -= delta;
+                self.consume(&mut nodes_left_to_decode, delta)?;
                 // decode the intervals
                 for _ in 1..number_of_intervals {
-                    let _ = self.decoder.read_interval_start();
-                    delta = self.decoder.read_interval_len() as usize;
+                    self.decoder.try_read_interval_start()?;
+                    delta = self.decoder.try_read_interval_len()? as usize;
                     delta += self.min_interval_length;
 
-                    nodes_left_to_decode -= delta;
+                    self.consume(&mut nodes_left_to_decode, delta)?;
                 }
             }
         }
@@ -186,15 +313,28 @@ impl<D: Decode> OffsetDegIter<D> {
         // decode the extra nodes if needed
         if nodes_left_to_decode != 0 {
             // pre-allocate with capacity for efficiency
-            let _ = self.decoder.read_first_residual();
+            self.decoder.try_read_first_residual()?;
             for _ in 1..nodes_left_to_decode {
-                let _ = self.decoder.read_residual();
+                self.decoder.try_read_residual()?;
             }
         }
         if self.compression_window != 0 {
             self.backrefs[self.node_id % self.compression_window] = degree;
         }
         self.node_id += 1;
-        Ok(degree)
+        Ok((degree, ref_delta))
+    }
+
+    /// Subtracts `n` from the number of successors of the current node that
+    /// are left to decode, or returns an error if they are fewer than `n`.
+    #[inline(always)]
+    fn consume(&self, nodes_left_to_decode: &mut usize, n: usize) -> Result<()> {
+        *nodes_left_to_decode = nodes_left_to_decode.checked_sub(n).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Node {}: more successors in references and intervals than the outdegree",
+                self.node_id
+            )
+        })?;
+        Ok(())
     }
 }
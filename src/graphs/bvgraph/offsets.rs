@@ -0,0 +1,501 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{
+    BVGraphSeq, Code, Decode, GraphProperties, SequentialDecoderFactory, EF, GRAPH_EXTENSION,
+    OBL_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION,
+};
+use crate::traits::SequentialLabeling;
+use crate::utils::MmapHelper;
+use anyhow::{ensure, Context, Result};
+use dsi_bitstream::prelude::*;
+use dsi_progress_logger::prelude::*;
+use epserde::prelude::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use sux::prelude::*;
+
+/// The number of gaps per chunk used by [`par_ef_from_offsets`].
+const PAR_DECODE_CHUNK_SIZE: usize = 1 << 16;
+
+/// An iterator over the offsets stored in an `.offsets` file, that is,
+/// over the prefix sums of a sequence of gaps coded with a given [`Code`].
+///
+/// Offsets are positions in bits, so they are accumulated as `u64`
+/// independently of the width of `usize`; an error is returned if the
+/// stream cannot be read or if an offset overflows.
+pub struct OffsetsIter<E: Endianness, R> {
+    reader: R,
+    code: Code,
+    offset: u64,
+    remaining: usize,
+    _marker: core::marker::PhantomData<E>,
+}
+
+impl<E: Endianness, R: GammaRead<E> + DeltaRead<E>> OffsetsIter<E, R> {
+    /// Creates an iterator returning `len` offsets read from `reader`.
+    ///
+    /// Only [`Code::Gamma`] and [`Code::Delta`] are used by `.offsets`
+    /// files; any other code is read as [`Code::Gamma`].
+    pub fn new(reader: R, code: Code, len: usize) -> Self {
+        Self {
+            reader,
+            code,
+            offset: 0,
+            remaining: len,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<E: Endianness, R: GammaRead<E> + DeltaRead<E>> Iterator for OffsetsIter<E, R>
+where
+    R::Error: std::error::Error + Send + Sync + 'static,
+{
+    type Item = Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let gap = match self.code {
+            Code::Delta => self.reader.read_delta().context("Could not read delta"),
+            _ => self.reader.read_gamma().context("Could not read gamma"),
+        };
+        Some(gap.and_then(|gap| {
+            self.offset = self
+                .offset
+                .checked_add(gap)
+                .context("Offset overflow while reading offsets")?;
+            Ok(self.offset)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Decodes in parallel `len` offsets coded with a given [`Code`] from an
+/// in-memory `.offsets` bitstream, passing to `push`, in order, the same
+/// offsets returned by an [`OffsetsIter`].
+///
+/// The sequence of gaps is split into chunks of `chunk_size` consecutive
+/// gaps. A first sequential pass finds the position of the first gap of
+/// each chunk by skipping codes without computing their values; then,
+/// chunks are decoded in rounds of `num_threads` chunks into partial prefix
+/// sums on the current [`rayon`] thread pool, and the partial sums of each
+/// chunk are shifted by the last offset of the previous chunk and passed to
+/// `push`. Thus, at most `num_threads` × `chunk_size` offsets are kept in
+/// memory at any time.
+///
+/// Note that the first pass reads the unary part of every code, so the
+/// parallel part of the work is only the decoding of the binary parts and
+/// the computation of the prefix sums.
+pub fn par_decode_offsets(
+    data: &[u32],
+    code: Code,
+    len: usize,
+    num_threads: usize,
+    chunk_size: usize,
+    mut push: impl FnMut(u64) -> Result<()>,
+) -> Result<()> {
+    use rayon::prelude::*;
+    let num_threads = num_threads.max(1);
+    let chunk_size = chunk_size.max(1);
+
+    // Find the bit position of the first gap of each chunk; the reader is
+    // strict, so that a truncated stream results in an error
+    let mut reader = BufBitReader::<BE, _>::new(MemWordReader::new_strict(data));
+    let mut starts = Vec::with_capacity(len.div_ceil(chunk_size));
+    for i in 0..len {
+        if i % chunk_size == 0 {
+            starts.push(reader.bit_pos()?);
+        }
+        // compute the length of the binary part of the code
+        let unary = reader.read_unary()?;
+        let binary = match code {
+            Code::Delta => {
+                ensure!(unary < 64, "Invalid δ code in the offsets stream");
+                reader.read_bits(unary as usize)? + (1 << unary) - 1
+            }
+            _ => unary,
+        };
+        reader
+            .skip_bits(binary as usize)
+            .context("The offsets stream is truncated")?;
+    }
+
+    let mut chunks = vec![Vec::with_capacity(chunk_size); num_threads];
+    let mut base = 0_u64;
+    for (round, round_starts) in starts.chunks(num_threads).enumerate() {
+        let first = round * num_threads * chunk_size;
+        // Decode each chunk of the round into partial prefix sums
+        chunks
+            .par_iter_mut()
+            .zip(round_starts)
+            .enumerate()
+            .try_for_each(|(i, (chunk, &start))| -> Result<()> {
+                let chunk_start = first + i * chunk_size;
+                let chunk_len = chunk_size.min(len - chunk_start);
+                let mut reader = BufBitReader::<BE, _>::new(MemWordReader::new(data));
+                reader.set_bit_pos(start)?;
+                chunk.clear();
+                for partial in OffsetsIter::<BE, _>::new(reader, code, chunk_len) {
+                    chunk.push(partial?);
+                }
+                Ok(())
+            })?;
+
+        // Shift the partial sums of each chunk by the last offset of the
+        // previous chunk
+        for chunk in &chunks[..round_starts.len()] {
+            for &partial in chunk {
+                push(
+                    base.checked_add(partial)
+                        .context("Offset overflow while reading offsets")?,
+                )?;
+            }
+            base = base
+                .checked_add(chunk[chunk.len() - 1])
+                .context("Offset overflow while reading offsets")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes the `.offsets` file of a graph and returns the offsets of its
+/// nodes, followed by the length in bits of the graph bitstream, as an
+/// [Elias-Fano representation](EF).
+///
+/// This is the content of the `.ef` file written by `webgraph build ef`.
+pub fn ef_from_offsets(basename: impl AsRef<Path>) -> Result<EF> {
+    par_ef_from_offsets(basename, 1)
+}
+
+/// Like [`ef_from_offsets`], but decodes the `.offsets` file using
+/// [`par_decode_offsets`] with `num_threads` threads of the current
+/// [`rayon`] thread pool.
+///
+/// If `num_threads` is one, the `.offsets` file is decoded sequentially,
+/// without mapping it in memory.
+pub fn par_ef_from_offsets(basename: impl AsRef<Path>, num_threads: usize) -> Result<EF> {
+    let basename = basename.as_ref();
+    let properties = GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION))?;
+    let num_nodes = properties.num_nodes;
+
+    let graph_path = basename.with_extension(GRAPH_EXTENSION);
+    let graph_len = 8 * std::fs::metadata(&graph_path)
+        .with_context(|| format!("Could not stat {}", graph_path.display()))?
+        .len();
+    let mut efb = EliasFanoBuilder::new(
+        num_nodes + 1,
+        usize::try_from(graph_len).with_context(|| {
+            format!(
+                "The length in bits of {} does not fit in a usize",
+                graph_path.display()
+            )
+        })?,
+    );
+
+    let offsets_path = basename.with_extension(OFFSETS_EXTENSION);
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("offset")
+        .expected_updates(Some(num_nodes + 1));
+    if num_threads > 1 {
+        pl.start(format!(
+            "Translating offsets to Elias-Fano using {} threads...",
+            num_threads
+        ));
+    } else {
+        pl.start("Translating offsets to Elias-Fano...");
+    }
+    let mut push = |offset: u64| -> Result<()> {
+        efb.push(usize::try_from(offset).with_context(|| {
+            format!(
+                "Offset {} in {} does not fit in a usize",
+                offset,
+                offsets_path.display()
+            )
+        })?);
+        pl.light_update();
+        Ok(())
+    };
+
+    if num_threads > 1 {
+        let data = MmapHelper::<u32>::mmap(&offsets_path, mmap_rs::MmapFlags::SEQUENTIAL)
+            .with_context(|| format!("Could not map {}", offsets_path.display()))?;
+        par_decode_offsets(
+            data.as_ref(),
+            properties.offsets_code,
+            num_nodes + 1,
+            num_threads,
+            PAR_DECODE_CHUNK_SIZE,
+            &mut push,
+        )?;
+    } else {
+        let reader =
+            BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(BufReader::with_capacity(
+                1 << 20,
+                File::open(&offsets_path)
+                    .with_context(|| format!("Could not open {}", offsets_path.display()))?,
+            )));
+        for offset in OffsetsIter::new(reader, properties.offsets_code, num_nodes + 1) {
+            push(offset?)?;
+        }
+    }
+    pl.done();
+
+    Ok(unsafe {
+        efb.build()
+            .map_high_bits(SelectAdaptConst::<_, _, 12, 4>::new)
+    })
+}
+
+/// Scans a graph with its [offset/degree iterator](BVGraphSeq::offset_deg_iter)
+/// and returns the offsets of its nodes, followed by the length in bits of the
+/// graph bitstream, as an [Elias-Fano representation](EF).
+///
+/// The result is the same as that of [`ef_from_offsets`], but no `.offsets`
+/// file is needed, and everything happens in memory. `upper_bound` must be
+/// at least the length in bits of the graph bitstream, for example, eight
+/// times the length of the `.graph` file.
+pub fn ef_from_graph<F: SequentialDecoderFactory>(
+    graph: &BVGraphSeq<F>,
+    upper_bound: usize,
+) -> Result<EF>
+where
+    for<'a> F::Decoder<'a>: Decode + BitSeek,
+{
+    let num_nodes = graph.num_nodes();
+    let mut efb = EliasFanoBuilder::new(num_nodes + 1, upper_bound);
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Building offsets from the graph...");
+    let mut push = |offset: u64| -> Result<()> {
+        ensure!(
+            offset <= upper_bound as u64,
+            "Offset {} is larger than the upper bound {}",
+            offset,
+            upper_bound
+        );
+        efb.push(offset as usize);
+        Ok(())
+    };
+    let mut iter = graph.offset_deg_iter();
+    for node in 0..num_nodes {
+        push(iter.get_pos())?;
+        iter.next_degree()
+            .with_context(|| format!("Could not decode the degree of node {}", node))?;
+        pl.light_update();
+    }
+    push(iter.get_pos())?;
+    pl.done();
+
+    Ok(unsafe {
+        efb.build()
+            .map_high_bits(SelectAdaptConst::<_, _, 12, 4>::new)
+    })
+}
+
+/// Returns whether the `.obl` file of a graph can be used in place of its
+/// `.offsets` file, that is, whether it exists and it is not older than the
+/// `.offsets` file.
+pub(crate) fn obl_is_fresh(basename: &Path) -> bool {
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    match (
+        modified(&basename.with_extension(OBL_EXTENSION)),
+        modified(&basename.with_extension(OFFSETS_EXTENSION)),
+    ) {
+        (Some(obl_time), Some(offsets_time)) => obl_time >= offsets_time,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Returns the offsets of a graph, using the `.obl` file as a cache of the
+/// `.offsets` file.
+///
+/// If the `.obl` file exists and is not older than the `.offsets` file, it
+/// is loaded; otherwise, the offsets are [decoded](ef_from_offsets) from the
+/// `.offsets` file and stored in the `.obl` file, if possible, so that the
+/// next call will not need to decode them again. Thus, an `.obl` file left
+/// over from a previous compression with the same basename is never used in
+/// place of newer offsets.
+///
+/// The `.obl` file has the same content as the `.ef` file written by
+/// `webgraph build ef`, which is never touched by this function, and it is
+/// used in its place when loading a [`BVGraph`](super::BVGraph) whose `.ef`
+/// file is missing.
+pub fn load_or_build_ef(basename: impl AsRef<Path>) -> Result<EF> {
+    let basename = basename.as_ref();
+    let obl_path = basename.with_extension(OBL_EXTENSION);
+
+    if obl_is_fresh(basename) {
+        log::info!("Loading offsets from {}", obl_path.display());
+        return EF::load_full(&obl_path)
+            .with_context(|| format!("Could not load {}", obl_path.display()));
+    }
+
+    let ef = ef_from_offsets(basename)?;
+    log::info!("Caching offsets in {}", obl_path.display());
+    // The offsets are available anyway, so failing to cache them (e.g.,
+    // because the directory of the graph is read-only) is not an error
+    if let Err(e) = ef.store(&obl_path) {
+        log::warn!("Could not store {}: {}", obl_path.display(), e);
+    }
+    Ok(ef)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use lender::*;
+
+    #[test]
+    fn test_offsets_iter_u64() -> Result<()> {
+        // Gaps whose prefix sums exceed u32::MAX
+        let gaps = [0, u32::MAX as u64, 1, 3 << 32, 5];
+        for code in [Code::Gamma, Code::Delta] {
+            let mut writer = <BufBitWriter<BE, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+            for &gap in &gaps {
+                match code {
+                    Code::Delta => writer.write_delta(gap)?,
+                    _ => writer.write_gamma(gap)?,
+                };
+            }
+            let data = writer.into_inner()?.into_inner();
+            let data: &[u32] = unsafe { data.align_to().1 };
+            let reader = <BufBitReader<BE, _>>::new(MemWordReader::new(data));
+
+            let offsets = OffsetsIter::new(reader, code, gaps.len()).collect::<Result<Vec<_>>>()?;
+            assert_eq!(
+                offsets,
+                vec![0, 0xFFFF_FFFF, 0x1_0000_0000, 0x4_0000_0000, 0x4_0000_0005]
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_decode_offsets() -> Result<()> {
+        let basename = Path::new("tests/data/cnr-2000");
+        let properties = GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION))?;
+        let len = properties.num_nodes + 1;
+        let offsets_path = basename.with_extension(OFFSETS_EXTENSION);
+
+        let reader = BufBitReader::<BE, _>::new(<WordAdapter<u32, _>>::new(BufReader::new(
+            std::fs::File::open(&offsets_path)?,
+        )));
+        let serial =
+            OffsetsIter::new(reader, properties.offsets_code, len).collect::<Result<Vec<_>>>()?;
+
+        let data = MmapHelper::<u32>::mmap(&offsets_path, mmap_rs::MmapFlags::empty())?;
+        for num_threads in [0, 1, 2, 7] {
+            for chunk_size in [0, 1, 1000, len, len + 5] {
+                let mut parallel = Vec::with_capacity(len);
+                par_decode_offsets(
+                    data.as_ref(),
+                    properties.offsets_code,
+                    len,
+                    num_threads,
+                    chunk_size,
+                    |offset| {
+                        parallel.push(offset);
+                        Ok(())
+                    },
+                )?;
+                assert_eq!(
+                    parallel, serial,
+                    "{} threads, chunks of size {}",
+                    num_threads, chunk_size
+                );
+            }
+        }
+
+        // A truncated stream is detected
+        assert!(par_decode_offsets(
+            &data.as_ref()[..1000],
+            properties.offsets_code,
+            len,
+            4,
+            100,
+            |_| Ok(())
+        )
+        .is_err());
+
+        // The parallel Elias-Fano representation is the same as the
+        // sequential one
+        let ef = par_ef_from_offsets(basename, 4)?;
+        assert_eq!(ef.len(), len);
+        for (i, &offset) in serial.iter().enumerate() {
+            assert_eq!(ef.get(i) as u64, offset);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_or_build_ef() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let basename = dir.path().join("cnr-2000");
+        for extension in [GRAPH_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION] {
+            std::fs::copy(
+                Path::new("tests/data/cnr-2000").with_extension(extension),
+                basename.with_extension(extension),
+            )?;
+        }
+        let graph = BVGraphSeq::with_basename(&basename)
+            .endianness::<BE>()
+            .load()?;
+        let mut iter = graph.offset_deg_iter();
+        let mut expected = iter.by_ref().map(|(offset, _)| offset).collect::<Vec<_>>();
+        expected.push(iter.get_pos());
+
+        // The first call decodes the offsets and writes the cache
+        let obl_path = basename.with_extension(OBL_EXTENSION);
+        assert!(!obl_path.exists());
+        let built = load_or_build_ef(&basename)?;
+        assert!(obl_path.exists());
+        assert!(!basename.with_extension(EF_EXTENSION).exists());
+        let cache_time = std::fs::metadata(&obl_path)?.modified()?;
+
+        // The second call hits the cache
+        let loaded = load_or_build_ef(&basename)?;
+        assert_eq!(std::fs::metadata(&obl_path)?.modified()?, cache_time);
+
+        // The cache is used for random access in place of the missing .ef file
+        let random = BVGraph::with_basename(&basename)
+            .endianness::<BE>()
+            .load()?;
+        for_![(node, succ) in graph.iter() {
+            assert!(itertools::equal(succ, random.successors(node)));
+        }];
+
+        // A newer .offsets file invalidates the cache
+        std::fs::File::options()
+            .write(true)
+            .open(basename.with_extension(OFFSETS_EXTENSION))?
+            .set_modified(cache_time + std::time::Duration::from_secs(10))?;
+        load_or_build_ef(&basename)?;
+        assert!(std::fs::metadata(&obl_path)?.modified()? > cache_time);
+
+        assert_eq!(built.len(), expected.len());
+        assert_eq!(loaded.len(), expected.len());
+        for (i, &offset) in expected.iter().enumerate() {
+            assert_eq!(built.get(i) as u64, offset);
+            assert_eq!(loaded.get(i) as u64, offset);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{Code, CompFlags, DEFAULT_ENDIANNESS};
+use anyhow::{bail, ensure, Context, Result};
+use dsi_bitstream::traits::{BigEndian, Endianness, LittleEndian};
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::path::Path;
+
+/// The content of the `.properties` file of a graph.
+///
+/// The file uses the format of the Java implementation: this structure
+/// collects, validated and typed, the keys needed to load a graph, that is,
+/// the number of nodes and arcs, the endianness, the compression flags
+/// (codes, window size, minimum interval length and ζ parameter), and the
+/// code of the `.offsets` file. Other keys, such as the statistics written by
+/// the Java implementation, are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphProperties {
+    /// The number of nodes (key `nodes`).
+    pub num_nodes: usize,
+    /// The number of arcs (key `arcs`).
+    pub num_arcs: u64,
+    /// The name of the endianness of the bitstreams (key `endianness`,
+    /// [`DEFAULT_ENDIANNESS`] if absent).
+    pub endianness: String,
+    /// The compression flags.
+    pub comp_flags: CompFlags,
+    /// The code used for the gaps of the `.offsets` file (the `OFFSETS`
+    /// compression flag, γ if absent).
+    pub offsets_code: Code,
+}
+
+impl GraphProperties {
+    /// Creates the properties of a graph with default compression flags
+    /// and γ-coded offsets.
+    pub fn new<E: Endianness>(num_nodes: usize, num_arcs: u64) -> Self {
+        Self {
+            num_nodes,
+            num_arcs,
+            endianness: E::NAME.to_string(),
+            comp_flags: CompFlags::default(),
+            offsets_code: Code::Gamma,
+        }
+    }
+
+    /// Reads and validates a `.properties` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let f = std::fs::File::open(path)
+            .with_context(|| format!("Cannot open property file {}", path.display()))?;
+        let map = java_properties::read(BufReader::new(f)).with_context(|| {
+            format!("Cannot parse {} as a java properties file", path.display())
+        })?;
+        Self::from_map(&map).with_context(|| format!("Invalid property file {}", path.display()))
+    }
+
+    /// Validates the keys of a decoded `.properties` file.
+    pub fn from_map(map: &HashMap<String, String>) -> Result<Self> {
+        let num_nodes = map
+            .get("nodes")
+            .context("Missing 'nodes' property")?
+            .parse::<usize>()
+            .context("Cannot parse 'nodes' as usize")?;
+        let num_arcs = map
+            .get("arcs")
+            .context("Missing 'arcs' property")?
+            .parse::<u64>()
+            .context("Cannot parse 'arcs' as u64")?;
+        let endianness = map
+            .get("endianness")
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| DEFAULT_ENDIANNESS.to_string());
+        let comp_flags = match endianness.as_str() {
+            BigEndian::NAME => CompFlags::from_properties::<BigEndian>(map),
+            LittleEndian::NAME => CompFlags::from_properties::<LittleEndian>(map),
+            e => bail!("Unknown endianness {}", e),
+        }
+        .context("Cannot parse compression flags")?;
+        let offsets_code = CompFlags::offsets_code_from_properties(map)?;
+
+        Ok(Self {
+            num_nodes,
+            num_arcs,
+            endianness,
+            comp_flags,
+            offsets_code,
+        })
+    }
+
+    /// Returns an error if the endianness is not `E`.
+    pub fn check_endianness<E: Endianness>(&self) -> Result<()> {
+        ensure!(
+            self.endianness == E::NAME,
+            "Wrong endianness, got {} while expected {}",
+            self.endianness,
+            E::NAME
+        );
+        Ok(())
+    }
+
+    /// Returns the content of a Java-compatible `.properties` file.
+    pub fn to_properties(&self) -> Result<String> {
+        let s = match self.endianness.as_str() {
+            BigEndian::NAME => self
+                .comp_flags
+                .to_properties::<BigEndian>(self.num_nodes, self.num_arcs)?,
+            LittleEndian::NAME => self
+                .comp_flags
+                .to_properties::<LittleEndian>(self.num_nodes, self.num_arcs)?,
+            e => bail!("Unknown endianness {}", e),
+        };
+        if self.offsets_code == Code::Gamma {
+            return Ok(s);
+        }
+        let flag = format!(
+            "OFFSETS_{}",
+            CompFlags::code_to_str(self.offsets_code).unwrap()
+        );
+        Ok(s.lines()
+            .map(|line| match line.strip_prefix("compressionflags=") {
+                Some("") => format!("{}{}\n", line, flag),
+                Some(_) => format!("{}|{}\n", line, flag),
+                None => format!("{}\n", line),
+            })
+            .collect())
+    }
+
+    /// Writes a Java-compatible `.properties` file.
+    pub fn store(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_properties()?)
+            .with_context(|| format!("Cannot write property file {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let properties = GraphProperties::load("tests/data/cnr-2000.properties")?;
+        assert_eq!(properties.num_nodes, 325_557);
+        assert_eq!(properties.num_arcs, 3_216_152);
+        assert_eq!(properties.endianness, BigEndian::NAME);
+        assert_eq!(properties.offsets_code, Code::Gamma);
+
+        let mut properties = GraphProperties::new::<LittleEndian>(10, 20);
+        properties.comp_flags.residuals = Code::Zeta { k: 5 };
+        properties.comp_flags.compression_window = 3;
+        properties.offsets_code = Code::Delta;
+        let map = java_properties::read(properties.to_properties()?.as_bytes())?;
+        assert_eq!(GraphProperties::from_map(&map)?, properties);
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_key() -> Result<()> {
+        let mut map = java_properties::read(
+            GraphProperties::new::<BigEndian>(10, 20)
+                .to_properties()?
+                .as_bytes(),
+        )?;
+        map.remove("arcs");
+        let err = GraphProperties::from_map(&map).unwrap_err();
+        assert!(err.to_string().contains("arcs"));
+        Ok(())
+    }
+}
@@ -6,10 +6,8 @@
  */
 
 use crate::prelude::*;
-use bitflags::Flags;
-use dsi_bitstream::traits::BE;
+use dsi_bitstream::traits::{BitSeek, BE};
 use lender::IntoLender;
-use std::path::PathBuf;
 
 use self::sequential::Iter;
 
@@ -28,12 +26,7 @@ impl BVGraph<()> {
     pub fn with_basename(
         basename: impl AsRef<std::path::Path>,
     ) -> LoadConfig<BE, Random, Dynamic, Mmap, Mmap> {
-        LoadConfig {
-            basename: PathBuf::from(basename.as_ref()),
-            graph_load_flags: Flags::empty(),
-            offsets_load_flags: Flags::empty(),
-            _marker: std::marker::PhantomData,
-        }
+        LoadConfig::new(basename)
     }
 }
 
@@ -112,6 +105,11 @@ where
     }
 
     /// Return a fast sequential iterator over the nodes of the graph and their successors.
+    ///
+    /// The decoder is positioned using the offsets, so the cost does not
+    /// depend on `start_node`; the successors of the `compression_window`
+    /// nodes preceding `start_node`, which might be referenced by the nodes
+    /// returned, are decoded by random access.
     fn iter_from(&self, start_node: usize) -> Self::Lender<'_> {
         let codes_reader = self.factory.new_decoder(start_node).unwrap();
         // we have to pre-fill the buffer
@@ -146,6 +144,9 @@ where
     }
 
     /// Return the outdegree of a node.
+    ///
+    /// Only the outdegree is decoded: references, blocks, intervals and
+    /// residuals are not read.
     fn outdegree(&self, node_id: usize) -> usize {
         let mut codes_reader = self
             .factory
@@ -265,6 +266,148 @@ where
     }
 }
 
+impl<F: RandomAccessDecoderFactory> BVGraph<F> {
+    /// Returns the first (i.e., smallest) successor of a node, or `None` if
+    /// the node has no successors.
+    ///
+    /// This is equivalent to `self.successors(node_id).next()`, but only the
+    /// data needed to find the minimum is decoded: the reference and its
+    /// blocks, the intervals, and the first residual. If the first copied
+    /// successor is the first successor of the reference, the reference is
+    /// examined in the same way, following the chain of references
+    /// iteratively.
+    ///
+    /// An error is returned if the data of a node in the chain is not
+    /// consistent (e.g., a reference before the first node, or more
+    /// successors in blocks and intervals than the outdegree).
+    pub fn first_successor(&self, node_id: usize) -> anyhow::Result<Option<usize>> {
+        let query = node_id;
+        let mut first = usize::MAX;
+        let mut node_id = node_id;
+        loop {
+            let mut reader = self.factory.new_decoder(node_id)?;
+            let degree = reader.read_outdegree() as usize;
+            // no edges, we are done! This can happen only for the first
+            // node, as references are followed only if they copy something
+            if degree == 0 {
+                return Ok(None);
+            }
+            let mut nodes_left_to_decode = degree;
+            let mut reference = None;
+
+            let ref_delta = if self.compression_window != 0 {
+                reader.read_reference_offset() as usize
+            } else {
+                0
+            };
+            if ref_delta != 0 {
+                let reference_node_id = node_id.checked_sub(ref_delta).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Node {}: the reference offset {} is larger than the node",
+                        node_id,
+                        ref_delta
+                    )
+                })?;
+                let ref_degree = self.outdegree(reference_node_id);
+                let number_of_blocks = reader.read_block_count() as usize;
+                let mut blocks = Vec::with_capacity(number_of_blocks);
+                if number_of_blocks != 0 {
+                    // the first block could be zero
+                    blocks.push(reader.read_block() as usize);
+                    // while the other can't
+                    for _ in 1..number_of_blocks {
+                        blocks.push(reader.read_block() as usize + 1);
+                    }
+                }
+                // blocks of even index are copy blocks; if their number is
+                // even, the remaining successors of the reference are
+                // copied, too
+                let mut copied = blocks.iter().step_by(2).sum::<usize>();
+                if number_of_blocks & 1 == 0 {
+                    copied += ref_degree
+                        .checked_sub(blocks.iter().sum::<usize>())
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Node {}: the blocks are longer than the outdegree {} of the reference",
+                                node_id,
+                                ref_degree
+                            )
+                        })?;
+                }
+                if copied != 0 {
+                    nodes_left_to_decode =
+                        nodes_left_to_decode.checked_sub(copied).ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Node {}: {} successors copied from the reference, but the outdegree is {}",
+                                node_id,
+                                copied,
+                                degree
+                            )
+                        })?;
+                    // if the first block is empty, copying starts after the
+                    // first skip block; otherwise, the first copied
+                    // successor is the first successor of the reference
+                    match blocks.first() {
+                        Some(0) => {
+                            first = first.min(
+                                self.successors(reference_node_id)
+                                    .nth(blocks[1])
+                                    .unwrap_or(usize::MAX),
+                            )
+                        }
+                        _ => reference = Some(reference_node_id),
+                    }
+                }
+            }
+
+            if nodes_left_to_decode != 0 && self.min_interval_length != 0 {
+                let number_of_intervals = reader.read_interval_count() as usize;
+                for i in 0..number_of_intervals {
+                    let start = reader.read_interval_start();
+                    if i == 0 {
+                        first = first.min(Self::gap_to_node(node_id, nat2int(start))?);
+                    }
+                    // intervals must be skipped to reach the residuals
+                    let len = reader.read_interval_len() as usize + self.min_interval_length;
+                    nodes_left_to_decode =
+                        nodes_left_to_decode.checked_sub(len).ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Node {}: more successors in intervals and references than the outdegree {}",
+                                node_id,
+                                degree
+                            )
+                        })?;
+                }
+            }
+
+            if nodes_left_to_decode != 0 {
+                let node_id_offset = nat2int(reader.read_first_residual());
+                first = first.min(Self::gap_to_node(node_id, node_id_offset)?);
+            }
+
+            match reference {
+                Some(reference_node_id) => node_id = reference_node_id,
+                None => break,
+            }
+        }
+
+        anyhow::ensure!(first != usize::MAX, "Node {}: no successor found", query);
+        Ok(Some(first))
+    }
+
+    /// Returns the node at the given signed distance from `node_id`, or an
+    /// error if it is negative.
+    fn gap_to_node(node_id: usize, offset: i64) -> anyhow::Result<usize> {
+        usize::try_from(node_id as i64 + offset).map_err(|_| {
+            anyhow::anyhow!(
+                "Node {}: the successor at distance {} is negative",
+                node_id,
+                offset
+            )
+        })
+    }
+}
+
 impl<F: RandomAccessDecoderFactory> BVGraph<F>
 where
     for<'a> F::Decoder<'a>: Decode,
@@ -282,9 +425,21 @@ where
         )
     }
 
+    #[inline(always)]
+    /// Creates an iterator over the offsets, node ids, and degrees of the
+    /// nodes; see [`DegreesIter`] for the guarantees on the offsets.
+    pub fn iter_degrees(&self) -> DegreesIter<F::Decoder<'_>> {
+        self.offset_deg_iter().with_node_ids()
+    }
+
     #[inline(always)]
     /// Creates an iterator specialized in the degrees of the nodes starting
     /// from a given node.
+    ///
+    /// The decoder is positioned using the offsets, so, as in the case of
+    /// [`outdegree`](RandomAccessLabeling::outdegree), the cost does not
+    /// depend on `node`: only the outdegrees of the `compression_window`
+    /// nodes preceding `node` are decoded in addition.
     pub fn offset_deg_iter_from(&self, node: usize) -> OffsetDegIter<F::Decoder<'_>> {
         let mut backrefs = vec![0; self.compression_window];
         for node_id in node.saturating_sub(self.compression_window)..node {
@@ -300,7 +455,201 @@ where
         )
     }
 }
-impl<F> RandomAccessGraph for BVGraph<F> where F: RandomAccessDecoderFactory {}
+
+impl<F: RandomAccessDecoderFactory> BVGraph<F>
+where
+    for<'a> F::Decoder<'a>: Decode + BitSeek,
+{
+    /// Returns the position in bits of the encoding of a node in the
+    /// bitstream, that is, its offset.
+    ///
+    /// The offset of `num_nodes()` is the length in bits of the encoding of
+    /// the whole graph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node_id` is greater than the number of nodes.
+    pub fn bit_offset(&self, node_id: usize) -> u64 {
+        assert!(
+            node_id <= self.number_of_nodes,
+            "Node {} is out of range (number of nodes: {})",
+            node_id,
+            self.number_of_nodes
+        );
+        self.factory
+            .new_decoder(node_id)
+            .unwrap()
+            .bit_pos()
+            .unwrap()
+    }
+
+    /// Returns the length in bits of the encoding of a node, that is, the
+    /// difference between the offset of the next node and its offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node_id` is not smaller than the number of nodes.
+    pub fn node_bit_len(&self, node_id: usize) -> u64 {
+        assert!(
+            node_id < self.number_of_nodes,
+            "Node {} is out of range (number of nodes: {})",
+            node_id,
+            self.number_of_nodes
+        );
+        self.bit_offset(node_id + 1) - self.bit_offset(node_id)
+    }
+}
+
+impl<F: RandomAccessDecoderFactory + Sync> BVGraph<F> {
+    /// Applies `func` to each node and its successors, decoding `num_chunks`
+    /// ranges of consecutive nodes in parallel on the current
+    /// [`rayon`] thread pool.
+    ///
+    /// Each chunk is decoded [starting from its first
+    /// node](SequentialLabeling::iter_from), so the decoder is positioned
+    /// using the offsets, and the `compression_window` nodes preceding the
+    /// chunk, which might be referenced by its nodes, are decoded beforehand
+    /// without being passed to `func`. Within a chunk nodes are passed to
+    /// `func` in increasing order, but there is no ordering between chunks.
+    ///
+    /// Returns the first decoding error, if any.
+    pub fn par_for_each_node(
+        &self,
+        num_chunks: usize,
+        func: impl Fn(usize, &[usize]) + Sync,
+    ) -> anyhow::Result<()> {
+        use rayon::prelude::*;
+        let chunk_size = self.number_of_nodes.div_ceil(num_chunks.max(1)).max(1);
+        (0..self.number_of_nodes)
+            .step_by(chunk_size)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .try_for_each(|start| {
+                let mut iter = self.iter_from(start);
+                for node in start..(start + chunk_size).min(self.number_of_nodes) {
+                    func(node, iter.next_successors()?);
+                }
+                Ok(())
+            })
+    }
+}
+
+impl<F> RandomAccessGraph for BVGraph<F>
+where
+    F: RandomAccessDecoderFactory,
+{
+    /// Stores the successors of a node in `buf`, which is cleared first, and
+    /// returns their number.
+    ///
+    /// Successors are decoded directly into `buf`: the successors of the
+    /// reference, if any, are decoded recursively into `buf` and masked in
+    /// place, and then merged with intervals and residuals.
+    fn successors_into(&self, node_id: usize, buf: &mut Vec<usize>) -> usize {
+        buf.clear();
+        let mut reader = self
+            .factory
+            .new_decoder(node_id)
+            .expect("Cannot create reader");
+        let degree = reader.read_outdegree() as usize;
+        // no edges, we are done!
+        if degree == 0 {
+            return 0;
+        }
+
+        let ref_delta = if self.compression_window != 0 {
+            reader.read_reference_offset() as usize
+        } else {
+            0
+        };
+        if ref_delta != 0 {
+            // decode the successors of the reference and keep the copy
+            // blocks, which have even index; if the number of blocks is
+            // even, the remaining successors are copied, too
+            let ref_degree = self.successors_into(node_id - ref_delta, buf);
+            let number_of_blocks = reader.read_block_count() as usize;
+            let (mut read, mut write) = (0, 0);
+            for i in 0..number_of_blocks {
+                // the first block could be zero, while the others can't
+                let len = reader.read_block() as usize + (i != 0) as usize;
+                if i % 2 == 0 {
+                    buf.copy_within(read..read + len, write);
+                    write += len;
+                }
+                read += len;
+            }
+            if number_of_blocks & 1 == 0 {
+                buf.copy_within(read..ref_degree, write);
+                write += ref_degree - read;
+            }
+            buf.truncate(write);
+        }
+
+        // move the copied successors to the end of the buffer, so that they
+        // can be merged in place with the extra successors
+        let copied = buf.len();
+        let extra = degree - copied;
+        if extra == 0 {
+            return degree;
+        }
+        buf.resize(degree, 0);
+        buf.copy_within(0..copied, extra);
+
+        let mut intervals = vec![];
+        let mut nodes_left_to_decode = extra;
+        if self.min_interval_length != 0 {
+            let number_of_intervals = reader.read_interval_count() as usize;
+            if number_of_intervals != 0 {
+                intervals.reserve_exact(number_of_intervals);
+                let node_id_offset = nat2int(reader.read_interval_start());
+                debug_assert!((node_id as i64 + node_id_offset) >= 0);
+                let mut start = (node_id as i64 + node_id_offset) as usize;
+                for i in 0..number_of_intervals {
+                    if i != 0 {
+                        start += 1 + reader.read_interval_start() as usize;
+                    }
+                    let len = reader.read_interval_len() as usize + self.min_interval_length;
+                    intervals.push(start..start + len);
+                    start += len;
+                    nodes_left_to_decode -= len;
+                }
+            }
+        }
+        let mut intervals = intervals.into_iter().flatten().peekable();
+
+        let mut next_residual = usize::MAX;
+        if nodes_left_to_decode != 0 {
+            let node_id_offset = nat2int(reader.read_first_residual());
+            next_residual = (node_id as i64 + node_id_offset) as usize;
+            nodes_left_to_decode -= 1;
+        }
+
+        // merge until the extra successors are exhausted, at which point the
+        // remaining copied successors are already in place; writes never
+        // overtake the copied successors still to be read
+        let mut next_copied = extra;
+        let mut write = 0;
+        for _ in 0..extra {
+            let next_interval = intervals.peek().copied().unwrap_or(usize::MAX);
+            let next_extra = next_interval.min(next_residual);
+            while next_copied < degree && buf[next_copied] < next_extra {
+                buf[write] = buf[next_copied];
+                next_copied += 1;
+                write += 1;
+            }
+            buf[write] = next_extra;
+            write += 1;
+            if next_extra == next_interval {
+                intervals.next();
+            } else if nodes_left_to_decode != 0 {
+                next_residual += 1 + reader.read_residual() as usize;
+                nodes_left_to_decode -= 1;
+            } else {
+                next_residual = usize::MAX;
+            }
+        }
+        degree
+    }
+}
 
 /// The iterator returned from [`BVGraph`] that returns the successors of a
 /// node in sorted order.
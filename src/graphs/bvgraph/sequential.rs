@@ -5,13 +5,10 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use std::path::PathBuf;
-
 use super::*;
 use crate::utils::nat2int;
 use crate::utils::CircularBuffer;
-use anyhow::Result;
-use bitflags::Flags;
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use dsi_bitstream::traits::BitSeek;
 use dsi_bitstream::traits::BE;
 use lender::*;
@@ -29,15 +26,32 @@ pub struct BVGraphSeq<F> {
 }
 
 impl BVGraphSeq<()> {
+    /// Returns a [load configuration](LoadConfig) for the graph with the given
+    /// basename, which can be customized and then [loaded](LoadConfig::load).
+    ///
+    /// By default the graph is memory mapped without any access hint. Since
+    /// sequential graphs are decoded front to back, on large graphs that are
+    /// not already in the page cache it is usually convenient to advise the
+    /// kernel of sequential access with [`LoadConfig::flags`] (or
+    /// [`LoadConfig::graph_flags`]): on Linux, this makes `madvise()` enable
+    /// aggressive read-ahead, so each page fault brings in a larger portion of
+    /// the file and a cold scan is bound by disk bandwidth rather than by
+    /// latency. The hint is irrelevant for graphs that are already cached.
+    ///
+    /// ```
+    /// # use webgraph::prelude::*;
+    /// # use dsi_bitstream::traits::BE;
+    /// let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+    ///     .endianness::<BE>()
+    ///     .flags(MemoryFlags::SEQUENTIAL)
+    ///     .load()?;
+    /// # assert_eq!(graph.num_nodes(), 325_557);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
     pub fn with_basename(
         basename: impl AsRef<std::path::Path>,
     ) -> LoadConfig<BE, Sequential, Dynamic, Mmap, Mmap> {
-        LoadConfig {
-            basename: PathBuf::from(basename.as_ref()),
-            graph_load_flags: Flags::empty(),
-            offsets_load_flags: Flags::empty(),
-            _marker: std::marker::PhantomData,
-        }
+        LoadConfig::new(basename)
     }
 }
 
@@ -70,19 +84,115 @@ impl<F: SequentialDecoderFactory> SequentialLabeling for BVGraphSeq<F> {
         self.number_of_arcs
     }
 
-    #[inline(always)]
+    /// Returns an iterator starting at `from`.
+    ///
+    /// Since no offsets are available, the bitstream is scanned from the
+    /// start. The encodings of most nodes before `from` are however just
+    /// skipped, as in [`offset_deg_iter`](BVGraphSeq::offset_deg_iter),
+    /// without building their successors: only the nodes from the last
+    /// node before `from` whose successors can be decoded without knowing
+    /// the successors of previous nodes are decoded and discarded. If the
+    /// `.ef` file is available, loading the graph as a [`BVGraph`] provides
+    /// an [`iter_from`](BVGraph::iter_from) that seeks directly to `from`,
+    /// decoding by random access just the nodes that might be referenced by
+    /// the first nodes returned.
+    ///
+    /// # Panics
+    ///
+    /// If the encodings of the nodes before `from` cannot be decoded; use
+    /// [`try_iter_from`](SequentialLabeling::try_iter_from) to get an error
+    /// instead.
     fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        self.try_iter_from(from.min(self.number_of_nodes))
+            .unwrap_or_else(|e| panic!("{:#}", e))
+    }
+
+    /// Returns an iterator starting at `from`, as
+    /// [`iter_from`](SequentialLabeling::iter_from) does, or an error if
+    /// `from` is larger than the number of nodes or if the encodings of the
+    /// nodes before `from` cannot be decoded.
+    fn try_iter_from(&self, from: usize) -> Result<Self::Lender<'_>> {
+        ensure!(
+            from <= self.number_of_nodes,
+            "Cannot start iterating from node {}, as the graph has {} nodes",
+            from,
+            self.number_of_nodes
+        );
+        let degrees = || -> Result<_> {
+            Ok(OffsetDegIter::new(
+                self.factory.new_decoder()?,
+                self.number_of_nodes,
+                self.compression_window,
+                self.min_interval_length,
+            ))
+        };
+        let degrees = if self.compression_window == 0 {
+            let mut degrees = degrees()?;
+            for _ in 0..from {
+                degrees.skip_successors()?;
+            }
+            degrees
+        } else {
+            skip_to_restart_node(degrees()?, degrees()?, from)
+                .with_context(|| format!("Could not scan the nodes before node {}", from))?
+        };
+
+        let start = degrees.node_id();
         let mut iter = Iter::new(
-            self.factory.new_decoder().unwrap(),
+            degrees.into_decoder(),
             self.number_of_nodes,
             self.compression_window,
             self.min_interval_length,
         );
+        iter.current_node = start;
+        for _ in start..from {
+            if let Some(Err(e)) = iter.try_next() {
+                return Err(e);
+            }
+        }
+        Ok(iter)
+    }
+}
 
-        let _ = iter.advance_by(from);
-
-        iter
+/// Returns `behind` positioned at the largest node `start` not greater than
+/// `from` minus the compression window such that no node in `[start, from)`
+/// copies successors from a node before `start`.
+///
+/// Since nodes from `from` on can reference only nodes from `from` minus the
+/// compression window on, decoding the graph from `start` with an empty
+/// window yields the correct successors of all nodes from `from` on. Note
+/// that `start` is not necessarily `from` minus the compression window
+/// times the maximum reference count: reference chains can be arbitrarily
+/// long, and their intervals can overlap, so all nodes before `from` must be
+/// scanned.
+///
+/// The nodes are scanned in a single pass by `ahead`, and `behind` is
+/// advanced to each restart node as soon as it is known to be one, that is,
+/// at most a compression window later, so the two decoders read the
+/// bitstream in lockstep. Both must be at the start of the graph.
+fn skip_to_restart_node<D: Decode>(
+    mut ahead: OffsetDegIter<D>,
+    mut behind: OffsetDegIter<D>,
+    from: usize,
+) -> Result<OffsetDegIter<D>> {
+    let compression_window = ahead.compression_window();
+    // low[j % compression_window] is the node referenced by j, or j if
+    // j does not use a reference
+    let mut low = vec![0; compression_window];
+    for node in 0..from {
+        let (_degree, ref_delta) = ahead.next_degree_and_ref_delta()?;
+        low[node % compression_window] = node - ref_delta;
+        // Only nodes in [x, x + compression_window) can reference nodes
+        // before x, so we now know whether x is a valid restart node
+        if let Some(x) = (node + 1).checked_sub(compression_window) {
+            if (x..=node).all(|j| low[j % compression_window] >= x) {
+                while behind.node_id() < x {
+                    behind.skip_successors()?;
+                }
+            }
+        }
     }
+    Ok(behind)
 }
 
 impl<F: SequentialDecoderFactory> SequentialGraph for BVGraphSeq<F> {}
@@ -153,6 +263,13 @@ where
             self.min_interval_length,
         )
     }
+
+    #[inline(always)]
+    /// Creates an iterator over the offsets, node ids, and degrees of the
+    /// nodes; see [`DegreesIter`] for the guarantees on the offsets.
+    pub fn iter_degrees(&self) -> DegreesIter<F::Decoder<'_>> {
+        self.offset_deg_iter().with_node_ids()
+    }
 }
 
 /// A fast sequential iterator over the nodes of the graph and their successors.
@@ -204,45 +321,86 @@ impl<D: Decode> Iter<D> {
         Ok(res)
     }
 
+    /// Get the successors of the next node in the stream in gap form, as in
+    /// [`RandomAccessGraph::successor_gaps`].
+    pub fn next_successor_gaps(
+        &mut self,
+    ) -> Result<SuccessorGaps<core::iter::Copied<core::slice::Iter<'_, usize>>>> {
+        Ok(SuccessorGaps::new(self.next_successors()?.iter().copied()))
+    }
+
     #[inline(always)]
     /// Inner method called by `next_successors` and the iterator `next` method
+    ///
+    /// Decoded values are checked to be within the range of nodes of the
+    /// graph, so that a corrupt bitstream results in an error rather than in
+    /// wrong successors.
     fn get_successors_iter_priv(&mut self, node_id: usize, results: &mut Vec<usize>) -> Result<()> {
-        let degree = self.decoder.read_outdegree() as usize;
+        let degree = self.decoder.try_read_outdegree()? as usize;
         // no edges, we are done!
         if degree == 0 {
             return Ok(());
         }
+        ensure!(
+            degree <= self.number_of_nodes,
+            "Node {}: outdegree {} out of range (the graph has {} nodes)",
+            node_id,
+            degree,
+            self.number_of_nodes
+        );
 
         // ensure that we have enough capacity in the vector for not reallocating
         results.reserve(degree.saturating_sub(results.capacity()));
         // read the reference offset
         let ref_delta = if self.compression_window != 0 {
-            self.decoder.read_reference_offset() as usize
+            self.decoder.try_read_reference_offset()? as usize
         } else {
             0
         };
         // if we copy nodes from a previous one
         if ref_delta != 0 {
+            ensure!(
+                ref_delta <= node_id.min(self.compression_window),
+                "Node {}: reference offset {} out of range (compression window {})",
+                node_id,
+                ref_delta,
+                self.compression_window
+            );
             // compute the node id of the reference
             let reference_node_id = node_id - ref_delta;
             // retrieve the data
-            let neighbours = &self.backrefs[reference_node_id];
+            let neighbours = self.backrefs.get(reference_node_id).ok_or_else(|| {
+                anyhow!(
+                    "Node {}: reference {} is not in the compression window",
+                    node_id,
+                    reference_node_id
+                )
+            })?;
             //debug_assert!(!neighbours.is_empty());
             // get the info on which destinations to copy
-            let number_of_blocks = self.decoder.read_block_count() as usize;
+            let number_of_blocks = self.decoder.try_read_block_count()? as usize;
             // no blocks, we copy everything
             if number_of_blocks == 0 {
                 results.extend_from_slice(neighbours);
             } else {
+                let out_of_range = |end: usize| {
+                    anyhow!(
+                        "Node {}: block ending at {} exceeds the {} successors of the reference",
+                        node_id,
+                        end,
+                        neighbours.len()
+                    )
+                };
                 // otherwise we copy only the blocks of even index
                 // the first block could be zero
-                let mut idx = self.decoder.read_block() as usize;
-                results.extend_from_slice(&neighbours[..idx]);
+                let mut idx = self.decoder.try_read_block()? as usize;
+                results.extend_from_slice(neighbours.get(..idx).ok_or_else(|| out_of_range(idx))?);
 
                 // while the other can't
                 for block_id in 1..number_of_blocks {
-                    let block = self.decoder.read_block() as usize;
-                    let end = idx + block + 1;
+                    let block = self.decoder.try_read_block()? as usize;
+                    let end = idx.saturating_add(block).saturating_add(1);
+                    ensure!(end <= neighbours.len(), out_of_range(end));
                     if block_id % 2 == 0 {
                         results.extend_from_slice(&neighbours[idx..end]);
                     }
@@ -254,52 +412,118 @@ impl<D: Decode> Iter<D> {
             }
         };
 
+        let num_copied = results.len();
+        ensure!(
+            results.len() <= degree,
+            "Node {}: {} successors copied from the reference, but the outdegree is {}",
+            node_id,
+            results.len(),
+            degree
+        );
         // if we still have to read nodes
         let nodes_left_to_decode = degree - results.len();
         if nodes_left_to_decode != 0 && self.min_interval_length != 0 {
             // read the number of intervals
-            let number_of_intervals = self.decoder.read_interval_count() as usize;
+            let number_of_intervals = self.decoder.try_read_interval_count()? as usize;
             if number_of_intervals != 0 {
                 // pre-allocate with capacity for efficiency
-                let node_id_offset = nat2int(self.decoder.read_interval_start());
-                let mut start = (node_id as i64 + node_id_offset) as usize;
-                let mut delta = self.decoder.read_interval_len() as usize;
-                delta += self.min_interval_length;
+                let node_id_offset = nat2int(self.decoder.try_read_interval_start()?);
+                let mut start =
+                    self.check_node(node_id, (node_id as i64).saturating_add(node_id_offset))?;
+                let mut delta = self.decoder.try_read_interval_len()? as usize;
+                delta = delta.saturating_add(self.min_interval_length);
                 // save the first interval
-                results.extend(start..(start + delta));
-                start += delta;
+                let mut end = self.check_interval_end(node_id, start, delta)?;
+                results.extend(start..end);
+                start = end;
                 // decode the intervals
                 for _ in 1..number_of_intervals {
-                    start += 1 + self.decoder.read_interval_start() as usize;
-                    delta = self.decoder.read_interval_len() as usize;
-                    delta += self.min_interval_length;
+                    start = start
+                        .saturating_add(1)
+                        .saturating_add(self.decoder.try_read_interval_start()? as usize);
+                    delta = self.decoder.try_read_interval_len()? as usize;
+                    delta = delta.saturating_add(self.min_interval_length);
 
-                    results.extend(start..(start + delta));
+                    end = self.check_interval_end(node_id, start, delta)?;
+                    results.extend(start..end);
 
-                    start += delta;
+                    start = end;
                 }
             }
         }
 
+        let num_intervals = results.len() - num_copied;
+        ensure!(
+            results.len() <= degree,
+            "Node {}: {} successors in intervals and references, but the outdegree is {}",
+            node_id,
+            results.len(),
+            degree
+        );
         // decode the extra nodes if needed
         let nodes_left_to_decode = degree - results.len();
         if nodes_left_to_decode != 0 {
             // pre-allocate with capacity for efficiency
-            let node_id_offset = nat2int(self.decoder.read_first_residual());
-            let mut extra = (node_id as i64 + node_id_offset) as usize;
+            let node_id_offset = nat2int(self.decoder.try_read_first_residual()?);
+            let mut extra =
+                self.check_node(node_id, (node_id as i64).saturating_add(node_id_offset))?;
             results.push(extra);
             // decode the successive extra nodes
             for _ in 1..nodes_left_to_decode {
-                extra += 1 + self.decoder.read_residual() as usize;
+                let residual = self.decoder.try_read_residual()? as usize;
+                extra = extra.saturating_add(1).saturating_add(residual);
+                ensure!(
+                    extra < self.number_of_nodes,
+                    "Node {}: residual successor {} out of range (the graph has {} nodes)",
+                    node_id,
+                    extra,
+                    self.number_of_nodes
+                );
                 results.push(extra);
             }
         }
 
-        results.sort();
+        // copied successors, intervals and residuals are each sorted, so we
+        // need to sort only if at least two of them are present
+        let num_residuals = results.len() - num_copied - num_intervals;
+        if (num_copied != 0) as u8 + (num_intervals != 0) as u8 + (num_residuals != 0) as u8 > 1 {
+            results.sort();
+        }
         Ok(())
     }
 }
 
+impl<D: Decode> Iter<D> {
+    /// Returns `succ` as a node, or an error if `succ` is not a node of the graph.
+    #[inline(always)]
+    fn check_node(&self, node_id: usize, succ: i64) -> Result<usize> {
+        ensure!(
+            succ >= 0 && (succ as u64) < self.number_of_nodes as u64,
+            "Node {}: successor {} out of range (the graph has {} nodes)",
+            node_id,
+            succ,
+            self.number_of_nodes
+        );
+        Ok(succ as usize)
+    }
+
+    /// Returns the end of the interval starting at `start` of length `len`,
+    /// or an error if the interval contains nodes that are not in the graph.
+    #[inline(always)]
+    fn check_interval_end(&self, node_id: usize, start: usize, len: usize) -> Result<usize> {
+        match start.checked_add(len) {
+            Some(end) if end <= self.number_of_nodes => Ok(end),
+            _ => bail!(
+                "Node {}: interval of length {} starting at {} out of range (the graph has {} nodes)",
+                node_id,
+                len,
+                start,
+                self.number_of_nodes
+            ),
+        }
+    }
+}
+
 impl<'succ, D: Decode> NodeLabelsLender<'succ> for Iter<D> {
     type Label = usize;
     type IntoIterator =
@@ -310,22 +534,46 @@ impl<'succ, D: Decode> Lending<'succ> for Iter<D> {
     type Lend = (usize, <Self as NodeLabelsLender<'succ>>::IntoIterator);
 }
 
-impl<D: Decode> Lender for Iter<D> {
-    fn next(&mut self) -> Option<Lend<'_, Self>> {
+impl<D: Decode> Iter<D> {
+    /// Fallible version of [`next`](Lender::next): inconsistent encodings,
+    /// such as successors, intervals or references out of range, and errors
+    /// of the underlying bit reader, such as reading past the end of a
+    /// truncated bitstream, are returned as errors rather than causing a
+    /// panic.
+    ///
+    /// Note that the readers of the [load modes](crate::graphs::bvgraph::LoadMode)
+    /// extend the bitstream with zeros, so past its end they do not return
+    /// errors.
+    ///
+    /// After an error, the state of the iterator is unspecified, and it
+    /// should not be used further.
+    pub fn try_next(&mut self) -> Option<Result<Lend<'_, Self>>> {
         if self.current_node >= self.number_of_nodes as _ {
             return None;
         }
         let mut res = self.backrefs.take(self.current_node);
         res.clear();
-        self.get_successors_iter_priv(self.current_node, &mut res)
-            .unwrap();
+        if let Err(e) = self.get_successors_iter_priv(self.current_node, &mut res) {
+            return Some(Err(e));
+        }
 
         let res = self.backrefs.replace(self.current_node, res);
         let node_id = self.current_node;
         self.current_node += 1;
-        Some((node_id, unsafe {
+        Some(Ok((node_id, unsafe {
             crate::traits::labels::SortedIter::new(res.iter().copied())
-        }))
+        })))
+    }
+}
+
+impl<D: Decode> Lender for Iter<D> {
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.try_next().map(|res| res.unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
     }
 }
 
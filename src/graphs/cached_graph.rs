@@ -0,0 +1,224 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+/// The least-recently-used cache of a [`CachedGraph`].
+#[derive(Debug)]
+struct Lru {
+    /// The successors of the cached nodes, with their last access time.
+    map: HashMap<usize, (Vec<usize>, u64)>,
+    /// The cached nodes, by last access time.
+    by_time: BTreeMap<u64, usize>,
+    /// The current time, incremented at each access.
+    time: u64,
+    hits: u64,
+    misses: u64,
+}
+
+/// A wrapper around a [random-access graph](RandomAccessGraph) that memoizes
+/// decoded successor lists.
+///
+/// The successors of the last `capacity` distinct nodes passed to
+/// [`successors`](RandomAccessGraph::successors) are kept in a
+/// least-recently-used cache: on a hit, a copy of the cached list is
+/// returned; on a miss, the list is decoded by the underlying graph and
+/// inserted in the cache, possibly evicting the least recently used node.
+/// This is useful when the same nodes (e.g., hubs) are queried over and
+/// over, as decoding a successor list is much more expensive than cloning
+/// it.
+///
+/// Sequential iteration is delegated to the underlying graph and does not
+/// touch the cache. The cache is protected by a mutex, so the wrapper can be
+/// shared among threads, albeit with some contention.
+#[derive(Debug)]
+pub struct CachedGraph<G: RandomAccessGraph> {
+    graph: G,
+    capacity: usize,
+    cache: Mutex<Lru>,
+}
+
+impl<G: RandomAccessGraph> CachedGraph<G> {
+    /// Creates a wrapper caching the successors of at most `capacity` nodes.
+    pub fn new(graph: G, capacity: usize) -> Self {
+        Self {
+            graph,
+            capacity,
+            cache: Mutex::new(Lru {
+                map: HashMap::with_capacity(capacity),
+                by_time: BTreeMap::new(),
+                time: 0,
+                hits: 0,
+                misses: 0,
+            }),
+        }
+    }
+
+    /// Returns a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Consumes the wrapper, returning the underlying graph.
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+
+    /// Returns the maximum number of nodes whose successors are cached.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of nodes whose successors are currently cached.
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap().map.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of calls to
+    /// [`successors`](RandomAccessGraph::successors) served by the cache.
+    pub fn hits(&self) -> u64 {
+        self.cache.lock().unwrap().hits
+    }
+
+    /// Returns the number of calls to
+    /// [`successors`](RandomAccessGraph::successors) that had to decode the
+    /// successors.
+    pub fn misses(&self) -> u64 {
+        self.cache.lock().unwrap().misses
+    }
+
+    /// Empties the cache and resets the counters.
+    pub fn clear(&self) {
+        let mut cache = self.cache.lock().unwrap();
+        cache.map.clear();
+        cache.by_time.clear();
+        cache.hits = 0;
+        cache.misses = 0;
+    }
+}
+
+impl<G: RandomAccessGraph> SequentialLabeling for CachedGraph<G> {
+    type Label = usize;
+    type Lender<'b> = G::Lender<'b>
+        where
+            Self: 'b;
+
+    #[inline(always)]
+    fn num_nodes(&self) -> usize {
+        self.graph.num_nodes()
+    }
+
+    #[inline(always)]
+    fn num_arcs_hint(&self) -> Option<u64> {
+        self.graph.num_arcs_hint()
+    }
+
+    #[inline(always)]
+    fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        self.graph.iter_from(from)
+    }
+}
+
+impl<G: RandomAccessGraph> SequentialGraph for CachedGraph<G> {}
+
+impl<G: RandomAccessGraph> RandomAccessLabeling for CachedGraph<G> {
+    type Labels<'a> = std::vec::IntoIter<usize>
+        where
+            Self: 'a;
+
+    #[inline(always)]
+    fn num_arcs(&self) -> u64 {
+        self.graph.num_arcs()
+    }
+
+    fn labels(&self, node_id: usize) -> <Self as RandomAccessLabeling>::Labels<'_> {
+        {
+            let mut cache = self.cache.lock().unwrap();
+            let cache = &mut *cache;
+            cache.time += 1;
+            if let Some((succ, time)) = cache.map.get_mut(&node_id) {
+                cache.by_time.remove(time);
+                *time = cache.time;
+                cache.by_time.insert(cache.time, node_id);
+                cache.hits += 1;
+                return succ.clone().into_iter();
+            }
+            cache.misses += 1;
+        }
+
+        // Decode without holding the lock
+        let succ = self
+            .graph
+            .successors(node_id)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        if self.capacity > 0 {
+            let mut cache = self.cache.lock().unwrap();
+            let cache = &mut *cache;
+            // Another thread might have inserted the same node meanwhile
+            if !cache.map.contains_key(&node_id) {
+                if cache.map.len() == self.capacity {
+                    let (_, evicted) = cache.by_time.pop_first().unwrap();
+                    cache.map.remove(&evicted);
+                }
+                cache.time += 1;
+                cache.map.insert(node_id, (succ.clone(), cache.time));
+                cache.by_time.insert(cache.time, node_id);
+            }
+        }
+        succ.into_iter()
+    }
+
+    #[inline(always)]
+    fn outdegree(&self, node_id: usize) -> usize {
+        if let Some((succ, _)) = self.cache.lock().unwrap().map.get(&node_id) {
+            return succ.len();
+        }
+        self.graph.outdegree(node_id)
+    }
+}
+
+impl<G: RandomAccessGraph> RandomAccessGraph for CachedGraph<G> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_graph() {
+        let graph = Left(VecGraph::from_arc_list([
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 0),
+            (3, 3),
+        ]));
+        let cached = CachedGraph::new(&graph, 2);
+
+        for node in [0, 1, 0, 2, 0, 3, 1] {
+            assert_eq!(
+                cached.successors(node).collect::<Vec<_>>(),
+                graph.successors(node).into_iter().collect::<Vec<_>>()
+            );
+            assert!(cached.len() <= 2);
+        }
+        // 1, 2 and 0 are evicted by 2, 3 and 1, respectively
+        assert_eq!((cached.hits(), cached.misses()), (2, 5));
+        assert_eq!(cached.outdegree(3), 1);
+
+        cached.clear();
+        assert!(cached.is_empty());
+        assert_eq!((cached.hits(), cached.misses()), (0, 0));
+    }
+}
@@ -0,0 +1,186 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use lender::*;
+
+/// A wrapper that keeps only the arcs of a graph satisfying a predicate; see
+/// also [`filter_arcs`](crate::transform::filter_arcs).
+///
+/// The second element is a function that, given the source and the
+/// destination of an arc, returns whether the arc should be kept. Since
+/// filtering preserves the order of successors, sortedness of the underlying
+/// graph is preserved. As for [`NoSelfLoopsGraph`], we can't provide the
+/// number of arcs or the outdegree of a node without enumerating successors,
+/// so we can't implement random access to the successors.
+#[derive(Debug, Clone)]
+pub struct FilterArcsGraph<G, F>(pub G, pub F);
+
+impl<G: SequentialGraph, F: Fn(usize, usize) -> bool> SequentialLabeling for FilterArcsGraph<G, F> {
+    type Label = usize;
+    type Lender<'b> = Iter<'b, G::Lender<'b>, F>
+        where
+            Self: 'b;
+
+    #[inline(always)]
+    fn num_nodes(&self) -> usize {
+        self.0.num_nodes()
+    }
+
+    #[inline(always)]
+    fn num_arcs_hint(&self) -> Option<u64> {
+        None
+    }
+
+    #[inline(always)]
+    fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        Iter {
+            iter: self.0.iter_from(from),
+            filter: &self.1,
+        }
+    }
+}
+
+impl<G: SequentialGraph + SplitLabeling, F: Fn(usize, usize) -> bool + Sync> SplitLabeling
+    for FilterArcsGraph<G, F>
+where
+    for<'a> <G as SequentialLabeling>::Lender<'a>: Clone + Send + Sync,
+{
+    type SplitLender<'a> = split::seq::Lender<'a, FilterArcsGraph<G, F>> where Self: 'a;
+    type IntoIterator<'a> = split::seq::IntoIterator<'a, FilterArcsGraph<G, F>> where Self: 'a;
+
+    fn split_iter(&self, how_many: usize) -> Self::IntoIterator<'_> {
+        split::seq::Iter::new(self.iter(), self.num_nodes(), how_many)
+    }
+}
+
+impl<G: SequentialGraph, F: Fn(usize, usize) -> bool> SequentialGraph for FilterArcsGraph<G, F> {}
+
+impl<'b, G: SequentialGraph, F: Fn(usize, usize) -> bool> IntoLender for &'b FilterArcsGraph<G, F> {
+    type Lender = <FilterArcsGraph<G, F> as SequentialLabeling>::Lender<'b>;
+
+    #[inline(always)]
+    fn into_lender(self) -> Self::Lender {
+        self.iter()
+    }
+}
+
+/// An iterator over the nodes of a graph that filters on the fly the arcs.
+#[derive(Debug)]
+pub struct Iter<'b, I, F> {
+    iter: I,
+    filter: &'b F,
+}
+
+impl<'b, I: Clone, F> Clone for Iter<'b, I, F> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            filter: self.filter,
+        }
+    }
+}
+
+impl<'b, 'succ, I, F> NodeLabelsLender<'succ> for Iter<'b, I, F>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    F: Fn(usize, usize) -> bool,
+{
+    type Label = usize;
+    type IntoIterator = Succ<'b, LenderIntoIter<'succ, I>, F>;
+}
+
+impl<'b, 'succ, I, F> Lending<'succ> for Iter<'b, I, F>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    F: Fn(usize, usize) -> bool,
+{
+    type Lend = (usize, <Self as NodeLabelsLender<'succ>>::IntoIterator);
+}
+
+unsafe impl<'b, I, F> SortedLender for Iter<'b, I, F>
+where
+    I: SortedLender + Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    F: Fn(usize, usize) -> bool,
+{
+}
+
+impl<'b, I, F> Lender for Iter<'b, I, F>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    F: Fn(usize, usize) -> bool,
+{
+    #[inline(always)]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let filter = self.filter;
+        self.iter.next().map(|x| {
+            let (node, succ) = x.into_pair();
+            (
+                node,
+                Succ {
+                    src: node,
+                    iter: succ.into_iter(),
+                    filter,
+                },
+            )
+        })
+    }
+}
+
+impl<'b, I, F> ExactSizeLender for Iter<'b, I, F>
+where
+    I: ExactSizeLender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    F: Fn(usize, usize) -> bool,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct Succ<'b, I: Iterator<Item = usize>, F> {
+    src: usize,
+    iter: I,
+    filter: &'b F,
+}
+
+impl<'b, I: Iterator<Item = usize>, F: Fn(usize, usize) -> bool> Iterator for Succ<'b, I, F> {
+    type Item = usize;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let dst = self.iter.next()?;
+            if (self.filter)(self.src, dst) {
+                return Some(dst);
+            }
+        }
+    }
+}
+
+unsafe impl<'b, I, F> SortedIterator for Succ<'b, I, F>
+where
+    I: Iterator<Item = usize> + SortedIterator,
+    F: Fn(usize, usize) -> bool,
+{
+}
+
+#[cfg(test)]
+#[test]
+fn test_filter_arcs_graph() -> anyhow::Result<()> {
+    use crate::{graphs::vec_graph::VecGraph, prelude::proj::Left};
+    let g = VecGraph::from_arc_list([(0, 1), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+    let p = FilterArcsGraph(Left(g), |src, dst| src != dst);
+    assert_eq!(p.num_nodes(), 3);
+    assert_eq!(p.num_arcs_hint(), None);
+
+    let mut iter = p.iter();
+    assert_eq!(iter.next().unwrap().1.collect::<Vec<_>>(), vec![1]);
+    assert_eq!(iter.next().unwrap().1.collect::<Vec<_>>(), vec![2]);
+    assert_eq!(iter.next().unwrap().1.collect::<Vec<_>>(), vec![0, 1]);
+    assert!(iter.next().is_none());
+
+    Ok(())
+}
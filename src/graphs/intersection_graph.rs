@@ -0,0 +1,243 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use lender::*;
+
+#[derive(Debug, Clone)]
+/// A wrapper exhibiting the intersection of two graphs with the same number
+/// of nodes; see also [`intersect`](crate::transform::intersect).
+///
+/// The successors of each node are the successors of the node that appear in
+/// both graphs, computed by a streaming merge of the two sorted successor
+/// lists, as in the case of [`UnionGraph`].
+///
+/// The two graphs must have the same number of nodes: use
+/// [`IntersectionGraph::new`] to check it.
+pub struct IntersectionGraph<G: SequentialGraph, H: SequentialGraph>(pub G, pub H);
+
+impl<G: SequentialGraph, H: SequentialGraph> IntersectionGraph<G, H> {
+    /// Creates the intersection of two graphs, returning an error if they do
+    /// not have the same number of nodes.
+    pub fn new(g: G, h: H) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            g.num_nodes() == h.num_nodes(),
+            "The graphs have a different number of nodes: {} != {}",
+            g.num_nodes(),
+            h.num_nodes()
+        );
+        Ok(Self(g, h))
+    }
+}
+
+impl<G: SequentialGraph, H: SequentialGraph> SequentialLabeling for IntersectionGraph<G, H>
+where
+    for<'a> G::Lender<'a>: SortedLender,
+    for<'a, 'b> LenderIntoIter<'b, G::Lender<'a>>: SortedIterator,
+    for<'a> H::Lender<'a>: SortedLender,
+    for<'a, 'b> LenderIntoIter<'b, H::Lender<'a>>: SortedIterator,
+{
+    type Label = usize;
+    type Lender<'b> = Iter<G::Lender<'b>, H::Lender<'b>>
+        where
+            Self: 'b;
+
+    #[inline(always)]
+    fn num_nodes(&self) -> usize {
+        debug_assert_eq!(self.0.num_nodes(), self.1.num_nodes());
+        self.0.num_nodes()
+    }
+
+    #[inline(always)]
+    fn num_arcs_hint(&self) -> Option<u64> {
+        None
+    }
+
+    #[inline(always)]
+    fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        Iter(self.0.iter_from(from), self.1.iter_from(from))
+    }
+}
+
+impl<G: SequentialGraph, H: SequentialGraph> SplitLabeling for IntersectionGraph<G, H>
+where
+    for<'a> G::Lender<'a>: SortedLender + Clone + Send + Sync,
+    for<'a, 'b> LenderIntoIter<'b, G::Lender<'a>>: SortedIterator,
+    for<'a> H::Lender<'a>: SortedLender + Clone + Send + Sync,
+    for<'a, 'b> LenderIntoIter<'b, H::Lender<'a>>: SortedIterator,
+{
+    type SplitLender<'a> = split::seq::Lender<'a, IntersectionGraph<G, H>> where Self: 'a;
+    type IntoIterator<'a> = split::seq::IntoIterator<'a, IntersectionGraph<G, H>> where Self: 'a;
+
+    fn split_iter(&self, how_many: usize) -> Self::IntoIterator<'_> {
+        split::seq::Iter::new(self.iter(), self.num_nodes(), how_many)
+    }
+}
+
+impl<G: SequentialGraph, H: SequentialGraph> SequentialGraph for IntersectionGraph<G, H>
+where
+    for<'a> G::Lender<'a>: SortedLender + Clone,
+    for<'a, 'b> LenderIntoIter<'b, G::Lender<'a>>: SortedIterator,
+    for<'a> H::Lender<'a>: SortedLender + Clone,
+    for<'a, 'b> LenderIntoIter<'b, H::Lender<'a>>: SortedIterator,
+{
+}
+
+impl<'c, G: SequentialGraph, H: SequentialGraph> IntoLender for &'c IntersectionGraph<G, H>
+where
+    for<'a> G::Lender<'a>: SortedLender + Clone,
+    for<'a, 'b> LenderIntoIter<'b, G::Lender<'a>>: SortedIterator,
+    for<'a> H::Lender<'a>: SortedLender + Clone,
+    for<'a, 'b> LenderIntoIter<'b, H::Lender<'a>>: SortedIterator,
+{
+    type Lender = <IntersectionGraph<G, H> as SequentialLabeling>::Lender<'c>;
+
+    #[inline(always)]
+    fn into_lender(self) -> Self::Lender {
+        self.iter()
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct Iter<L, M>(L, M);
+
+impl<
+        'succ,
+        L: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+        M: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    > NodeLabelsLender<'succ> for Iter<L, M>
+{
+    type Label = usize;
+    type IntoIterator = Succ<LenderIntoIter<'succ, L>, LenderIntoIter<'succ, M>>;
+}
+
+impl<
+        'succ,
+        L: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+        M: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    > Lending<'succ> for Iter<L, M>
+{
+    type Lend = (usize, <Self as NodeLabelsLender<'succ>>::IntoIterator);
+}
+
+impl<
+        L: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+        M: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+    > Lender for Iter<L, M>
+{
+    #[inline(always)]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let (node0, iter0) = self.0.next()?.into_pair();
+        let (node1, iter1) = self.1.next()?.into_pair();
+        debug_assert_eq!(node0, node1);
+        Some((node0, Succ::new(iter0.into_iter(), iter1.into_iter())))
+    }
+}
+
+impl<
+        L: Lender + for<'next> NodeLabelsLender<'next, Label = usize> + ExactSizeLender,
+        M: Lender + for<'next> NodeLabelsLender<'next, Label = usize> + ExactSizeLender,
+    > ExactSizeLender for Iter<L, M>
+{
+    fn len(&self) -> usize {
+        self.0.len().min(self.1.len())
+    }
+}
+
+unsafe impl<
+        L: Lender + for<'next> NodeLabelsLender<'next, Label = usize> + SortedLender,
+        M: Lender + for<'next> NodeLabelsLender<'next, Label = usize> + SortedLender,
+    > SortedLender for Iter<L, M>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct Succ<I: Iterator<Item = usize>, J: Iterator<Item = usize>> {
+    iter0: I,
+    iter1: J,
+}
+
+impl<I: Iterator<Item = usize>, J: Iterator<Item = usize>> Succ<I, J> {
+    pub fn new(iter0: I, iter1: J) -> Self {
+        Self { iter0, iter1 }
+    }
+}
+
+impl<I: Iterator<Item = usize>, J: Iterator<Item = usize>> Iterator for Succ<I, J> {
+    type Item = usize;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut next0 = self.iter0.next()?;
+        let mut next1 = self.iter1.next()?;
+        loop {
+            match next0.cmp(&next1) {
+                std::cmp::Ordering::Less => next0 = self.iter0.next()?,
+                std::cmp::Ordering::Greater => next1 = self.iter1.next()?,
+                std::cmp::Ordering::Equal => return Some(next0),
+            }
+        }
+    }
+}
+
+unsafe impl<I: Iterator<Item = usize> + SortedIterator, J: Iterator<Item = usize> + SortedIterator>
+    SortedIterator for Succ<I, J>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graphs::vec_graph::VecGraph, prelude::proj::Left};
+
+    fn successors<S: SequentialLabeling<Label = usize>>(graph: &S) -> Vec<Vec<usize>> {
+        let mut result = vec![];
+        for_!((_, succ) in graph.iter() {
+            result.push(succ.into_iter().collect());
+        });
+        result
+    }
+
+    #[test]
+    fn test_intersection_graph() -> anyhow::Result<()> {
+        let arcs0 = [(0, 1), (0, 4), (1, 1), (2, 0), (3, 2), (3, 4), (4, 2)];
+        let arcs1 = [(0, 2), (0, 4), (1, 0), (3, 1), (3, 2), (3, 4), (4, 3)];
+        let intersection = IntersectionGraph::new(
+            Left(VecGraph::from_arc_list(arcs0)),
+            Left(VecGraph::from_arc_list(arcs1)),
+        )?;
+        assert_eq!(intersection.num_nodes(), 5);
+        assert_eq!(
+            successors(&intersection),
+            vec![vec![4], vec![], vec![], vec![2, 4], vec![]]
+        );
+
+        // The intersection of a graph with itself is the graph
+        let intersection = IntersectionGraph::new(
+            Left(VecGraph::from_arc_list(arcs0)),
+            Left(VecGraph::from_arc_list(arcs0)),
+        )?;
+        assert_eq!(
+            successors(&intersection),
+            successors(&Left(VecGraph::from_arc_list(arcs0)))
+        );
+
+        // The intersection with an arc-disjoint graph is empty
+        let disjoint = [(0, 0), (1, 2), (2, 1), (3, 0), (4, 4)];
+        let intersection = IntersectionGraph::new(
+            Left(VecGraph::from_arc_list(arcs0)),
+            Left(VecGraph::from_arc_list(disjoint)),
+        )?;
+        assert!(successors(&intersection).iter().all(Vec::is_empty));
+
+        assert!(IntersectionGraph::new(
+            Left(VecGraph::from_arc_list(arcs0)),
+            Left(VecGraph::from_arc_list([(5, 0)])),
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -7,16 +7,26 @@
 
 pub mod arc_list_graph;
 pub mod bvgraph;
+pub mod cached_graph;
+pub mod filter_arcs_graph;
+pub mod intersection_graph;
 pub mod no_selfloops_graph;
 pub mod permuted_graph;
+pub mod predecessors;
 pub mod random;
+pub mod subgraph;
 pub mod union_graph;
 pub mod vec_graph;
 
 pub mod prelude {
     pub use super::bvgraph::*;
+    pub use super::cached_graph::CachedGraph;
+    pub use super::filter_arcs_graph::FilterArcsGraph;
+    pub use super::intersection_graph::IntersectionGraph;
     pub use super::no_selfloops_graph::NoSelfLoopsGraph;
     pub use super::permuted_graph::PermutedGraph;
+    pub use super::predecessors::{DiskPredecessors, Predecessors};
+    pub use super::subgraph::SubGraph;
     pub use super::union_graph::UnionGraph;
     pub use super::vec_graph::VecGraph;
 }
@@ -26,8 +26,8 @@ impl<G: SequentialGraph> SequentialLabeling for NoSelfLoopsGraph<G> {
 
     #[inline(always)]
     fn num_arcs_hint(&self) -> Option<u64> {
-        // it's just a hint, and we don't know how many self-loops there are
-        self.0.num_arcs_hint()
+        // we don't know how many self-loops there are
+        None
     }
 
     #[inline(always)]
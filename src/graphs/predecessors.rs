@@ -0,0 +1,178 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use crate::transform::transpose;
+use anyhow::{Context, Result};
+use dsi_bitstream::traits::BE;
+use epserde::deser::DeserType;
+use itertools::Either;
+use std::cell::OnceCell;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// The type of the transpose built in memory by [`Predecessors`].
+pub type InMemoryTranspose = Left<VecGraph<()>>;
+
+/// A wrapper exhibiting the predecessors of the nodes of a graph.
+///
+/// The predecessors of a node are its successors in the transpose of the
+/// graph. If the transpose is available, for example because it has been
+/// compressed under its own basename, it can be passed to
+/// [`Predecessors::with_transpose`], and [`Predecessors::predecessors`] will
+/// just delegate to its random-access
+/// [`successors`](RandomAccessLabeling::labels).
+///
+/// Otherwise, [`Predecessors::new`] will build the transpose using
+/// [`transpose`] at the first call to [`Predecessors::predecessors`], and will
+/// cache it for the subsequent calls. Note that the cached transpose is a
+/// [`VecGraph`], which uses much more memory than a compressed
+/// [`BVGraph`]—tens of bytes per arc, rather than a few bits—so this option
+/// is viable only for graphs that are small with respect to the available
+/// memory. Building the transpose requires also temporary disk space, as
+/// arcs are sorted externally in batches of `batch_size` arcs.
+///
+/// For example, given a graph with basename `basename` whose transpose has
+/// basename `basename-t`:
+///
+/// ```ignore
+/// let graph = BVGraph::with_basename("basename").load()?;
+/// let transpose = BVGraph::with_basename("basename-t").load()?;
+/// let pred = Predecessors::with_transpose(graph, transpose);
+/// for p in pred.predecessors(0)? {
+///     // ...
+/// }
+/// ```
+pub struct Predecessors<G: SequentialGraph, T: RandomAccessGraph = InMemoryTranspose> {
+    graph: G,
+    batch_size: usize,
+    transpose: Option<T>,
+    in_memory: OnceCell<InMemoryTranspose>,
+}
+
+impl<G: SequentialGraph> Predecessors<G> {
+    /// Creates a wrapper that will build the transpose of `graph` in memory
+    /// at the first call to [`Predecessors::predecessors`], sorting arcs in
+    /// batches of `batch_size` arcs.
+    pub fn new(graph: G, batch_size: usize) -> Self {
+        Self {
+            graph,
+            batch_size,
+            transpose: None,
+            in_memory: OnceCell::new(),
+        }
+    }
+}
+
+impl<G: SequentialGraph, T: RandomAccessGraph> Predecessors<G, T> {
+    /// Creates a wrapper that will use `transpose`, which must be the
+    /// transpose of `graph`, to enumerate predecessors.
+    pub fn with_transpose(graph: G, transpose: T) -> Self {
+        Self {
+            graph,
+            batch_size: 0,
+            transpose: Some(transpose),
+            in_memory: OnceCell::new(),
+        }
+    }
+
+    /// Returns the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Returns an iterator over the predecessors of `node_id`, that is, over
+    /// the nodes `u` such that `node_id` is a successor of `u`, in increasing
+    /// order.
+    ///
+    /// If no transpose was provided and this is the first call, the
+    /// transpose will be built in memory, and the method will return an
+    /// error if the construction fails.
+    pub fn predecessors(&self, node_id: usize) -> Result<impl Iterator<Item = usize> + '_> {
+        if let Some(transpose) = &self.transpose {
+            return Ok(Either::Left(transpose.successors(node_id).into_iter()));
+        }
+        if self.in_memory.get().is_none() {
+            let transposed = transpose(&self.graph, self.batch_size)?;
+            let _ = self.in_memory.set(Left(VecGraph::from_lender(&transposed)));
+        }
+        Ok(Either::Right(
+            self.in_memory
+                .get()
+                .unwrap()
+                .successors(node_id)
+                .into_iter(),
+        ))
+    }
+}
+
+/// The type of the transpose compressed on disk by [`DiskPredecessors`].
+pub type DiskTranspose =
+    BVGraph<DynCodesDecoderFactory<BE, MmapHelper<u32>, DeserType<'static, EF>>>;
+
+/// Predecessors of the nodes of a graph computed out of core.
+///
+/// Differently from [`Predecessors`], which caches the transpose in memory,
+/// this structure [transposes](transpose) the graph at construction time and
+/// compresses the transpose as a [`BVGraph`] in a temporary directory, which
+/// is then memory-mapped to answer [`predecessors`](Self::predecessors)
+/// queries by random access. Memory usage is thus bounded by the batches
+/// used for sorting and by the offsets of the transpose. The temporary
+/// directory is deleted when the structure is dropped.
+pub struct DiskPredecessors {
+    // must be dropped before the directory containing its files
+    transpose: DiskTranspose,
+    basename: PathBuf,
+    _dir: TempDir,
+}
+
+impl DiskPredecessors {
+    /// Transposes `graph`, sorting arcs in batches of `batch_size` arcs, and
+    /// compresses the transpose in a temporary directory.
+    pub fn new(graph: &impl SequentialGraph, batch_size: usize) -> Result<Self> {
+        let dir = tempfile::Builder::new()
+            .prefix("DiskPredecessors")
+            .tempdir()?;
+        let basename = dir.path().join("transpose");
+        let transposed = transpose(graph, batch_size).context("Could not transpose the graph")?;
+        BVComp::single_thread::<BE, _>(
+            &basename,
+            &transposed,
+            CompFlags::default(),
+            true,
+            Some(graph.num_nodes()),
+        )
+        .context("Could not compress the transpose")?;
+        load_or_build_ef(&basename)?;
+        let transpose = BVGraph::with_basename(&basename)
+            .endianness::<BE>()
+            .load()
+            .context("Could not load the transpose")?;
+        Ok(Self {
+            transpose,
+            basename,
+            _dir: dir,
+        })
+    }
+
+    /// Returns the transpose.
+    pub fn transpose(&self) -> &DiskTranspose {
+        &self.transpose
+    }
+
+    /// Returns the basename of the transpose, whose files are deleted when
+    /// this structure is dropped.
+    pub fn basename(&self) -> &Path {
+        &self.basename
+    }
+
+    /// Returns an iterator over the predecessors of `node_id`, that is, over
+    /// the nodes `u` such that `node_id` is a successor of `u`, in increasing
+    /// order.
+    pub fn predecessors(&self, node_id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.transpose.successors(node_id)
+    }
+}
@@ -6,3 +6,6 @@
 
 mod er;
 pub use er::ErdosRenyi;
+
+mod sparse;
+pub use sparse::{Model, RandomGraph};
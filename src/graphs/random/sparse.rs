@@ -0,0 +1,241 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use lender::{Lend, Lender, Lending};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use crate::prelude::{NodeLabelsLender, SequentialGraph, SequentialLabeling, SplitLabeling};
+
+/// The distribution of the successors of a [`RandomGraph`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Model {
+    /// Each arc is present independently with the same probability, as in
+    /// the Erdös-Rényi model, so outdegrees are binomial.
+    ErdosRenyi,
+    /// Outdegrees follow a (truncated) discrete power law with the given
+    /// exponent, which must be greater than two, and successors are chosen
+    /// uniformly at random.
+    PowerLaw { exponent: f64 },
+}
+
+/// Provides a sequential implementation of sparse random graphs with a
+/// given average degree.
+///
+/// Differently from [`ErdosRenyi`](super::ErdosRenyi), the time required to
+/// iterate over the graph is linear in the number of arcs, and
+/// [`iter_from`](SequentialLabeling::iter_from) takes constant time, as the
+/// successors of each node are generated by a
+/// [pseudorandom number generator](SmallRng) seeded with the seed of the
+/// graph and the node. Loops are never included, and successors are returned
+/// in increasing order.
+///
+/// The average degree is exact, in expectation, for the
+/// [Erdös-Rényi model](Model::ErdosRenyi), and approximate for the
+/// [power-law model](Model::PowerLaw), as outdegrees are truncated at the
+/// number of nodes minus one.
+#[derive(Debug, Clone)]
+pub struct RandomGraph {
+    num_nodes: usize,
+    avg_degree: f64,
+    model: Model,
+    seed: u64,
+}
+
+impl RandomGraph {
+    /// Creates a new random graph, given the number of nodes, the average
+    /// degree, the model, and a seed.
+    pub fn new(num_nodes: usize, avg_degree: f64, model: Model, seed: u64) -> Self {
+        assert!(avg_degree >= 0.0, "The average degree must be nonnegative");
+        if let Model::PowerLaw { exponent } = model {
+            assert!(exponent > 2.0, "The exponent must be greater than two");
+        }
+        Self {
+            num_nodes,
+            avg_degree,
+            model,
+            seed,
+        }
+    }
+}
+
+impl SequentialLabeling for RandomGraph {
+    type Label = usize;
+    type Lender<'a> = Iter;
+
+    fn num_nodes(&self) -> usize {
+        self.num_nodes
+    }
+
+    fn iter_from(&self, from: usize) -> Iter {
+        Iter {
+            graph: self.clone(),
+            x: from,
+        }
+    }
+}
+
+impl SplitLabeling for RandomGraph {
+    type SplitLender<'a> = lender::Take<Iter>;
+    type IntoIterator<'a> = Vec<lender::Take<Iter>>;
+
+    fn split_iter(&self, how_many: usize) -> Self::IntoIterator<'_> {
+        let nodes_per_iter = self.num_nodes.div_ceil(how_many);
+        (0..how_many)
+            .map(|i| {
+                self.iter_from((i * nodes_per_iter).min(self.num_nodes))
+                    .take(nodes_per_iter)
+            })
+            .collect()
+    }
+}
+
+impl SequentialGraph for RandomGraph {}
+
+#[derive(Debug, Clone)]
+pub struct Iter {
+    graph: RandomGraph,
+    x: usize,
+}
+
+impl Iter {
+    /// Returns the successors of `x`.
+    fn successors(&self, x: usize) -> Vec<usize> {
+        let n = self.graph.num_nodes;
+        if n <= 1 {
+            return vec![];
+        }
+        let mut rng = SmallRng::seed_from_u64(
+            self.graph.seed ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        );
+        // successors are chosen among the other n - 1 nodes, mapping y >= x
+        // to y + 1
+        let skip_loop = |y: usize| if y >= x { y + 1 } else { y };
+
+        match self.graph.model {
+            Model::ErdosRenyi => {
+                let p = (self.graph.avg_degree / (n - 1) as f64).min(1.0);
+                if p == 0.0 {
+                    return vec![];
+                }
+                if p == 1.0 {
+                    return (0..n - 1).map(skip_loop).collect();
+                }
+                // the gaps between successive arcs are geometric; ln_1p is
+                // accurate even when 1 - p rounds to 1
+                let log_q = (-p).ln_1p();
+                let mut result = vec![];
+                let mut y: usize = 0;
+                loop {
+                    let u = 1.0 - rng.gen::<f64>();
+                    y = y.saturating_add((u.ln() / log_q) as usize);
+                    if y >= n - 1 {
+                        return result;
+                    }
+                    result.push(skip_loop(y));
+                    y += 1;
+                }
+            }
+            Model::PowerLaw { exponent } => {
+                // a Pareto distribution whose floor has the given mean
+                let x_min = (self.graph.avg_degree + 0.5) * (exponent - 2.0) / (exponent - 1.0);
+                let u = 1.0 - rng.gen::<f64>();
+                let degree = ((x_min * u.powf(-1.0 / (exponent - 1.0))) as usize).min(n - 1);
+                let mut result = rand::seq::index::sample(&mut rng, n - 1, degree).into_vec();
+                result.iter_mut().for_each(|y| *y = skip_loop(*y));
+                result.sort_unstable();
+                result
+            }
+        }
+    }
+}
+
+impl<'succ> NodeLabelsLender<'succ> for Iter {
+    type Label = usize;
+    type IntoIterator = Vec<usize>;
+}
+
+impl<'succ> Lending<'succ> for Iter {
+    type Lend = (usize, <Self as NodeLabelsLender<'succ>>::IntoIterator);
+}
+
+impl Lender for Iter {
+    #[inline(always)]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.x >= self.graph.num_nodes {
+            return None;
+        }
+        let result = Some((self.x, self.successors(self.x)));
+        self.x += 1;
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.graph.num_nodes.saturating_sub(self.x);
+        (len, Some(len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+    use crate::labels::Left;
+
+    #[test]
+    fn test_random_graph() {
+        for model in [Model::ErdosRenyi, Model::PowerLaw { exponent: 2.5 }] {
+            let num_nodes = 10_000;
+            let g = RandomGraph::new(num_nodes, 10.0, model, 0);
+            let v = Left(VecGraph::from_lender(g.iter()));
+
+            let mut num_arcs = 0;
+            let mut iter = g.iter();
+            while let Some((x, succ)) = iter.next() {
+                assert!(succ.windows(2).all(|w| w[0] < w[1]));
+                assert!(succ.iter().all(|&y| y != x && y < num_nodes));
+                num_arcs += succ.len();
+            }
+            let avg_degree = num_arcs as f64 / num_nodes as f64;
+            assert!(
+                (9.0..11.0).contains(&avg_degree),
+                "{:?}: {}",
+                model,
+                avg_degree
+            );
+
+            // iter_from and split_iter return the same successors
+            for from in [0, 1, 5000, num_nodes - 1, num_nodes] {
+                let mut it0 = g.iter_from(from);
+                let mut it1 = v.iter_from(from);
+                while let Some((x, s)) = it0.next() {
+                    let (y, t) = it1.next().unwrap();
+                    assert_eq!(x, y);
+                    assert_eq!(s, t.into_iter().collect::<Vec<_>>());
+                }
+                assert!(it1.next().is_none());
+            }
+            let mut split = Left(VecGraph::new());
+            for lender in g.split_iter(7) {
+                split.0.add_lender(lender);
+            }
+            assert_eq!(split, v);
+        }
+    }
+
+    #[test]
+    fn test_tiny_probability() {
+        // 1 - p rounds to 1, so a naive computation of ln(1 - p) would make
+        // every node a successor
+        let num_nodes = 100_000;
+        let g = RandomGraph::new(num_nodes, 1E-12, Model::ErdosRenyi, 0);
+        let mut num_arcs = 0;
+        let mut iter = g.iter();
+        while let Some((_, succ)) = iter.next() {
+            num_arcs += succ.len();
+        }
+        assert_eq!(num_arcs, 0);
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use anyhow::{ensure, Result};
+use lender::*;
+
+/// A wrapper restricting a graph to a range of nodes `[start..end)`; see
+/// also [`subgraph`](crate::transform::subgraph).
+///
+/// Nodes are renumbered from `start..end` to `0..end - start`, and arcs whose
+/// destination is outside of the range are dropped. Since renumbering is a
+/// shift, sortedness of the underlying graph is preserved. As for
+/// [`FilterArcsGraph`], we can't provide the number of arcs or the outdegree
+/// of a node without enumerating successors, so we can't implement random
+/// access to the successors.
+#[derive(Debug, Clone)]
+pub struct SubGraph<G> {
+    graph: G,
+    start: usize,
+    end: usize,
+}
+
+impl<G: SequentialGraph> SubGraph<G> {
+    /// Creates a new subgraph containing the nodes in `[start..end)`.
+    ///
+    /// Returns an error if `start` is greater than `end`, or if `end` is
+    /// greater than the number of nodes of the graph.
+    pub fn new(graph: G, start: usize, end: usize) -> Result<Self> {
+        ensure!(
+            start <= end,
+            "The start of the range ({}) is greater than its end ({})",
+            start,
+            end
+        );
+        ensure!(
+            end <= graph.num_nodes(),
+            "The end of the range ({}) is greater than the number of nodes ({})",
+            end,
+            graph.num_nodes()
+        );
+        Ok(Self { graph, start, end })
+    }
+
+    /// Returns the underlying graph.
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+}
+
+impl<G: SequentialGraph> SequentialLabeling for SubGraph<G> {
+    type Label = usize;
+    type Lender<'b> = Iter<G::Lender<'b>>
+        where
+            Self: 'b;
+
+    #[inline(always)]
+    fn num_nodes(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline(always)]
+    fn num_arcs_hint(&self) -> Option<u64> {
+        None
+    }
+
+    #[inline(always)]
+    fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        let from = from.min(self.num_nodes());
+        Iter {
+            iter: self.graph.iter_from(self.start + from),
+            start: self.start,
+            end: self.end,
+            remaining: self.num_nodes() - from,
+        }
+    }
+}
+
+impl<G: SequentialGraph + SplitLabeling> SplitLabeling for SubGraph<G>
+where
+    for<'a> <G as SequentialLabeling>::Lender<'a>: Clone + Send + Sync,
+{
+    type SplitLender<'a> = split::seq::Lender<'a, SubGraph<G>> where Self: 'a;
+    type IntoIterator<'a> = split::seq::IntoIterator<'a, SubGraph<G>> where Self: 'a;
+
+    fn split_iter(&self, how_many: usize) -> Self::IntoIterator<'_> {
+        split::seq::Iter::new(self.iter(), self.num_nodes(), how_many)
+    }
+}
+
+impl<G: SequentialGraph> SequentialGraph for SubGraph<G> {}
+
+impl<'b, G: SequentialGraph> IntoLender for &'b SubGraph<G> {
+    type Lender = <SubGraph<G> as SequentialLabeling>::Lender<'b>;
+
+    #[inline(always)]
+    fn into_lender(self) -> Self::Lender {
+        self.iter()
+    }
+}
+
+/// An iterator over the nodes of a range of a graph that renumbers on the
+/// fly nodes and drops arcs leaving the range.
+#[derive(Debug, Clone)]
+pub struct Iter<I> {
+    iter: I,
+    start: usize,
+    end: usize,
+    remaining: usize,
+}
+
+impl<'succ, I> NodeLabelsLender<'succ> for Iter<I>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+{
+    type Label = usize;
+    type IntoIterator = Succ<LenderIntoIter<'succ, I>>;
+}
+
+impl<'succ, I> Lending<'succ> for Iter<I>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+{
+    type Lend = (usize, <Self as NodeLabelsLender<'succ>>::IntoIterator);
+}
+
+unsafe impl<I> SortedLender for Iter<I> where
+    I: SortedLender + Lender + for<'next> NodeLabelsLender<'next, Label = usize>
+{
+}
+
+impl<I> Lender for Iter<I>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+{
+    #[inline(always)]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (start, end) = (self.start, self.end);
+        self.iter.next().map(|x| {
+            let (node, succ) = x.into_pair();
+            (
+                node - start,
+                Succ {
+                    iter: succ.into_iter(),
+                    start,
+                    end,
+                },
+            )
+        })
+    }
+}
+
+impl<I> ExactSizeLender for Iter<I>
+where
+    I: Lender + for<'next> NodeLabelsLender<'next, Label = usize>,
+{
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+#[derive(Debug)]
+pub struct Succ<I: Iterator<Item = usize>> {
+    iter: I,
+    start: usize,
+    end: usize,
+}
+
+impl<I: Iterator<Item = usize>> Iterator for Succ<I> {
+    type Item = usize;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let dst = self.iter.next()?;
+            if (self.start..self.end).contains(&dst) {
+                return Some(dst - self.start);
+            }
+        }
+    }
+}
+
+unsafe impl<I> SortedIterator for Succ<I> where I: Iterator<Item = usize> + SortedIterator {}
+
+#[cfg(test)]
+#[test]
+fn test_subgraph() -> anyhow::Result<()> {
+    use crate::{graphs::vec_graph::VecGraph, prelude::proj::Left};
+    let graph = || {
+        Left(VecGraph::from_arc_list([
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (2, 0),
+            (2, 1),
+            (2, 3),
+            (3, 3),
+            (3, 4),
+            (4, 2),
+        ]))
+    };
+    let s = SubGraph::new(graph(), 1, 4)?;
+    assert_eq!(s.num_nodes(), 3);
+    assert_eq!(s.num_arcs_hint(), None);
+
+    let mut iter = s.iter();
+    let mut arcs = vec![];
+    while let Some((node, succ)) = iter.next() {
+        for dst in succ {
+            arcs.push((node, dst));
+        }
+    }
+    assert_eq!(arcs, vec![(0, 1), (1, 0), (1, 2), (2, 2)]);
+
+    let mut iter = s.iter_from(2);
+    assert_eq!(iter.next().unwrap().1.collect::<Vec<_>>(), vec![2]);
+    assert!(iter.next().is_none());
+
+    // every arc of the subgraph is an arc of the graph
+    let g = s.into_inner();
+    for (src, dst) in arcs {
+        assert!(g.successors(src + 1).into_iter().any(|s| s == dst + 1));
+    }
+
+    assert_eq!(SubGraph::new(graph(), 2, 2)?.iter().count(), 0);
+    assert!(SubGraph::new(graph(), 3, 2).is_err());
+    assert!(SubGraph::new(graph(), 0, 6).is_err());
+
+    Ok(())
+}
@@ -9,8 +9,31 @@ use lender::*;
 
 #[derive(Debug, Clone)]
 /// A wrapper exhibiting the union of two graphs.
+///
+/// The successors of each node are the sorted union of the successors of the
+/// node in the two graphs, computed by a streaming merge of the two sorted
+/// successor lists that removes duplicates.
+///
+/// If the two graphs have a different number of nodes, the union has the
+/// largest number of nodes, and missing nodes are considered as having no
+/// successors. Use [`UnionGraph::new`] to check that the number of nodes is
+/// the same.
 pub struct UnionGraph<G: SequentialGraph, H: SequentialGraph>(pub G, pub H);
 
+impl<G: SequentialGraph, H: SequentialGraph> UnionGraph<G, H> {
+    /// Creates the union of two graphs, returning an error if they do not
+    /// have the same number of nodes.
+    pub fn new(g: G, h: H) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            g.num_nodes() == h.num_nodes(),
+            "The graphs have a different number of nodes: {} != {}",
+            g.num_nodes(),
+            h.num_nodes()
+        );
+        Ok(Self(g, h))
+    }
+}
+
 impl<G: SequentialGraph, H: SequentialGraph> SequentialLabeling for UnionGraph<G, H>
 where
     for<'a> G::Lender<'a>: SortedLender,
@@ -244,4 +267,34 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_union_graph_new() -> anyhow::Result<()> {
+        use crate::{graphs::vec_graph::VecGraph, prelude::proj::Left};
+        let arcs0 = [(0, 1), (0, 4), (1, 1), (2, 0), (3, 2), (3, 4), (4, 2)];
+        let arcs1 = [(0, 2), (0, 4), (1, 0), (3, 1), (3, 2), (3, 3), (4, 3)];
+        let mut adj = vec![std::collections::BTreeSet::new(); 5];
+        for (src, dst) in arcs0.iter().chain(arcs1.iter()) {
+            adj[*src].insert(*dst);
+        }
+
+        let union = UnionGraph::new(
+            Left(VecGraph::from_arc_list(arcs0)),
+            Left(VecGraph::from_arc_list(arcs1)),
+        )?;
+        let mut iter = union.iter();
+        for (node, succ) in adj.into_iter().enumerate() {
+            let Some((x, s)) = iter.next() else { panic!() };
+            assert_eq!(x, node);
+            assert_eq!(s.collect::<Vec<_>>(), succ.into_iter().collect::<Vec<_>>());
+        }
+        assert!(iter.next().is_none());
+
+        assert!(UnionGraph::new(
+            Left(VecGraph::from_arc_list(arcs0)),
+            Left(VecGraph::from_arc_list([(5, 0)])),
+        )
+        .is_err());
+        Ok(())
+    }
 }
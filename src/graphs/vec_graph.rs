@@ -86,29 +86,28 @@ impl<L: Clone + 'static> VecGraph<L> {
     }
 
     /// Add an arc to the graph and return whether it is a new one.
+    ///
+    /// Arcs are identified by their endpoints only: if the arc is already
+    /// present, the graph is left unchanged, and in particular the label of
+    /// the arc is not replaced by `l`.
+    ///
+    /// If `u` or `v` are not nodes of the graph, the graph is extended with
+    /// isolated nodes up to the largest of the two, as in
+    /// [`add_node`](Self::add_node). Successors are kept sorted.
     pub fn add_labeled_arc(&mut self, u: usize, v: usize, l: L) -> bool {
-        let max = u.max(v);
-        if max >= self.succ.len() {
-            panic!(
-                "Node {} does not exist (the graph has {} nodes)",
-                max,
-                self.succ.len(),
-            );
-        }
+        self.add_node(u.max(v));
         let result = self.succ[u].insert(Successor(v, l));
         self.number_of_arcs += result as u64;
         result
     }
 
     /// Remove an arc from the graph and return whether it was present or not.
+    ///
+    /// If `u` or `v` are not nodes of the graph the arc cannot be present,
+    /// so the graph is left unchanged and false is returned.
     pub fn remove_arc(&mut self, u: usize, v: usize) -> bool {
-        let max = u.max(v);
-        if max >= self.succ.len() {
-            panic!(
-                "Node {} does not exist (the graph has {} nodes)",
-                max,
-                self.succ.len(),
-            );
+        if u.max(v) >= self.succ.len() {
+            return false;
         }
         // SAFETY: the label is not used by Eq/Ord.
         let result = self.succ[u].remove(&Successor(v, unsafe {
@@ -134,6 +133,10 @@ impl<L: Clone + 'static> VecGraph<L> {
     }
 
     /// Creates a new graph from an [`IntoLender`] yielding a [`NodeLabelsLender`].
+    ///
+    /// Successors are stored with their labels and sorted by destination. If
+    /// a destination appears more than once in a list of successors, only
+    /// the first occurrence and its label are kept.
     pub fn from_labeled_lender<I: IntoLender>(iter_nodes: I) -> Self
     where
         I::Lender: for<'next> NodeLabelsLender<'next, Label = (usize, L)>,
@@ -166,10 +169,31 @@ impl<L: Clone + 'static> VecGraph<L> {
         g.add_labeled_arcs(arcs);
         g
     }
+
+    /// Returns the transpose of this graph, in which each arc `(u, v)` with
+    /// label `l` becomes an arc `(v, u)` with label `l`.
+    ///
+    /// The transpose is built in memory, and has the same number of nodes as
+    /// this graph. For large graphs, use
+    /// [`transpose_labeled`](crate::transform::transpose_labeled), which sorts
+    /// arcs out of core.
+    pub fn transpose_labeled(&self) -> Self {
+        let mut t = Self::empty(self.succ.len());
+        for (u, succ) in self.succ.iter().enumerate() {
+            for Successor(v, l) in succ {
+                t.succ[*v].insert(Successor(u, l.clone()));
+            }
+        }
+        t.number_of_arcs = self.number_of_arcs;
+        t
+    }
 }
 
 impl VecGraph<()> {
     /// Add an arc to the graph and return whether it is a new one.
+    ///
+    /// If `u` or `v` are not nodes of the graph, the graph is extended with
+    /// isolated nodes up to the largest of the two.
     pub fn add_arc(&mut self, u: usize, v: usize) -> bool {
         self.add_labeled_arc(u, v, ())
     }
@@ -222,6 +246,17 @@ impl VecGraph<()> {
         g.add_arc_list(arcs);
         g
     }
+
+    /// Returns the transpose of this graph, in which each arc `(u, v)`
+    /// becomes an arc `(v, u)`.
+    ///
+    /// The transpose is built in memory, and has the same number of nodes as
+    /// this graph. For large graphs, use
+    /// [`transpose`](crate::transform::transpose), which sorts arcs out of
+    /// core.
+    pub fn transpose(&self) -> Self {
+        self.transpose_labeled()
+    }
 }
 
 impl<'a, L: Clone + 'static> IntoLender for &'a VecGraph<L> {
@@ -304,4 +339,74 @@ fn test_remove() {
     let mut g = VecGraph::<_>::from_labeled_arc_list([(0, 1, 1), (0, 2, 2), (1, 2, 3)]);
     assert!(g.remove_arc(0, 2));
     assert!(!g.remove_arc(0, 2));
+    assert!(!g.remove_arc(1, 0));
+    assert!(!g.remove_arc(5, 0));
+    assert_eq!(g.num_nodes(), 3);
+    assert_eq!(g.num_arcs(), 2);
+}
+
+#[test]
+fn test_add_arc() {
+    let mut g = VecGraph::new();
+    assert!(g.add_arc(0, 3));
+    assert!(g.add_arc(0, 1));
+    assert!(!g.add_arc(0, 3));
+    assert_eq!(g.num_nodes(), 4);
+    assert_eq!(g.num_arcs(), 2);
+
+    // Nodes are added as needed, both as sources and as targets
+    assert!(g.add_arc(6, 2));
+    assert_eq!(g.num_nodes(), 7);
+    assert_eq!(g.outdegree(5), 0);
+    assert!(g.add_arc(2, 8));
+    assert_eq!(g.num_nodes(), 9);
+
+    assert!(g.remove_arc(0, 3));
+    assert!(g.add_arc(0, 2));
+    let succ = Left(&g).successors(0).into_iter().collect::<Vec<_>>();
+    assert_eq!(succ, vec![1, 2]);
+    assert_eq!(g.num_arcs(), 4);
+}
+
+#[test]
+fn test_duplicate_labeled_arc() {
+    let mut g = VecGraph::<_>::from_labeled_arc_list([(0, 2, 1), (0, 1, 2)]);
+    assert!(!g.add_labeled_arc(0, 2, 3));
+    assert_eq!(g.num_arcs(), 2);
+    assert_eq!(
+        RandomAccessLabeling::labels(&g, 0).collect::<Vec<_>>(),
+        vec![(1, 2), (2, 1)]
+    );
+}
+
+#[test]
+fn test_transpose() {
+    let arcs = vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 4), (3, 4)];
+    let mut g = VecGraph::from_arc_list(arcs);
+    // An isolated node at the end must be preserved
+    g.add_node(5);
+
+    let t = g.transpose();
+    assert_eq!(t.num_nodes(), 6);
+    assert_eq!(t.num_arcs(), 6);
+    assert_eq!(
+        Left(&t).successors(4).into_iter().collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+    assert_eq!(
+        t,
+        VecGraph::from_lender(&crate::transform::transpose(Left(&g), 3).unwrap())
+    );
+    assert_eq!(t.transpose(), g);
+}
+
+#[test]
+fn test_transpose_labeled() {
+    let g = VecGraph::<_>::from_labeled_arc_list([(0, 1, 'a'), (0, 2, 'b'), (2, 1, 'c')]);
+    let t = g.transpose_labeled();
+    assert_eq!(
+        RandomAccessLabeling::labels(&t, 1).collect::<Vec<_>>(),
+        vec![(0, 'a'), (2, 'c')]
+    );
+    assert_eq!(t.transpose_labeled(), g);
 }
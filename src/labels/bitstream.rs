@@ -0,0 +1,194 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Labelings stored in a bitstream and decoded by a [`BitDeserializer`].
+
+*/
+
+use crate::prelude::{
+    BitDeserializer, IteratorImpl, RandomAccessGraph, RandomAccessLabeling, SequentialLabeling,
+};
+use core::marker::PhantomData;
+use dsi_bitstream::prelude::*;
+use sux::traits::IndexedSeq;
+
+/// The type of the bit reader passed to the [`BitDeserializer`] of a
+/// [`BitStreamLabeling`].
+pub type LabelReader<'a, E> = BufBitReader<E, MemWordReader<u32, &'a [u32]>>;
+
+/// A random-access labeling of a graph whose labels are stored in a
+/// bitstream.
+///
+/// The labels of each node are stored consecutively, in the same order of
+/// its successors in `graph`, and the bit offset of the labels of node `x` is
+/// the `x`-th element of `offsets`, which must contain one more element than
+/// the number of nodes (the last one being the length of the bitstream).
+/// Each label is decoded by the [`BitDeserializer`] provided at construction
+/// time. Since the labels of a node end at the offset of the next node, each
+/// label must be represented by at least one bit.
+///
+/// The graph provides the outdegrees, so that they can be computed without
+/// decoding the labels, and the successors returned, together with their
+/// labels, by [`labeled_successors`](BitStreamLabeling::labeled_successors).
+/// The labeling can also be [zipped](crate::labels::Zip) with another graph
+/// with the same successor lists.
+pub struct BitStreamLabeling<G, E: Endianness, B, O, D> {
+    graph: G,
+    data: B,
+    offsets: O,
+    deserializer: D,
+    _marker: PhantomData<E>,
+}
+
+impl<
+        G: RandomAccessGraph,
+        E: Endianness,
+        B: AsRef<[u32]>,
+        O: IndexedSeq<Input = usize, Output = usize>,
+        D,
+    > BitStreamLabeling<G, E, B, O, D>
+{
+    /// Creates a new labeling of `graph` from the bitstream `data`, the bit
+    /// offsets of the labels of each node, and a deserializer for the labels.
+    pub fn new(graph: G, data: B, offsets: O, deserializer: D) -> Self {
+        assert_eq!(
+            offsets.len(),
+            graph.num_nodes() + 1,
+            "The offsets must contain one more element than the number of nodes"
+        );
+        Self {
+            graph,
+            data,
+            offsets,
+            deserializer,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+}
+
+/// The iterator over the labels of a node returned by [`BitStreamLabeling`].
+pub struct Labels<'a, E: Endianness, D> {
+    reader: LabelReader<'a, E>,
+    deserializer: &'a D,
+    end_pos: u64,
+}
+
+impl<'a, E: Endianness, D, L> Iterator for Labels<'a, E, D>
+where
+    LabelReader<'a, E>: BitRead<E> + BitSeek,
+    D: BitDeserializer<E, LabelReader<'a, E>, DeserType = L>,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.bit_pos().unwrap() >= self.end_pos {
+            return None;
+        }
+        // NOTE: here we cannot propagate the error
+        Some(self.deserializer.deserialize(&mut self.reader).unwrap())
+    }
+}
+
+impl<
+        G: RandomAccessGraph + 'static,
+        E: Endianness,
+        B: AsRef<[u32]> + 'static,
+        O: IndexedSeq<Input = usize, Output = usize> + 'static,
+        D: 'static,
+        L,
+    > SequentialLabeling for BitStreamLabeling<G, E, B, O, D>
+where
+    for<'a> LabelReader<'a, E>: BitRead<E> + BitSeek,
+    for<'a> D: BitDeserializer<E, LabelReader<'a, E>, DeserType = L>,
+{
+    type Label = L;
+    type Lender<'node> = IteratorImpl<'node, Self>
+        where
+            Self: 'node;
+
+    fn num_nodes(&self) -> usize {
+        self.graph.num_nodes()
+    }
+
+    fn num_arcs_hint(&self) -> Option<u64> {
+        Some(self.graph.num_arcs())
+    }
+
+    fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        IteratorImpl {
+            labeling: self,
+            nodes: (from..self.num_nodes()),
+        }
+    }
+}
+
+impl<
+        G: RandomAccessGraph + 'static,
+        E: Endianness,
+        B: AsRef<[u32]> + 'static,
+        O: IndexedSeq<Input = usize, Output = usize> + 'static,
+        D: 'static,
+        L,
+    > RandomAccessLabeling for BitStreamLabeling<G, E, B, O, D>
+where
+    for<'a> LabelReader<'a, E>: BitRead<E> + BitSeek,
+    for<'a> D: BitDeserializer<E, LabelReader<'a, E>, DeserType = L>,
+{
+    type Labels<'succ> = Labels<'succ, E, D> where Self: 'succ;
+
+    fn num_arcs(&self) -> u64 {
+        self.graph.num_arcs()
+    }
+
+    fn labels(&self, node_id: usize) -> <Self as RandomAccessLabeling>::Labels<'_> {
+        let mut reader = LabelReader::<E>::new(MemWordReader::new(self.data.as_ref()));
+        reader
+            .set_bit_pos(self.offsets.get(node_id) as u64)
+            .unwrap();
+        Labels {
+            reader,
+            deserializer: &self.deserializer,
+            end_pos: self.offsets.get(node_id + 1) as u64,
+        }
+    }
+
+    fn outdegree(&self, node_id: usize) -> usize {
+        self.graph.outdegree(node_id)
+    }
+}
+
+impl<
+        G: RandomAccessGraph + 'static,
+        E: Endianness,
+        B: AsRef<[u32]> + 'static,
+        O: IndexedSeq<Input = usize, Output = usize> + 'static,
+        D: 'static,
+        L,
+    > BitStreamLabeling<G, E, B, O, D>
+where
+    for<'a> LabelReader<'a, E>: BitRead<E> + BitSeek,
+    for<'a> D: BitDeserializer<E, LabelReader<'a, E>, DeserType = L>,
+{
+    /// Returns the successors of a node together with their labels, as
+    /// pairs `(dst, label)`.
+    ///
+    /// The labels are decoded alongside the successors, so this method is
+    /// equivalent to zipping [`RandomAccessGraph::successors`] and
+    /// [`labels`](RandomAccessLabeling::labels).
+    pub fn labeled_successors(&self, node_id: usize) -> impl Iterator<Item = (usize, L)> + '_ {
+        self.graph
+            .successors(node_id)
+            .into_iter()
+            .zip(self.labels(node_id))
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Lazy transformation of the labels of a labeled graph.
+
+[`MapLabels`] wraps a [labeled sequential graph](LabeledSequentialGraph) and
+applies a function to the label of each arc as successors are enumerated,
+leaving the successors themselves untouched. For example,
+`MapLabels::new(g, |w: u32| w as f64 / 2.0)` is a labeled graph with the same
+arcs as `g` and floating-point labels, obtained without materializing a new
+graph.
+
+Since successors are not changed, the number of nodes and arcs and the
+sortedness of the underlying graph are preserved.
+
+*/
+
+use crate::prelude::{
+    LabeledSequentialGraph, LenderIntoIterator, NodeLabelsLender, Pair, SequentialLabeling,
+    SortedIterator, SortedLender,
+};
+use lender::{IntoLender, Lend, Lender, Lending};
+
+/// A labeled graph whose labels are those of the underlying graph mapped
+/// through a function.
+#[derive(Clone, Debug)]
+pub struct MapLabels<S, F> {
+    graph: S,
+    f: F,
+}
+
+impl<S, F> MapLabels<S, F> {
+    /// Creates a labeled graph with the same arcs as `graph` in which
+    /// each label `l` is replaced by `f(l)`.
+    pub fn new(graph: S, f: F) -> Self {
+        Self { graph, f }
+    }
+
+    /// Returns the underlying graph.
+    pub fn into_inner(self) -> S {
+        self.graph
+    }
+}
+
+/// The lender returned by [`MapLabels`].
+#[derive(Clone, Debug)]
+pub struct MapLabelsIter<'a, L, F> {
+    iter: L,
+    f: &'a F,
+}
+
+impl<'a, 'succ, T, U, L, F> NodeLabelsLender<'succ> for MapLabelsIter<'a, L, F>
+where
+    L: Lender + for<'next> NodeLabelsLender<'next, Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    type Label = (usize, U);
+    type IntoIterator = MapLabelsIntoIterator<'a, <L as NodeLabelsLender<'succ>>::IntoIterator, F>;
+}
+
+impl<'a, 'succ, T, U, L, F> Lending<'succ> for MapLabelsIter<'a, L, F>
+where
+    L: Lender + for<'next> NodeLabelsLender<'next, Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    type Lend = (usize, LenderIntoIterator<'succ, Self>);
+}
+
+impl<'a, T, U, L, F> Lender for MapLabelsIter<'a, L, F>
+where
+    L: Lender + for<'next> NodeLabelsLender<'next, Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    #[inline(always)]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let f = self.f;
+        self.iter.next().map(|x| {
+            let (node, succ) = x.into_pair();
+            (node, MapLabelsIntoIterator { iter: succ, f })
+        })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+unsafe impl<'a, T, U, L, F> SortedLender for MapLabelsIter<'a, L, F>
+where
+    L: SortedLender + for<'next> NodeLabelsLender<'next, Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+}
+
+/// The successors of a node returned by [`MapLabelsIter`].
+#[derive(Clone, Debug)]
+pub struct MapLabelsIntoIterator<'a, I, F> {
+    iter: I,
+    f: &'a F,
+}
+
+impl<'a, T, U, I, F> IntoIterator for MapLabelsIntoIterator<'a, I, F>
+where
+    I: IntoIterator<Item = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    type Item = (usize, U);
+    type IntoIter = MapLabelsIntoIter<'a, I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapLabelsIntoIter {
+            iter: self.iter.into_iter(),
+            f: self.f,
+        }
+    }
+}
+
+/// The iterator on the successors of a node returned by [`MapLabelsIter`].
+#[derive(Clone, Debug)]
+pub struct MapLabelsIntoIter<'a, I, F> {
+    iter: I,
+    f: &'a F,
+}
+
+impl<'a, T, U, I, F> Iterator for MapLabelsIntoIter<'a, I, F>
+where
+    I: Iterator<Item = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    type Item = (usize, U);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(succ, label)| (succ, (self.f)(label)))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+unsafe impl<'a, T, U, I, F> SortedIterator for MapLabelsIntoIter<'a, I, F>
+where
+    I: SortedIterator + Iterator<Item = (usize, T)>,
+    F: Fn(T) -> U,
+{
+}
+
+impl<'a, T, U, S, F> IntoLender for &'a MapLabels<S, F>
+where
+    S: SequentialLabeling<Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    type Lender = <MapLabels<S, F> as SequentialLabeling>::Lender<'a>;
+
+    #[inline(always)]
+    fn into_lender(self) -> Self::Lender {
+        self.iter()
+    }
+}
+
+impl<T, U, S, F> SequentialLabeling for MapLabels<S, F>
+where
+    S: SequentialLabeling<Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+    type Label = (usize, U);
+
+    type Lender<'node> = MapLabelsIter<'node, S::Lender<'node>, F>
+    where
+        Self: 'node;
+
+    fn num_nodes(&self) -> usize {
+        self.graph.num_nodes()
+    }
+
+    fn num_arcs_hint(&self) -> Option<u64> {
+        self.graph.num_arcs_hint()
+    }
+
+    fn iter_from(&self, from: usize) -> Self::Lender<'_> {
+        MapLabelsIter {
+            iter: self.graph.iter_from(from),
+            f: &self.f,
+        }
+    }
+}
+
+impl<T, U, S, F> LabeledSequentialGraph<U> for MapLabels<S, F>
+where
+    S: SequentialLabeling<Label = (usize, T)>,
+    F: Fn(T) -> U,
+{
+}
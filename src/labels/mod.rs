@@ -7,6 +7,9 @@
 
 //! Utility structures for labelings.
 
+pub mod bitstream;
+pub use bitstream::BitStreamLabeling;
+
 pub mod swh_labels;
 pub use swh_labels::SeqLabels;
 
@@ -15,3 +18,6 @@ pub use zip::*;
 
 pub mod proj;
 pub use proj::*;
+
+pub mod map;
+pub use map::MapLabels;
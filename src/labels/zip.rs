@@ -106,6 +106,69 @@ where
     }
 }
 
+/// Returns a lender advancing in lockstep the lenders of two labelings on
+/// the same nodes, such as a graph and its transpose.
+///
+/// Differently from [`Zip`], which pairs the labels of each node and thus
+/// requires the two labelings to have the same outdegrees, the lender returns
+/// triples made of a node and of its two lists of labels, which are
+/// independent. See [`ZipNodes`] for the checks performed during the
+/// iteration.
+///
+/// # Panics
+///
+/// Panics if the two labelings have a different number of nodes.
+pub fn zip_nodes<'a, L: SequentialLabeling, R: SequentialLabeling>(
+    left: &'a L,
+    right: &'a R,
+) -> ZipNodes<L::Lender<'a>, R::Lender<'a>> {
+    assert_eq!(
+        left.num_nodes(),
+        right.num_nodes(),
+        "The two labelings have a different number of nodes"
+    );
+    ZipNodes(left.iter(), right.iter())
+}
+
+/// The lender returned by [`zip_nodes`].
+///
+/// The lender panics if the two underlying lenders return different
+/// nodes, or if one of them ends before the other.
+#[derive(Clone, Debug)]
+pub struct ZipNodes<L, R>(L, R);
+
+impl<'succ, L, R> Lending<'succ> for ZipNodes<L, R>
+where
+    L: Lender + for<'next> NodeLabelsLender<'next>,
+    R: Lender + for<'next> NodeLabelsLender<'next>,
+{
+    type Lend = (
+        usize,
+        LenderIntoIterator<'succ, L>,
+        LenderIntoIterator<'succ, R>,
+    );
+}
+
+impl<L, R> Lender for ZipNodes<L, R>
+where
+    L: Lender + for<'next> NodeLabelsLender<'next>,
+    R: Lender + for<'next> NodeLabelsLender<'next>,
+{
+    #[inline(always)]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        match (self.0.next(), self.1.next()) {
+            (None, None) => None,
+            (Some(left), Some(right)) => {
+                let (left_node, left_labels) = left.into_pair();
+                let (right_node, right_labels) = right.into_pair();
+                assert_eq!(left_node, right_node, "The lenders are not aligned");
+                Some((left_node, left_labels, right_labels))
+            }
+            _ => panic!("The lenders have a different length"),
+        }
+    }
+}
+
 impl<'a, L: SequentialLabeling, R: SequentialLabeling> IntoLender for &'a Zip<L, R> {
     type Lender = <Zip<L, R> as SequentialLabeling>::Lender<'a>;
 
@@ -36,9 +36,10 @@ Usually there is a convenience method doing the wrapping for you.
 
 */
 
-use crate::prelude::{Pair, RandomAccessLabeling, SequentialLabeling};
+use crate::prelude::{Pair, RandomAccessLabeling, SequentialLabeling, SortedIterator};
 use impl_tools::autoimpl;
 use lender::*;
+use sux::bits::BitVec;
 
 use super::lenders::{LenderIntoIter, NodeLabelsLender};
 
@@ -55,6 +56,23 @@ struct this_method_cannot_be_called_use_successors_instead;
 #[autoimpl(for<S: trait + ?Sized> &S, &mut S)]
 pub trait SequentialGraph: SequentialLabeling<Label = usize> {}
 
+/// Returns the arcs of a graph as a vector of pairs `(src, dst)` sorted
+/// lexicographically.
+///
+/// This is useful for testing and for small graphs, as the whole graph is
+/// materialized in memory. Sorting is linear if the graph is
+/// [sorted](super::labels::SortedLender).
+pub fn collect_arcs(graph: &impl SequentialGraph) -> Vec<(usize, usize)> {
+    let mut arcs = vec![];
+    for_!( (src, succ) in graph.iter() {
+        for dst in succ {
+            arcs.push((src, dst));
+        }
+    });
+    arcs.sort();
+    arcs
+}
+
 /// Convenience type alias for the iterator over the successors of a node
 /// returned by the [`iter_from`](SequentialLabeling::iter_from) method.
 pub type Successors<'succ, 'node, S> =
@@ -105,8 +123,193 @@ pub trait RandomAccessGraph: RandomAccessLabeling<Label = usize> + SequentialGra
         }
         false
     }
+
+    /// Stores the successors of a node in `buf`, which is cleared first, and
+    /// returns their number.
+    ///
+    /// The default implementation extends `buf` with the iterator returned by
+    /// [`successors`](RandomAccessGraph::successors).
+    #[inline(always)]
+    fn successors_into(&self, node_id: usize, buf: &mut Vec<usize>) -> usize {
+        buf.clear();
+        buf.extend(self.successors(node_id));
+        buf.len()
+    }
+
+    /// Sets in `bits` the bits corresponding to the successors of a node, and
+    /// returns the number of successors.
+    ///
+    /// No bit is cleared, so `bits` must contain no set bits outside of the
+    /// successors of the node, for example because it has just been created
+    /// or because the bits set by the previous call have been unset by the
+    /// caller. In this way the same bit vector can be reused across calls in
+    /// time proportional to the outdegree, rather than to the number of
+    /// nodes. Membership tests on the successors of a node with a very large
+    /// outdegree can then be performed in constant time, without allocating
+    /// or scanning a vector.
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is shorter than the number of nodes.
+    #[inline(always)]
+    fn successors_into_bitset(&self, node_id: usize, bits: &mut BitVec) -> usize {
+        assert!(
+            bits.len() >= self.num_nodes(),
+            "The bit vector has length {} but the graph has {} nodes",
+            bits.len(),
+            self.num_nodes()
+        );
+        let mut count = 0;
+        for succ in self.successors(node_id) {
+            bits.set(succ, true);
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns a bit vector of length equal to the number of nodes whose
+    /// bits are set in correspondence of the successors of a node.
+    ///
+    /// See [`successors_into_bitset`](RandomAccessGraph::successors_into_bitset)
+    /// to reuse the same bit vector across calls.
+    #[inline(always)]
+    fn successors_bitset(&self, node_id: usize) -> BitVec {
+        let mut bits = BitVec::new(self.num_nodes());
+        self.successors_into_bitset(node_id, &mut bits);
+        bits
+    }
+
+    /// Returns the successors of a node satisfying a predicate.
+    ///
+    /// Successors are returned in the same order as by
+    /// [`successors`](RandomAccessGraph::successors); in particular, if the
+    /// latter is [sorted](SortedIterator), so is the returned iterator.
+    #[inline(always)]
+    fn successors_filtered<P: FnMut(&usize) -> bool>(
+        &self,
+        node_id: usize,
+        pred: P,
+    ) -> core::iter::Filter<
+        <<Self as RandomAccessLabeling>::Labels<'_> as IntoIterator>::IntoIter,
+        P,
+    > {
+        self.successors(node_id).into_iter().filter(pred)
+    }
+
+    /// Returns the successors of a node that belong to a sorted set.
+    ///
+    /// The set must be sorted in ascending order. It is scanned together with
+    /// the (sorted) successor list, skipping ahead by binary search, so each
+    /// element of the set is passed over just once.
+    #[inline(always)]
+    fn successors_in<'a>(
+        &'a self,
+        node_id: usize,
+        set: &'a [usize],
+    ) -> SuccessorsIn<'a, <<Self as RandomAccessLabeling>::Labels<'a> as IntoIterator>::IntoIter>
+    where
+        <<Self as RandomAccessLabeling>::Labels<'a> as IntoIterator>::IntoIter: SortedIterator,
+    {
+        debug_assert!(set.windows(2).all(|w| w[0] <= w[1]), "The set is not sorted");
+        SuccessorsIn {
+            iter: self.successors(node_id).into_iter(),
+            set,
+        }
+    }
+
+    /// Returns the successors of a node in gap form: the first successor is
+    /// returned as is, and each subsequent successor is returned as its
+    /// difference with the previous one.
+    ///
+    /// Since the successors are sorted, the gaps are nonnegative, and they
+    /// are zero only in the case of duplicate successors. This form is
+    /// cheaper to re-encode with instantaneous codes.
+    #[inline(always)]
+    fn successor_gaps<'a>(
+        &'a self,
+        node_id: usize,
+    ) -> SuccessorGaps<<<Self as RandomAccessLabeling>::Labels<'a> as IntoIterator>::IntoIter>
+    where
+        <<Self as RandomAccessLabeling>::Labels<'a> as IntoIterator>::IntoIter: SortedIterator,
+    {
+        SuccessorGaps::new(self.successors(node_id).into_iter())
+    }
+}
+
+/// An iterator returning the gaps between consecutive elements of a sorted
+/// iterator on successors, as returned by
+/// [`RandomAccessGraph::successor_gaps`].
+///
+/// The first element is returned as is.
+#[derive(Debug, Clone)]
+pub struct SuccessorGaps<I> {
+    iter: I,
+    prev: usize,
+}
+
+impl<I: Iterator<Item = usize>> SuccessorGaps<I> {
+    /// Creates a new iterator on the gaps of `iter`, whose elements must be
+    /// sorted.
+    pub fn new(iter: I) -> Self {
+        Self { iter, prev: 0 }
+    }
+}
+
+impl<I: Iterator<Item = usize>> Iterator for SuccessorGaps<I> {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<usize> {
+        let succ = self.iter.next()?;
+        debug_assert!(succ >= self.prev, "Successors are not sorted");
+        let gap = succ - self.prev;
+        self.prev = succ;
+        Some(gap)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<I: ExactSizeIterator<Item = usize>> ExactSizeIterator for SuccessorGaps<I> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// The iterator returned by [`RandomAccessGraph::successors_in`].
+#[derive(Debug, Clone)]
+pub struct SuccessorsIn<'a, I> {
+    iter: I,
+    set: &'a [usize],
+}
+
+impl<'a, I: Iterator<Item = usize>> Iterator for SuccessorsIn<'a, I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let succ = self.iter.next()?;
+            // skip the elements of the set smaller than the successor
+            let skip = self.set.partition_point(|&x| x < succ);
+            self.set = &self.set[skip..];
+            match self.set.first() {
+                None => return None,
+                Some(&x) if x == succ => {
+                    self.set = &self.set[1..];
+                    return Some(succ);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+unsafe impl<'a, I: SortedIterator<Item = usize>> SortedIterator for SuccessorsIn<'a, I> {}
+
 /// A labeled sequential graph.
 ///
 /// A labeled sequential graph is a sequential labeling whose labels are pairs
@@ -114,6 +317,22 @@ pub trait RandomAccessGraph: RandomAccessLabeling<Label = usize> + SequentialGra
 /// label.
 pub trait LabeledSequentialGraph<L>: SequentialLabeling<Label = (usize, L)> {}
 
+/// Returns the arcs of a labeled graph as a vector of triples
+/// `(src, dst, label)` sorted lexicographically by `(src, dst)`.
+///
+/// Arcs with the same source and destination keep the order in which they
+/// are returned by the graph. See [`collect_arcs`].
+pub fn collect_labeled_arcs<L>(graph: &impl LabeledSequentialGraph<L>) -> Vec<(usize, usize, L)> {
+    let mut arcs = vec![];
+    for_!( (src, succ) in graph.iter() {
+        for (dst, label) in succ {
+            arcs.push((src, dst, label));
+        }
+    });
+    arcs.sort_by_key(|&(src, dst, _)| (src, dst));
+    arcs
+}
+
 /// A wrapper associating to each successor the label `()`.
 ///
 /// This wrapper can be used whenever a method requires a labeled graph, but the
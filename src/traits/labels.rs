@@ -72,6 +72,23 @@ pub trait SequentialLabeling {
         None
     }
 
+    /// Returns the number of arcs in the graph.
+    ///
+    /// The default implementation returns [`num_arcs_hint`](SequentialLabeling::num_arcs_hint)
+    /// if available; otherwise, it scans the whole labeling, which takes time
+    /// linear in the number of arcs. Implementations that store the number of
+    /// arcs should return it from [`num_arcs_hint`](SequentialLabeling::num_arcs_hint).
+    fn count_arcs(&self) -> u64 {
+        if let Some(num_arcs) = self.num_arcs_hint() {
+            return num_arcs;
+        }
+        let mut num_arcs = 0;
+        for_!( (_node, labels) in self.iter() {
+            num_arcs += labels.into_iter().count() as u64;
+        });
+        num_arcs
+    }
+
     /// Returns an iterator over the labeling.
     ///
     /// Iterators over the labeling return pairs given by a node of the graph
@@ -87,6 +104,31 @@ pub trait SequentialLabeling {
     /// starting point of the iteration
     fn iter_from(&self, from: usize) -> Self::Lender<'_>;
 
+    /// Returns an iterator over the labeling starting at `from` (included),
+    /// or an error if `from` is greater than the number of nodes.
+    ///
+    /// This method should be preferred over
+    /// [`iter_from`](SequentialLabeling::iter_from) when `from` is not known
+    /// to be valid, as the behavior of the latter is implementation-specific
+    /// for out-of-range nodes: sequential implementations will usually decode
+    /// the whole labeling and return an empty iterator, whereas random-access
+    /// implementations might panic.
+    ///
+    /// Note that purely sequential implementations, such as
+    /// [`BVGraphSeq`](crate::graphs::bvgraph::sequential::BVGraphSeq), must
+    /// scan all nodes before `from`; random-access implementations, such as
+    /// [`BVGraph`](crate::graphs::bvgraph::random_access::BVGraph), position
+    /// themselves directly on `from`.
+    fn try_iter_from(&self, from: usize) -> anyhow::Result<Self::Lender<'_>> {
+        anyhow::ensure!(
+            from <= self.num_nodes(),
+            "Cannot start iterating from node {}, as the graph has {} nodes",
+            from,
+            self.num_nodes()
+        );
+        Ok(self.iter_from(from))
+    }
+
     /// Applies `func` to each chunk of nodes of size `node_granularity` in
     /// parallel, and folds the results using `fold`.
     ///
@@ -339,6 +381,12 @@ impl<I> SortedIter<I> {
 
 unsafe impl<I: Iterator> SortedIterator for SortedIter<I> {}
 
+// Filtering preserves the order of the remaining elements.
+unsafe impl<I: SortedIterator, P: FnMut(&I::Item) -> bool> SortedIterator
+    for core::iter::Filter<I, P>
+{
+}
+
 impl<I: Iterator> Iterator for SortedIter<I> {
     type Item = I::Item;
 
@@ -16,6 +16,7 @@ Without the implementations, one would obtain a normal [`Lender`], which would n
 as an argument, say, of [`BVComp::extend`](crate::graphs::bvgraph::BVComp::extend).
 
 */
+use crate::traits::Pair;
 use lender::*;
 // missing implementations for [Cloned, Copied, Owned] because they don't
 // implement Lender but Iterator.
@@ -102,6 +103,67 @@ pub type LenderIntoIterator<'lend, L> = <L as NodeLabelsLender<'lend>>::IntoIter
 pub type LenderIntoIter<'lend, L> =
     <<L as NodeLabelsLender<'lend>>::IntoIterator as IntoIterator>::IntoIter;
 
+/// Conversion of a [`NodeLabelsLender`] into an [`Iterator`] on owned pairs.
+///
+/// The lending design makes it impossible to use standard [`Iterator`]
+/// combinators, such as [`collect`](Iterator::collect), on the pairs
+/// returned by a [`NodeLabelsLender`]. This trait, which is implemented by all
+/// lenders, makes it possible to turn a [`NodeLabelsLender`] into an
+/// [`OwnedIter`], an [`Iterator`] returning pairs made of a node and of a
+/// vector containing its labels.
+///
+/// This is convenient for scripts and tests, but it allocates a new vector
+/// for each node. For large graphs, use the [`Lender`] directly.
+///
+/// ```rust
+/// use webgraph::prelude::*;
+/// use dsi_bitstream::prelude::BE;
+/// use lender::*;
+///
+/// let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+///     .endianness::<BE>()
+///     .load()?;
+/// let first = graph.iter().take(100).into_owned_iter().collect::<Vec<_>>();
+/// assert_eq!(first.len(), 100);
+/// assert_eq!(first[0].0, 0);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub trait IntoOwnedIter: Lender + Sized {
+    /// Turns this lender into an [`OwnedIter`].
+    fn into_owned_iter(self) -> OwnedIter<Self>
+    where
+        Self: for<'next> NodeLabelsLender<'next>,
+    {
+        OwnedIter(self)
+    }
+}
+
+impl<L: Lender> IntoOwnedIter for L {}
+
+/// The [`Iterator`] returned by [`IntoOwnedIter::into_owned_iter`].
+#[derive(Debug, Clone)]
+pub struct OwnedIter<L>(L);
+
+impl<T, L> Iterator for OwnedIter<L>
+where
+    L: Lender + for<'next> NodeLabelsLender<'next, Label = T>,
+{
+    type Item = (usize, Vec<T>);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|lend| {
+            let (node, labels) = lend.into_pair();
+            (node, labels.into_iter().collect())
+        })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
 impl<'lend, A, B> NodeLabelsLender<'lend> for lender::Chain<A, B>
 where
     A: Lender + for<'next> NodeLabelsLender<'next>,
@@ -42,3 +42,89 @@ impl<E: Endianness, BR: BitRead<E>> BitDeserializer<E, BR> for () {
         Ok(())
     }
 }
+
+/// A [`BitSerializer`] and [`BitDeserializer`] for integer labels (`u32` or
+/// `u64`) using [γ codes](dsi_bitstream::codes::gamma).
+///
+/// γ codes are appropriate for small labels, e.g., multiplicities or small
+/// weights.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GammaSerDe<T>(core::marker::PhantomData<T>);
+
+/// A [`BitSerializer`] and [`BitDeserializer`] for integer labels (`u32` or
+/// `u64`) using [δ codes](dsi_bitstream::codes::delta).
+///
+/// δ codes are appropriate for labels distributed on a larger range than
+/// those for which [`GammaSerDe`] is appropriate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeltaSerDe<T>(core::marker::PhantomData<T>);
+
+macro_rules! impl_code_serde {
+    ($serde:ident, $write:ident, $read:ident, $write_fn:ident, $read_fn:ident, $($ty:ty),*) => {$(
+        impl<E: Endianness, BW: $write<E>> BitSerializer<E, BW> for $serde<$ty> {
+            type SerType = $ty;
+            #[inline(always)]
+            fn serialize(&self, value: &Self::SerType, bitstream: &mut BW) -> Result<usize, BW::Error> {
+                bitstream.$write_fn(u64::from(*value))
+            }
+        }
+
+        impl<E: Endianness, BR: $read<E>> BitDeserializer<E, BR> for $serde<$ty> {
+            type DeserType = $ty;
+            #[inline(always)]
+            #[allow(clippy::unnecessary_cast)]
+            fn deserialize(&self, bitstream: &mut BR) -> Result<Self::DeserType, BR::Error> {
+                bitstream.$read_fn().map(|x| x as $ty)
+            }
+        }
+    )*};
+}
+
+impl_code_serde!(
+    GammaSerDe,
+    GammaWrite,
+    GammaRead,
+    write_gamma,
+    read_gamma,
+    u32,
+    u64
+);
+impl_code_serde!(
+    DeltaSerDe,
+    DeltaWrite,
+    DeltaRead,
+    write_delta,
+    read_delta,
+    u32,
+    u64
+);
+
+/// A [`BitSerializer`] and [`BitDeserializer`] for `u64` labels using `N`
+/// bits per label.
+///
+/// Labels must fit in `N` bits, with `0 < N ≤ 64`; this condition is checked
+/// only in debug mode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedWidth<const N: usize>;
+
+impl<const N: usize, E: Endianness, BW: BitWrite<E>> BitSerializer<E, BW> for FixedWidth<N> {
+    type SerType = u64;
+    #[inline(always)]
+    fn serialize(&self, value: &Self::SerType, bitstream: &mut BW) -> Result<usize, BW::Error> {
+        debug_assert!(
+            N == 64 || *value >> N == 0,
+            "The label {} does not fit in {} bits",
+            value,
+            N
+        );
+        bitstream.write_bits(*value, N)
+    }
+}
+
+impl<const N: usize, E: Endianness, BR: BitRead<E>> BitDeserializer<E, BR> for FixedWidth<N> {
+    type DeserType = u64;
+    #[inline(always)]
+    fn deserialize(&self, bitstream: &mut BR) -> Result<Self::DeserType, BR::Error> {
+        bitstream.read_bits(N)
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::SequentialGraph;
+use dsi_progress_logger::prelude::*;
+use lender::*;
+
+/// Returns the compressed sparse row (CSR) representation of a graph.
+///
+/// The first returned vector contains the `num_nodes + 1` offsets, and the
+/// second one the concatenation of all successor lists: the successors of
+/// node `x` are `dsts[offsets[x]..offsets[x + 1]]`, in the order in which
+/// they are returned by the graph.
+///
+/// The graph is scanned once. Both vectors are fully materialized, so this
+/// requires `(num_nodes + 1 + num_arcs) * size_of::<usize>()` bytes, that is,
+/// eight bytes per node and per arc on 64-bit platforms; the destination
+/// vector is preallocated if the number of arcs is
+/// [known](crate::traits::SequentialLabeling::num_arcs_hint). To reuse
+/// existing buffers, use [`to_csr_into`].
+pub fn to_csr(graph: &impl SequentialGraph) -> (Vec<usize>, Vec<usize>) {
+    let mut offsets = Vec::new();
+    let mut dsts = Vec::new();
+    to_csr_into(graph, &mut offsets, &mut dsts);
+    (offsets, dsts)
+}
+
+/// Stores in the provided buffers the compressed sparse row (CSR)
+/// representation of a graph.
+///
+/// The buffers are cleared first; see [`to_csr`] for the content of the
+/// buffers and the memory requirements.
+pub fn to_csr_into(graph: &impl SequentialGraph, offsets: &mut Vec<usize>, dsts: &mut Vec<usize>) {
+    let num_nodes = graph.num_nodes();
+    offsets.clear();
+    offsets.reserve(num_nodes + 1);
+    dsts.clear();
+    if let Some(num_arcs) = graph.num_arcs_hint() {
+        dsts.reserve(num_arcs as usize);
+    }
+
+    let mut pl = ProgressLogger::default();
+    pl.display_memory(true)
+        .item_name("node")
+        .expected_updates(Some(num_nodes));
+    pl.start("Building CSR representation...");
+
+    offsets.push(0);
+    for_!( (_, succ) in graph.iter() {
+        dsts.extend(succ);
+        offsets.push(dsts.len());
+        pl.light_update();
+    });
+    pl.done();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+    use crate::labels::Left;
+    use crate::traits::{RandomAccessGraph, SequentialLabeling};
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_to_csr() {
+        let graph = Left(VecGraph::from_arc_list([
+            (0, 1),
+            (0, 3),
+            (1, 2),
+            (3, 0),
+            (3, 1),
+            (3, 3),
+            (5, 4),
+        ]));
+        let (offsets, dsts) = to_csr(&graph);
+        assert_eq!(offsets, vec![0, 2, 3, 3, 6, 6, 7]);
+        assert_eq!(dsts, vec![1, 3, 2, 0, 1, 3, 4]);
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let node = rng.gen_range(0..graph.num_nodes());
+            assert!(itertools::equal(
+                dsts[offsets[node]..offsets[node + 1]].iter().copied(),
+                graph.successors(node)
+            ));
+        }
+
+        // Buffers are cleared before being reused
+        let mut offsets = vec![42; 10];
+        let mut dsts = vec![42; 100];
+        to_csr_into(
+            &Left(VecGraph::from_arc_list([(1, 0)])),
+            &mut offsets,
+            &mut dsts,
+        );
+        assert_eq!(offsets, vec![0, 0, 1]);
+        assert_eq!(dsts, vec![0]);
+    }
+}
@@ -0,0 +1,22 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::graphs::filter_arcs_graph::FilterArcsGraph;
+use crate::traits::SequentialGraph;
+
+/// Returns a sequential graph containing only the arcs `(src, dst)` of the
+/// provided graph such that `filter(src, dst)` is true.
+///
+/// Arcs are filtered lazily while iterating, so no new graph is built; for
+/// example, `filter_arcs(&graph, |src, dst| src != dst)` removes self-loops,
+/// and `filter_arcs(&graph, |src, dst| dst > src)` keeps only the arcs going
+/// forward.
+pub fn filter_arcs<G: SequentialGraph, F: Fn(usize, usize) -> bool>(
+    graph: G,
+    filter: F,
+) -> FilterArcsGraph<G, F> {
+    FilterArcsGraph(graph, filter)
+}
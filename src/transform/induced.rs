@@ -0,0 +1,81 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::graphs::arc_list_graph;
+use crate::prelude::sort_pairs::{BatchIterator, KMergeIters};
+use crate::prelude::*;
+use anyhow::{ensure, Context, Result};
+use dsi_progress_logger::prelude::*;
+use lender::*;
+use tempfile::Builder;
+
+/// Returns the subgraph induced by the nodes `v` such that `keep[v]` is true
+/// as a [sequential graph](crate::traits::SequentialGraph), together with the
+/// mapping from the nodes of the subgraph to the nodes of the input graph.
+///
+/// Kept nodes are renumbered densely preserving their order, so the `i`-th
+/// kept node becomes node `i` and the `i`-th element of the returned mapping
+/// is its original identifier. The result contains the arcs of the input
+/// graph whose endpoints are both kept; kept nodes without such arcs are
+/// still part of the result. Successors are returned in sorted order, so the
+/// result can be compressed directly.
+///
+/// For the meaning of the additional parameter, see
+/// [`SortPairs`](crate::prelude::sort_pairs::SortPairs).
+#[allow(clippy::type_complexity)]
+pub fn induced_subgraph(
+    graph: &impl SequentialGraph,
+    keep: &[bool],
+    batch_size: usize,
+) -> Result<(
+    Left<arc_list_graph::ArcListGraph<KMergeIters<BatchIterator<()>, ()>>>,
+    Vec<usize>,
+)> {
+    ensure!(
+        keep.len() == graph.num_nodes(),
+        "The given node subset has {} values and thus it's incompatible with a graph with {} nodes.",
+        keep.len(),
+        graph.num_nodes(),
+    );
+
+    // build the dense renumbering of the kept nodes
+    let mut map = Vec::with_capacity(keep.len());
+    let mut inv = Vec::new();
+    for (node, &kept) in keep.iter().enumerate() {
+        if kept {
+            map.push(inv.len());
+            inv.push(node);
+        } else {
+            map.push(usize::MAX);
+        }
+    }
+
+    let dir = Builder::new().prefix("InducedSubgraph").tempdir()?;
+    let mut sorted = SortPairs::new(batch_size, dir.path())?;
+
+    let mut pl = ProgressLogger::default();
+    pl.item_name("node")
+        .expected_updates(Some(graph.num_nodes()));
+    pl.start("Creating batches...");
+    // create batches of sorted edges
+    for_!( (src, succ) in graph.iter() {
+        if keep[src] {
+            for dst in succ {
+                if keep[dst] {
+                    sorted.push(map[src], map[dst])?;
+                }
+            }
+        }
+        pl.light_update();
+    });
+
+    // get a graph on the sorted data
+    let edges = sorted.iter().context("Could not read arcs")?;
+    let sorted = arc_list_graph::ArcListGraph::new_labeled(inv.len(), edges);
+    pl.done();
+
+    Ok((Left(sorted), inv))
+}
@@ -0,0 +1,22 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::graphs::intersection_graph::IntersectionGraph;
+use crate::traits::SequentialGraph;
+use anyhow::Result;
+
+/// Returns a sequential graph containing the arcs that appear in both of the
+/// provided graphs.
+///
+/// The sorted successor lists of the two graphs are merged lazily while
+/// iterating, so no new graph is built. Returns an error if the graphs do not
+/// have the same number of nodes.
+pub fn intersect<G: SequentialGraph, H: SequentialGraph>(
+    g: G,
+    h: H,
+) -> Result<IntersectionGraph<G, H>> {
+    IntersectionGraph::new(g, h)
+}
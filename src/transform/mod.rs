@@ -7,9 +7,27 @@
 
 //! Transformations on labelings and graphs.
 
+mod csr;
+pub use csr::*;
+
+mod filter;
+pub use filter::*;
+
+mod induced;
+pub use induced::*;
+
+mod intersect;
+pub use intersect::*;
+
 mod simplify;
 pub use simplify::*;
 
+mod subgraph;
+pub use subgraph::*;
+
+mod symmetrize;
+pub use symmetrize::*;
+
 mod transpose;
 pub use transpose::*;
 
@@ -17,6 +17,11 @@ use tempfile::Builder;
 
 /// Returns a [sequential](crate::traits::SequentialGraph) permuted graph.
 ///
+/// Node `v` of the input graph becomes node `perm[v]`, that is, for each arc
+/// `(u, v)` of the input graph the result has the arc `(perm[u], perm[v])`.
+/// Successors are returned in sorted order, so the result can be compressed
+/// directly. Permuting by the inverse of `perm` yields back the input graph.
+///
 /// Note that if the graph is [splittable](SplitLabeling),
 /// [`permute_split`] will be much faster.
 ///
@@ -36,7 +41,7 @@ pub fn permute(
     let dir = Builder::new().prefix("Permute").tempdir()?;
 
     // create a stream where to dump the sorted pairs
-    let mut sorted = SortPairs::new(batch_size, dir)?;
+    let mut sorted = SortPairs::new(batch_size, dir.path())?;
 
     // get a premuted view
     let pgraph = PermutedGraph { graph, perm };
@@ -48,7 +53,7 @@ pub fn permute(
     // create batches of sorted edges
     for_!( (src, succ) in pgraph.iter() {
         for dst in succ {
-            sorted.push(dst, src)?;
+            sorted.push(src, dst)?;
         }
         pl.light_update();
     });
@@ -11,7 +11,7 @@ use crate::graphs::{
 };
 use crate::labels::Left;
 use crate::traits::{SequentialGraph, SplitLabeling};
-use crate::utils::sort_pairs::{BatchIterator, KMergeIters, SortPairs};
+use crate::utils::sort_pairs::{BatchIterator, DedupPairs, KMergeIters, SortPairs};
 use anyhow::{Context, Result};
 use dsi_progress_logger::prelude::*;
 use itertools::{Dedup, Itertools};
@@ -95,6 +95,69 @@ pub fn simplify(
     Ok(Left(sorted))
 }
 
+/// Returns a version of the provided graph without self-loops and duplicate
+/// arcs as a [sequential graph](crate::traits::SequentialGraph).
+///
+/// Differently from [`simplify`], the graph is not symmetrized: the arc
+/// `(v, w)` is present in the result iff `v ≠ w` and `(v, w)` appears at
+/// least once in the input. Successors are returned in sorted order.
+///
+/// For the meaning of the additional parameter, see
+/// [`SortPairs`](crate::prelude::sort_pairs::SortPairs).
+#[allow(clippy::type_complexity)]
+pub fn simplify_directed(
+    graph: &impl SequentialGraph,
+    batch_size: usize,
+) -> Result<Left<arc_list_graph::ArcListGraph<DedupPairs<KMergeIters<BatchIterator<()>, ()>>>>> {
+    sort_arcs(
+        graph,
+        batch_size,
+        "simplify-directed-",
+        |sorted, src, dst| {
+            if src != dst {
+                sorted.push(src, dst)?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Returns the graph whose arcs are those pushed by `push` into a
+/// [`SortPairs`] for each arc of the provided graph, in sorted order and
+/// without duplicates.
+///
+/// This is the common implementation of [`simplify_directed`] and
+/// [`symmetrize`](super::symmetrize); `prefix` is the prefix of the
+/// temporary directory containing the batches.
+#[allow(clippy::type_complexity)]
+pub(crate) fn sort_arcs(
+    graph: &impl SequentialGraph,
+    batch_size: usize,
+    prefix: &str,
+    mut push: impl FnMut(&mut SortPairs, usize, usize) -> Result<()>,
+) -> Result<Left<arc_list_graph::ArcListGraph<DedupPairs<KMergeIters<BatchIterator<()>, ()>>>>> {
+    let dir = Builder::new().prefix(prefix).tempdir()?;
+    let mut sorted = SortPairs::new(batch_size, dir.path())?;
+
+    let mut pl = ProgressLogger::default();
+    pl.item_name("node")
+        .expected_updates(Some(graph.num_nodes()));
+    pl.start("Creating batches...");
+    // create batches of sorted edges
+    let mut iter = graph.iter();
+    while let Some((src, succ)) = iter.next() {
+        for dst in succ {
+            push(&mut sorted, src, dst)?;
+        }
+        pl.light_update();
+    }
+    // merge the batches, collapsing runs of identical arcs
+    let sorted = arc_list_graph::ArcListGraph::new_labeled(graph.num_nodes(), sorted.iter_dedup()?);
+    pl.done();
+
+    Ok(Left(sorted))
+}
+
 /// Returns a simplified (i.e., undirected and loopless) version of the provided
 /// graph as a [sequential graph](crate::traits::SequentialGraph).
 ///
@@ -105,7 +168,7 @@ pub fn simplify_split<S>(
     graph: &S,
     batch_size: usize,
     threads: impl Borrow<rayon::ThreadPool>,
-) -> Result<Left<arc_list_graph::ArcListGraph<itertools::Dedup<KMergeIters<BatchIterator<()>, ()>>>>>
+) -> Result<Left<arc_list_graph::ArcListGraph<DedupPairs<KMergeIters<BatchIterator<()>, ()>>>>>
 where
     S: SequentialGraph + SplitLabeling,
 {
@@ -149,10 +212,37 @@ where
     // get a graph on the sorted data
     log::debug!("Waiting for threads to finish");
     let edges: KMergeIters<BatchIterator> = rx.iter().sum();
-    let edges = edges.dedup();
+    let edges = DedupPairs::new(edges);
     log::debug!("All threads finished");
     let sorted = arc_list_graph::ArcListGraph::new_labeled(graph.num_nodes(), edges);
 
     drop(dirs);
     Ok(Left(sorted))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+
+    #[test]
+    fn test_simplify_directed() -> anyhow::Result<()> {
+        let arcs = [
+            (0, 1),
+            (0, 1),
+            (0, 0),
+            (1, 2),
+            (1, 0),
+            (1, 2),
+            (2, 2),
+            (2, 0),
+        ];
+        let g = Left(arc_list_graph::ArcListGraph::new(3, arcs));
+        let s = simplify_directed(&g, 2)?;
+        assert_eq!(
+            Left(VecGraph::from_lender(&s)),
+            Left(VecGraph::from_arc_list([(0, 1), (1, 0), (1, 2), (2, 0)]))
+        );
+        Ok(())
+    }
+}
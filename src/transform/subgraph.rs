@@ -0,0 +1,19 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::graphs::subgraph::SubGraph;
+use crate::traits::SequentialGraph;
+use anyhow::Result;
+
+/// Returns a sequential graph containing only the nodes in `[start..end)` of
+/// the provided graph, renumbered to `[0..end - start)`.
+///
+/// Arcs whose destination is outside the range are dropped lazily while
+/// iterating, so no new graph is built. Returns an error if `start` is
+/// greater than `end`, or if `end` is greater than the number of nodes.
+pub fn subgraph<G: SequentialGraph>(graph: G, start: usize, end: usize) -> Result<SubGraph<G>> {
+    SubGraph::new(graph, start, end)
+}
@@ -0,0 +1,92 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::simplify::sort_arcs;
+use crate::graphs::arc_list_graph;
+use crate::labels::Left;
+use crate::traits::SequentialGraph;
+use crate::utils::sort_pairs::{BatchIterator, DedupPairs, KMergeIters};
+use anyhow::Result;
+
+/// Returns the symmetrized version of the provided graph, that is, the union
+/// of the graph and of its transpose, as a
+/// [sequential graph](crate::traits::SequentialGraph).
+///
+/// Differently from [`simplify`](super::simplify), self-loops are preserved.
+/// The arc `(v, w)` is present in the result iff `(v, w)` or `(w, v)` appears
+/// at least once in the input; in particular, arcs present in both
+/// directions in the input are not duplicated. Successors are returned in
+/// sorted order.
+///
+/// For the meaning of the additional parameter, see
+/// [`SortPairs`](crate::prelude::sort_pairs::SortPairs).
+#[allow(clippy::type_complexity)]
+pub fn symmetrize(
+    graph: &impl SequentialGraph,
+    batch_size: usize,
+) -> Result<Left<arc_list_graph::ArcListGraph<DedupPairs<KMergeIters<BatchIterator<()>, ()>>>>> {
+    // push the arcs in both directions; the arcs emitted twice are
+    // collapsed while merging
+    sort_arcs(graph, batch_size, "symmetrize-", |sorted, src, dst| {
+        sorted.push(src, dst)?;
+        if src != dst {
+            sorted.push(dst, src)?;
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+    use crate::traits::collect_arcs;
+
+    #[test]
+    fn test_symmetrize() -> anyhow::Result<()> {
+        let arcs = [(0, 1), (0, 1), (0, 0), (1, 2), (1, 0), (2, 2), (3, 1)];
+        let g = Left(arc_list_graph::ArcListGraph::new(4, arcs));
+        let s = symmetrize(&g, 2)?;
+        assert_eq!(
+            Left(VecGraph::from_lender(&s)),
+            Left(VecGraph::from_arc_list([
+                (0, 0),
+                (0, 1),
+                (1, 0),
+                (1, 2),
+                (1, 3),
+                (2, 1),
+                (2, 2),
+                (3, 1)
+            ]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_symmetrize_cnr_2000() -> anyhow::Result<()> {
+        use crate::graphs::bvgraph::BVGraphSeq;
+        use dsi_bitstream::traits::BE;
+
+        let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+            .endianness::<BE>()
+            .load()?;
+        let sym = collect_arcs(&symmetrize(&graph, 10_000)?);
+
+        // (u, v) is an arc iff (v, u) is an arc
+        let mut reversed: Vec<_> = sym.iter().map(|&(src, dst)| (dst, src)).collect();
+        reversed.sort_unstable();
+        assert_eq!(reversed, sym);
+
+        // The arcs are exactly those of the graph and of its transpose
+        let mut expected = collect_arcs(&graph);
+        expected.extend(expected.clone().into_iter().map(|(src, dst)| (dst, src)));
+        expected.sort_unstable();
+        expected.dedup();
+        assert_eq!(sym, expected);
+        Ok(())
+    }
+}
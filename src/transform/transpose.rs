@@ -7,12 +7,14 @@
 use crate::graphs::arc_list_graph;
 use crate::prelude::proj::Left;
 use crate::prelude::sort_pairs::{BatchIterator, BitReader, BitWriter, KMergeIters, SortPairs};
-use crate::prelude::{BitDeserializer, BitSerializer, LabeledSequentialGraph, SequentialGraph};
-use crate::traits::graph::UnitLabelGraph;
-use anyhow::Result;
+use crate::prelude::{
+    BitDeserializer, BitSerializer, LabeledSequentialGraph, SequentialGraph, SplitLabeling,
+};
+use anyhow::{Context, Result};
 use dsi_bitstream::traits::NE;
 use dsi_progress_logger::prelude::*;
 use lender::prelude::*;
+use std::borrow::Borrow;
 use tempfile::Builder;
 
 /// Returns the transpose of the provided labeled graph as a [sequential
@@ -67,20 +69,136 @@ pub fn transpose(
     graph: impl SequentialGraph,
     batch_size: usize,
 ) -> Result<Left<arc_list_graph::ArcListGraph<KMergeIters<BatchIterator<()>, ()>>>> {
-    Ok(Left(transpose_labeled(
-        &UnitLabelGraph(graph),
-        batch_size,
-        (),
-        (),
-    )?))
+    transpose_with(&graph, batch_size, |_, _| {})
+}
+
+/// Returns the transpose of the provided graph as a [sequential
+/// graph](crate::traits::SequentialGraph), invoking `f(dst, src)` on each
+/// reversed arc.
+///
+/// The closure is called once for each arc `(src, dst)` of the graph, in the
+/// order in which arcs are scanned, before the reversed arc is sorted. It can
+/// be used to gather statistics, such as the indegrees of the nodes, in the
+/// same pass as the transposition. The result is the same as that of
+/// [`transpose`].
+///
+/// For the meaning of the additional parameter, see
+/// [`SortPairs`](crate::prelude::sort_pairs::SortPairs).
+#[allow(clippy::type_complexity)]
+pub fn transpose_with(
+    graph: &impl SequentialGraph,
+    batch_size: usize,
+    mut f: impl FnMut(usize, usize),
+) -> Result<Left<arc_list_graph::ArcListGraph<KMergeIters<BatchIterator<()>, ()>>>> {
+    let dir = Builder::new().prefix("Transpose").tempdir()?;
+    let mut sorted = SortPairs::new(batch_size, dir.path())?;
+
+    let mut pl = progress_logger!(
+        item_name = "node",
+        expected_updates = Some(graph.num_nodes()),
+        display_memory = true
+    );
+    pl.start("Creating batches...");
+    // create batches of sorted edges
+    for_!( (src, succ) in graph.iter() {
+        for dst in succ {
+            f(dst, src);
+            sorted.push(dst, src)?;
+        }
+        pl.light_update();
+    });
+    // merge the batches
+    let edges = sorted.iter().context("Could not read arcs")?;
+    let sorted = arc_list_graph::ArcListGraph::new_labeled(graph.num_nodes(), edges);
+    pl.done();
+
+    Ok(Left(sorted))
+}
+
+/// Returns the transpose of the provided [splittable](SplitLabeling) graph as
+/// a [sequential graph](crate::traits::SequentialGraph).
+///
+/// The graph is split into as many parts as there are threads in the pool;
+/// each thread sorts the reversed arcs of its part into its own batches,
+/// and all batches are then merged. The result is the same as that of
+/// [`transpose`], which should be used if the graph is not splittable.
+///
+/// For the meaning of the additional parameter, see
+/// [`SortPairs`](crate::prelude::sort_pairs::SortPairs). Each thread uses
+/// batches of `batch_size` arcs, as [`transpose`] does.
+#[allow(clippy::type_complexity)]
+pub fn transpose_split<S>(
+    graph: &S,
+    batch_size: usize,
+    threads: impl Borrow<rayon::ThreadPool>,
+) -> Result<Left<arc_list_graph::ArcListGraph<KMergeIters<BatchIterator<()>, ()>>>>
+where
+    S: SequentialGraph + SplitLabeling,
+{
+    let pool = threads.borrow();
+    let num_threads = pool.current_num_threads();
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let mut dirs = vec![];
+
+    pool.in_place_scope(|scope| -> Result<()> {
+        let mut thread_id = 0;
+        #[allow(clippy::explicit_counter_loop)] // enumerate requires some extra bounds here
+        for iter in graph.split_iter(num_threads) {
+            let tx = tx.clone();
+            let dir = Builder::new()
+                .prefix(&format!("Transpose{}", thread_id))
+                .tempdir()
+                .context("Could not create a temporary directory")?;
+            let dir_path = dir.path().to_path_buf();
+            dirs.push(dir);
+            scope.spawn(move |_| {
+                log::debug!("Spawned thread {}", thread_id);
+                let result = (|| -> Result<KMergeIters<BatchIterator>> {
+                    let mut sorted = SortPairs::new(batch_size, dir_path)?;
+                    for_!( (src, succ) in iter {
+                        for dst in succ {
+                            sorted.push(dst, src)?;
+                        }
+                    });
+                    sorted.iter().context("Could not read arcs")
+                })();
+                // the receiver outlives the scope, so sending cannot fail
+                let _ = tx.send(result);
+                log::debug!("Thread {} finished", thread_id);
+            });
+            thread_id += 1;
+        }
+        Ok(())
+    })?;
+    drop(tx);
+
+    // get a graph on the sorted data
+    log::debug!("Waiting for threads to finish");
+    let edges: KMergeIters<BatchIterator> = rx
+        .iter()
+        .collect::<Result<Vec<_>>>()
+        .context("Could not sort the transposed arcs")?
+        .into_iter()
+        .sum();
+    log::debug!("All threads finished");
+    let sorted = arc_list_graph::ArcListGraph::new_labeled(graph.num_nodes(), edges);
+
+    drop(dirs);
+    Ok(Left(sorted))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+    use crate::labels::MapLabels;
+    use crate::traits::{collect_labeled_arcs, SequentialLabeling};
+    use dsi_bitstream::codes::{GammaRead, GammaWrite};
+    use dsi_bitstream::traits::{BitRead, BitWrite};
+
     #[test]
     fn test_transposition() -> anyhow::Result<()> {
-        use crate::graphs::vec_graph::VecGraph;
         let arcs = vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 4), (3, 4)];
         let g = Left(VecGraph::from_arc_list(arcs));
 
@@ -95,59 +213,106 @@ mod tests {
     }
 
     #[test]
-    fn test_transposition_labeled() -> anyhow::Result<()> {
-        use dsi_bitstream::codes::{GammaRead, GammaWrite};
-        use dsi_bitstream::traits::{BitRead, BitWrite};
-
-        use crate::graphs::vec_graph::VecGraph;
-        use crate::traits::SequentialLabeling;
-
-        #[derive(Clone, Copy, PartialEq, Debug)]
-        struct Payload(f64);
-
-        #[derive(Clone, Copy, PartialEq, Debug)]
-        struct BD {}
-
-        impl BitDeserializer<NE, BitReader> for BD
-        where
-            BitReader: GammaRead<NE>,
-        {
-            type DeserType = Payload;
-
-            fn deserialize(
-                &self,
-                bitstream: &mut BitReader,
-            ) -> Result<Self::DeserType, <BitReader as BitRead<NE>>::Error> {
-                let mantissa = bitstream.read_gamma()?;
-                let exponent = bitstream.read_gamma()?;
-                let result = f64::from_bits((exponent << 53) | mantissa);
-                Ok(Payload(result))
-            }
+    fn test_transposition_with() -> anyhow::Result<()> {
+        let arcs = vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 4), (3, 4), (4, 0)];
+        let g = Left(VecGraph::from_arc_list(arcs));
+
+        let mut indegrees = vec![0; g.num_nodes()];
+        let trans = transpose_with(&g, 3, |dst, _src| indegrees[dst] += 1)?;
+        assert_eq!(
+            Left(VecGraph::from_lender(&trans)),
+            Left(VecGraph::from_lender(&transpose(&g, 3)?))
+        );
+
+        let mut outdegrees = vec![];
+        for_!( (_node, succ) in trans.iter() {
+            outdegrees.push(succ.into_iter().count());
+        });
+        assert_eq!(indegrees, outdegrees);
+        assert_eq!(indegrees, vec![1, 1, 2, 1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transposition_split() -> anyhow::Result<()> {
+        use crate::graphs::bvgraph::BVGraph;
+        use dsi_bitstream::traits::BE;
+
+        let graph = BVGraph::with_basename("tests/data/cnr-2000")
+            .endianness::<BE>()
+            .load()?;
+        let expected = Left(VecGraph::from_lender(&transpose(&graph, 10_000)?));
+        for num_threads in [1, 3] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()?;
+            let trans = transpose_split(&graph, 10_000, &pool)?;
+            assert_eq!(Left(VecGraph::from_lender(&trans)), expected);
+        }
+
+        // A batch size smaller than the number of threads
+        let g = Left(arc_list_graph::ArcListGraph::new(
+            3,
+            [(0, 1), (0, 2), (1, 2), (2, 0)],
+        ));
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build()?;
+        let trans = transpose_split(&g, 2, &pool)?;
+        assert_eq!(
+            Left(VecGraph::from_lender(&trans)),
+            Left(VecGraph::from_arc_list([(0, 2), (1, 0), (2, 0), (2, 1)]))
+        );
+        Ok(())
+    }
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Payload(f64);
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct BD {}
+
+    impl BitDeserializer<NE, BitReader> for BD
+    where
+        BitReader: GammaRead<NE>,
+    {
+        type DeserType = Payload;
+
+        fn deserialize(
+            &self,
+            bitstream: &mut BitReader,
+        ) -> Result<Self::DeserType, <BitReader as BitRead<NE>>::Error> {
+            let mantissa = bitstream.read_gamma()?;
+            let exponent = bitstream.read_gamma()?;
+            let result = f64::from_bits((exponent << 53) | mantissa);
+            Ok(Payload(result))
         }
+    }
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct BS {}
 
-        #[derive(Clone, Copy, PartialEq, Debug)]
-        struct BS {}
-
-        impl BitSerializer<NE, BitWriter> for BS
-        where
-            BitWriter: GammaWrite<NE>,
-        {
-            type SerType = Payload;
-
-            fn serialize(
-                &self,
-                value: &Self::SerType,
-                bitstream: &mut BitWriter,
-            ) -> Result<usize, <BitWriter as BitWrite<NE>>::Error> {
-                let value = value.0.to_bits();
-                let mantissa = value & ((1 << 53) - 1);
-                let exponent = value >> 53;
-                let mut written_bits = 0;
-                written_bits += bitstream.write_gamma(mantissa)?;
-                written_bits += bitstream.write_gamma(exponent)?;
-                Ok(written_bits)
-            }
+    impl BitSerializer<NE, BitWriter> for BS
+    where
+        BitWriter: GammaWrite<NE>,
+    {
+        type SerType = Payload;
+
+        fn serialize(
+            &self,
+            value: &Self::SerType,
+            bitstream: &mut BitWriter,
+        ) -> Result<usize, <BitWriter as BitWrite<NE>>::Error> {
+            let value = value.0.to_bits();
+            let mantissa = value & ((1 << 53) - 1);
+            let exponent = value >> 53;
+            let mut written_bits = 0;
+            written_bits += bitstream.write_gamma(mantissa)?;
+            written_bits += bitstream.write_gamma(exponent)?;
+            Ok(written_bits)
         }
+    }
+
+    #[test]
+    fn test_transposition_labeled() -> anyhow::Result<()> {
         let arcs = vec![
             (0, 1, Payload(1.0)),
             (0, 2, Payload(f64::EPSILON)),
@@ -169,7 +334,116 @@ mod tests {
         let g4 = VecGraph::from_labeled_lender(g.iter());
 
         assert_eq!(g3, g4);
+        // Equality ignores labels, and NaN is not equal to itself
+        let bits = |g: &VecGraph<Payload>| -> Vec<_> {
+            collect_labeled_arcs(g)
+                .into_iter()
+                .map(|(src, dst, Payload(x))| (src, dst, x.to_bits()))
+                .collect()
+        };
+        assert_eq!(bits(&g3), bits(&g));
+        assert_eq!(bits(&g4), bits(&g));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transposition_map_labels() -> anyhow::Result<()> {
+        use crate::traits::GammaSerDe;
+
+        let arcs = vec![
+            (0, 1, 1_u32),
+            (0, 2, 0),
+            (1, 2, 2),
+            (2, 4, u32::MAX),
+            (3, 4, 1000),
+        ];
+        let g = VecGraph::<u32>::from_labeled_arc_list(arcs.clone());
+        // The labels change type, too
+        let doubled = MapLabels::new(&g, |x: u32| 2 * x as u64);
+        assert_eq!(doubled.num_nodes(), g.num_nodes());
+
+        let expected: Vec<_> = arcs
+            .into_iter()
+            .map(|(src, dst, x)| (src, dst, 2 * x as u64))
+            .collect();
+        assert_eq!(collect_labeled_arcs(&doubled), expected);
+
+        let trans = transpose_labeled(
+            &doubled,
+            2,
+            GammaSerDe::<u64>::default(),
+            GammaSerDe::<u64>::default(),
+        )?;
+        let g2 = VecGraph::<u64>::from_labeled_lender(trans.iter());
+
+        let trans = transpose_labeled(
+            &g2,
+            2,
+            GammaSerDe::<u64>::default(),
+            GammaSerDe::<u64>::default(),
+        )?;
+        let g3 = VecGraph::<u64>::from_labeled_lender(trans.iter());
+
+        assert_eq!(collect_labeled_arcs(&g3), expected);
+
+        Ok(())
+    }
+
+    /// Transposes twice a graph with the given labels, and checks that the
+    /// labeled arcs are preserved.
+    fn check_label_serde<S, D, L>(labels: &[L], ser: S, deser: D) -> anyhow::Result<()>
+    where
+        S: BitSerializer<NE, BitWriter, SerType = L> + Clone,
+        D: BitDeserializer<NE, BitReader, DeserType = L> + Clone + 'static,
+        L: Copy + Send + Sync + PartialEq + core::fmt::Debug + 'static,
+    {
+        let arcs = [(0, 1), (0, 2), (1, 2), (1, 3), (2, 4), (3, 4), (4, 0)];
+        let arcs = arcs
+            .iter()
+            .zip(labels.iter().cycle())
+            .map(|(&(src, dst), &l)| (src, dst, l))
+            .collect::<Vec<_>>();
+        let g = VecGraph::from_labeled_arc_list(arcs.iter().copied());
+
+        let trans = transpose_labeled(&g, 2, ser.clone(), deser.clone())?;
+        let g2 = VecGraph::from_labeled_lender(trans.iter());
+        let trans = transpose_labeled(&g2, 2, ser, deser)?;
+        let g3 = VecGraph::from_labeled_lender(trans.iter());
+
+        assert_eq!(collect_labeled_arcs(&g3), arcs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transposition_integer_labels() -> anyhow::Result<()> {
+        use crate::traits::{DeltaSerDe, FixedWidth, GammaSerDe};
 
+        let small = [0_u32, 1, 2, 1000, u32::MAX];
+        let large = [0_u64, 1, 2, 1 << 40, u64::MAX - 1];
+        check_label_serde(
+            &small,
+            GammaSerDe::<u32>::default(),
+            GammaSerDe::<u32>::default(),
+        )?;
+        check_label_serde(
+            &small,
+            DeltaSerDe::<u32>::default(),
+            DeltaSerDe::<u32>::default(),
+        )?;
+        check_label_serde(
+            &large,
+            GammaSerDe::<u64>::default(),
+            GammaSerDe::<u64>::default(),
+        )?;
+        check_label_serde(
+            &large,
+            DeltaSerDe::<u64>::default(),
+            DeltaSerDe::<u64>::default(),
+        )?;
+        check_label_serde(&[0, 1, 7, 5, 3], FixedWidth::<3>, FixedWidth::<3>)?;
+        check_label_serde(&large, FixedWidth::<64>, FixedWidth::<64>)?;
+        check_label_serde(&[u64::MAX, 0], FixedWidth::<64>, FixedWidth::<64>)?;
         Ok(())
     }
 }
@@ -15,10 +15,13 @@
 /// [`IndexMut`](std::ops::IndexMut) with both positive and negative indices,
 /// which are resolved with modular arithmetic. It is also possible to
 /// [take](CircularBuffer::take) and [replace](CircularBuffer::replace) the
-/// value at a given index.
+/// value at a given index, and to [get](CircularBuffer::get) it only if it
+/// is still in the buffer.
 #[derive(Debug, Clone)]
 pub(crate) struct CircularBuffer<T: Default> {
     data: Vec<T>,
+    /// One plus the largest index passed to [`replace`](CircularBuffer::replace).
+    end: usize,
 }
 
 impl<T: Default> CircularBuffer<T> {
@@ -26,6 +29,7 @@ impl<T: Default> CircularBuffer<T> {
     pub(crate) fn new(len: usize) -> Self {
         Self {
             data: (0..len).map(|_| T::default()).collect::<Vec<_>>(),
+            end: 0,
         }
     }
 
@@ -40,8 +44,24 @@ impl<T: Default> CircularBuffer<T> {
     pub(crate) fn replace(&mut self, index: usize, data: T) -> &T {
         let idx = index % self.data.len();
         self.data[idx] = data;
+        self.end = self.end.max(index + 1);
         &self.data[idx]
     }
+
+    /// Returns the element at the given index, if it is in the buffer, that
+    /// is, if it is one of the last `len` indices up to the largest index
+    /// passed to [`replace`](CircularBuffer::replace).
+    ///
+    /// Differently from indexing, which resolves any index with modular
+    /// arithmetic, this method returns `None` for indices that have been
+    /// evicted from the buffer or that have not been written yet.
+    pub(crate) fn get(&self, index: usize) -> Option<&T> {
+        if index < self.end && self.end - index <= self.data.len() {
+            Some(&self.data[index % self.data.len()])
+        } else {
+            None
+        }
+    }
 }
 
 impl<T: Default> core::ops::Index<usize> for CircularBuffer<T> {
@@ -79,3 +99,28 @@ impl<T: Default> core::ops::IndexMut<isize> for CircularBuffer<T> {
         &mut self.data[idx]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let mut buffer = CircularBuffer::<Vec<usize>>::new(3);
+        assert_eq!(buffer.get(0), None);
+        for i in 0..5 {
+            buffer.replace(i, vec![i]);
+        }
+        // evicted
+        assert_eq!(buffer.get(0), None);
+        assert_eq!(buffer.get(1), None);
+        // in the buffer
+        assert_eq!(buffer.get(2), Some(&vec![2]));
+        assert_eq!(buffer.get(4), Some(&vec![4]));
+        // future
+        assert_eq!(buffer.get(5), None);
+        assert_eq!(buffer.get(usize::MAX), None);
+        // indexing still uses modular arithmetic
+        assert_eq!(buffer[5_usize], vec![2]);
+    }
+}
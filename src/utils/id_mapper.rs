@@ -0,0 +1,205 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::graphs::arc_list_graph::ArcListGraph;
+use crate::labels::Left;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::io::Write;
+use std::path::Path;
+
+/// A mapping assigning dense node ids to external string identifiers
+/// (e.g., URLs).
+///
+/// Identifiers are assigned consecutive ids, starting from zero, in the
+/// order in which they are first seen; the mapping can then be
+/// [written](IdMapper::write_names) to translate results back.
+///
+/// Each identifier is stored only once, in a single string containing all
+/// identifiers in order of id: the hash table maps hashes of identifiers to
+/// ids, and identifiers with the same hash are chained, so that the memory
+/// used per identifier, besides its bytes, is a few words.
+#[derive(Debug, Clone, Default)]
+pub struct IdMapper {
+    /// The identifiers, concatenated in order of id.
+    names: String,
+    /// The end of each identifier in `names`.
+    ends: Vec<usize>,
+    /// The largest id of an identifier with a given hash.
+    ids: HashMap<u64, usize>,
+    /// For each id, the previous id of an identifier with the same hash, or
+    /// `usize::MAX`.
+    chain: Vec<usize>,
+    hasher: RandomState,
+}
+
+impl IdMapper {
+    /// Creates an empty mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the identifier with the given id, which must exist.
+    fn name_unchecked(&self, id: usize) -> &str {
+        let start = if id == 0 { 0 } else { self.ends[id - 1] };
+        &self.names[start..self.ends[id]]
+    }
+
+    /// Returns the id of an identifier with a given hash, if it has been
+    /// seen.
+    fn find(&self, name: &str, hash: u64) -> Option<usize> {
+        let mut id = *self.ids.get(&hash)?;
+        while id != usize::MAX {
+            if self.name_unchecked(id) == name {
+                return Some(id);
+            }
+            id = self.chain[id];
+        }
+        None
+    }
+
+    /// Returns the id of an identifier, assigning it the next free id if it
+    /// has not been seen yet.
+    pub fn id(&mut self, name: &str) -> usize {
+        let hash = self.hasher.hash_one(name);
+        if let Some(id) = self.find(name, hash) {
+            return id;
+        }
+        let id = self.len();
+        self.chain
+            .push(self.ids.insert(hash, id).unwrap_or(usize::MAX));
+        self.names.push_str(name);
+        self.ends.push(self.names.len());
+        id
+    }
+
+    /// Returns the id of an identifier, if it has been seen.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.find(name, self.hasher.hash_one(name))
+    }
+
+    /// Returns the identifier with the given id, if any.
+    pub fn name(&self, id: usize) -> Option<&str> {
+        (id < self.len()).then(|| self.name_unchecked(id))
+    }
+
+    /// Returns an iterator over the identifiers, in order of id.
+    pub fn names(&self) -> impl ExactSizeIterator<Item = &str> + '_ {
+        (0..self.len()).map(|id| self.name_unchecked(id))
+    }
+
+    /// Returns the number of identifiers seen so far.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns whether no identifier has been seen.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Maps a list of arcs between identifiers to a graph.
+    ///
+    /// The arcs are collected, mapped, sorted and deduplicated in memory,
+    /// so this is suitable only for small graphs; larger inputs should be
+    /// mapped one arc at a time with [`id`](IdMapper::id) and sorted with
+    /// [`SortPairs`](crate::utils::SortPairs). The number of nodes of the graph
+    /// is the number of identifiers seen so far, including those seen by
+    /// previous calls.
+    #[allow(clippy::type_complexity)]
+    pub fn arc_list_graph<S: AsRef<str>>(
+        &mut self,
+        arcs: impl IntoIterator<Item = (S, S)>,
+    ) -> Left<
+        ArcListGraph<
+            std::iter::Map<
+                std::vec::IntoIter<(usize, usize)>,
+                fn((usize, usize)) -> (usize, usize, ()),
+            >,
+        >,
+    > {
+        let mut arcs = arcs
+            .into_iter()
+            .map(|(src, dst)| (self.id(src.as_ref()), self.id(dst.as_ref())))
+            .collect::<Vec<_>>();
+        arcs.sort_unstable();
+        arcs.dedup();
+        Left(ArcListGraph::new(self.len(), arcs))
+    }
+
+    /// Writes the identifiers in order of id, one per line.
+    pub fn write_names(&self, mut writer: impl Write) -> Result<()> {
+        for name in self.names() {
+            writer.write_all(name.as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the identifiers in order of id, one per line, to the given
+    /// file.
+    pub fn store(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("Could not create {}", path.display()))?;
+        self.write_names(std::io::BufWriter::new(file))
+            .with_context(|| format!("Could not write identifiers to {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::vec_graph::VecGraph;
+
+    #[test]
+    fn test_id_mapper() -> Result<()> {
+        let arcs = [
+            ("https://a.org/", "https://b.org/"),
+            ("https://b.org/", "https://c.org/x"),
+            ("https://a.org/", "https://c.org/x"),
+            ("https://c.org/x", "https://a.org/"),
+            ("https://a.org/", "https://b.org/"),
+        ];
+        let mut mapper = IdMapper::new();
+        let graph = mapper.arc_list_graph(arcs);
+        assert_eq!(mapper.len(), 3);
+        assert_eq!(mapper.get("https://c.org/x"), Some(2));
+        assert_eq!(mapper.get("https://d.org/"), None);
+        assert_eq!(mapper.name(1), Some("https://b.org/"));
+        assert_eq!(mapper.name(3), None);
+        assert_eq!(
+            mapper.names().collect::<Vec<_>>(),
+            vec!["https://a.org/", "https://b.org/", "https://c.org/x"]
+        );
+        assert_eq!(
+            Left(VecGraph::from_lender(&graph)),
+            Left(VecGraph::from_arc_list([(0, 1), (0, 2), (1, 2), (2, 0)]))
+        );
+
+        let mut names = vec![];
+        mapper.write_names(&mut names)?;
+        assert_eq!(
+            String::from_utf8(names)?,
+            "https://a.org/\nhttps://b.org/\nhttps://c.org/x\n"
+        );
+
+        // Many identifiers, some of which are prefixes of others
+        let mut mapper = IdMapper::new();
+        for i in 0..10_000 {
+            assert_eq!(mapper.id(&i.to_string()), i);
+        }
+        for i in 0..10_000 {
+            assert_eq!(mapper.id(&i.to_string()), i);
+            assert_eq!(mapper.name(i), Some(i.to_string().as_str()));
+        }
+        assert_eq!(mapper.get(""), None);
+        assert_eq!(mapper.len(), 10_000);
+        Ok(())
+    }
+}
@@ -133,6 +133,41 @@ impl<W> MmapHelper<W> {
             _marker: core::marker::PhantomData,
         })
     }
+
+    /// Brings the whole mapping into the page cache by reading one byte
+    /// per page, sequentially.
+    ///
+    /// This method moves the cost of page faults to an explicit up-front
+    /// step, which is useful when measuring steady-state access times. If
+    /// `will_need` is true, on Unix-like systems the kernel is first advised
+    /// with `MADV_WILLNEED`, so that read-ahead can start immediately.
+    ///
+    /// Returns the number of pages touched.
+    pub fn warm(&self, will_need: bool) -> Result<usize> {
+        let ptr = self.mmap.as_ptr();
+        let len = self.mmap.len();
+        #[cfg(unix)]
+        if will_need {
+            let res = unsafe { libc::madvise(ptr as *mut libc::c_void, len, libc::MADV_WILLNEED) };
+            ensure!(
+                res == 0,
+                "madvise failed: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+        #[cfg(not(unix))]
+        let _ = will_need;
+
+        let page_size = MmapOptions::page_size();
+        let mut checksum = 0_u8;
+        let mut pages = 0;
+        for offset in (0..len).step_by(page_size) {
+            checksum ^= unsafe { ptr.add(offset).read_volatile() };
+            pages += 1;
+        }
+        std::hint::black_box(checksum);
+        Ok(pages)
+    }
 }
 
 impl<W> MmapHelper<W, MmapMut> {
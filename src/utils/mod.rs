@@ -10,13 +10,35 @@
 use rand::Rng;
 use std::path::PathBuf;
 
-/// Bijective mapping from isize to u64 as defined in <https://github.com/vigna/dsiutils/blob/master/src/it/unimi/dsi/bits/Fast.java>
+/// Bijective mapping from i64 to u64 as defined in <https://github.com/vigna/dsiutils/blob/master/src/it/unimi/dsi/bits/Fast.java>
+///
+/// This is the zig-zag mapping, which interleaves nonnegative and negative
+/// integers: a nonnegative `x` is mapped to `2 * x`, and a negative `x` to
+/// `-2 * x - 1`, so that integers of small absolute value are mapped to
+/// small natural numbers. It is used to write signed values, such as the
+/// first residual of a node or the start of its first interval, using codes
+/// for natural numbers. Its inverse is [`nat2int`].
+///
+/// ```
+/// # use webgraph::utils::*;
+///
+/// assert_eq!(int2nat(0), 0);
+/// assert_eq!(int2nat(-1), 1);
+/// assert_eq!(int2nat(1), 2);
+/// assert_eq!(int2nat(-2), 3);
+/// assert_eq!(int2nat(2), 4);
+/// assert_eq!(int2nat(i64::MAX), u64::MAX - 1);
+/// assert_eq!(int2nat(i64::MIN), u64::MAX);
+/// ```
 pub const fn int2nat(x: i64) -> u64 {
     (x << 1 ^ (x >> 63)) as u64
 }
 
 /// Bijective mapping from u64 to i64 as defined in <https://github.com/vigna/dsiutils/blob/master/src/it/unimi/dsi/bits/Fast.java>
 ///
+/// This is the inverse of the zig-zag mapping [`int2nat`]: an even `x` is
+/// mapped to `x / 2`, and an odd `x` to `-(x + 1) / 2`.
+///
 /// ```
 /// # use webgraph::utils::*;
 ///
@@ -58,6 +80,9 @@ pub(crate) use circular_buffer::*;
 mod mmap_helper;
 pub use mmap_helper::*;
 
+mod id_mapper;
+pub use id_mapper::*;
+
 mod java_perm;
 pub use java_perm::*;
 
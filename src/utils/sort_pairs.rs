@@ -234,6 +234,18 @@ where
             .unwrap()
         })))
     }
+
+    /// Returns an iterator over the labeled pairs, lexicographically sorted,
+    /// in which each pair `(x, y)` appears once.
+    ///
+    /// If the same pair was pushed several times with different labels, the
+    /// label returned is the first one in the merge order. See
+    /// [`DedupPairs`].
+    pub fn iter_dedup(
+        &mut self,
+    ) -> anyhow::Result<DedupPairs<KMergeIters<BatchIterator<D>, D::DeserType>>> {
+        Ok(DedupPairs::new(self.iter()?))
+    }
 }
 
 /// An iterator that can read the batch files generated by [`SortPairs`].
@@ -594,6 +606,39 @@ impl<T, I: Iterator<Item = (usize, usize, T)>> Extend<KMergeIters<I, T>> for KMe
     }
 }
 
+/// An iterator on labeled pairs that drops pairs equal to the previous one,
+/// ignoring labels.
+///
+/// If the underlying iterator returns sorted pairs, as in the case of
+/// [`KMergeIters`], each pair is returned exactly once, with the label of its
+/// first occurrence. This is the deduplication step needed, for example, to
+/// avoid parallel arcs when building a graph from the output of [`SortPairs`].
+#[derive(Clone, Debug)]
+pub struct DedupPairs<I> {
+    iter: I,
+    last: Option<(usize, usize)>,
+}
+
+impl<T, I: Iterator<Item = (usize, usize, T)>> DedupPairs<I> {
+    pub fn new(iter: I) -> Self {
+        DedupPairs { iter, last: None }
+    }
+}
+
+impl<T, I: Iterator<Item = (usize, usize, T)>> Iterator for DedupPairs<I> {
+    type Item = (usize, usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (src, dst, label) = self.iter.next()?;
+            if self.last != Some((src, dst)) {
+                self.last = Some((src, dst));
+                return Some((src, dst, label));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -648,4 +693,32 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_sort_pairs_dedup() -> anyhow::Result<()> {
+        use tempfile::Builder;
+
+        let dir = Builder::new().prefix("test_sort_pairs_dedup-").tempdir()?;
+        // Small batches, so that duplicates end up in different batches
+        let mut sp = SortPairs::new(3, dir.path())?;
+        let pairs = [
+            (2, 1),
+            (0, 1),
+            (2, 1),
+            (1, 3),
+            (0, 1),
+            (0, 0),
+            (2, 1),
+            (1, 3),
+        ];
+        for (x, y) in pairs {
+            sp.push(x, y)?;
+        }
+        assert_eq!(sp.iter()?.count(), pairs.len());
+        assert_eq!(
+            sp.iter_dedup()?.map(|(x, y, _)| (x, y)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 3), (2, 1)]
+        );
+        Ok(())
+    }
 }
@@ -60,3 +60,25 @@ fn test_cnr2000() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_seed() -> Result<()> {
+    // 4 -> 0 -> 2
+    //       `-> 3
+    // 1 -> 5
+    let mut graph = webgraph::graphs::vec_graph::VecGraph::new();
+
+    for i in 0..=5 {
+        graph.add_node(i);
+    }
+    graph.add_arc(4, 0);
+    graph.add_arc(0, 2);
+    graph.add_arc(0, 3);
+    graph.add_arc(1, 5);
+
+    let order: Vec<_> = BfsOrder::new_from(&Left(graph), 4).collect();
+
+    assert_eq!(order, vec![4, 0, 2, 3, 1, 5]);
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::*;
+use lender::*;
+use sux::prelude::*;
+use webgraph::graphs::vec_graph::VecGraph;
+use webgraph::labels::bitstream::LabelReader;
+use webgraph::prelude::*;
+
+/// Labels are γ-coded.
+struct GammaDeserializer;
+
+impl<'a> BitDeserializer<BE, LabelReader<'a, BE>> for GammaDeserializer {
+    type DeserType = u64;
+
+    fn deserialize(
+        &self,
+        bitstream: &mut LabelReader<'a, BE>,
+    ) -> Result<Self::DeserType, <LabelReader<'a, BE> as BitRead<BE>>::Error> {
+        bitstream.read_gamma()
+    }
+}
+
+#[test]
+fn test_bitstream_labeling() -> Result<()> {
+    let labeled = VecGraph::from_labeled_arc_list([
+        (0, 1, 10),
+        (0, 3, 0),
+        (1, 2, 7),
+        (2, 0, 1000),
+        (2, 1, 3),
+        (2, 3, 5),
+        (4, 0, 1),
+    ]);
+    let num_nodes = labeled.num_nodes();
+
+    // Write the labels, recording the offset of each node
+    let mut writer = <BufBitWriter<BE, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+    let mut offsets = vec![0];
+    let mut pos = 0;
+    let mut iter = labeled.iter();
+    while let Some((_, succ)) = iter.next() {
+        for (_, label) in succ {
+            pos += writer.write_gamma(label)?;
+        }
+        offsets.push(pos);
+    }
+    let data = writer.into_inner()?.into_inner();
+    let data: Vec<u32> = unsafe { data.align_to().1 }.to_vec();
+    let mut efb = EliasFanoBuilder::new(offsets.len(), pos);
+    for offset in offsets {
+        efb.push(offset);
+    }
+    let ef: webgraph::graphs::bvgraph::EF = unsafe {
+        efb.build()
+            .map_high_bits(SelectAdaptConst::<_, _, 12, 4>::new)
+    };
+
+    // Compress the graph and attach the labels to it
+    let tmp_dir = tempfile::tempdir()?;
+    let basename = tmp_dir.path().join("labeled");
+    BVComp::single_thread::<BE, _>(
+        &basename,
+        &Left(labeled.clone()),
+        CompFlags::default(),
+        true,
+        Some(num_nodes),
+    )?;
+    load_or_build_ef(&basename)?;
+    let graph = BVGraph::with_basename(&basename)
+        .endianness::<BE>()
+        .load()?;
+    let labeling = BitStreamLabeling::<_, BE, _, _, _>::new(graph, data, ef, GammaDeserializer);
+    assert_eq!(labeling.num_nodes(), num_nodes);
+    assert_eq!(labeling.num_arcs(), labeled.num_arcs());
+
+    // Random-access labeled successors match sequential iteration
+    let mut iter = labeled.iter();
+    let mut seq = labeling.iter();
+    while let Some((node, succ)) = iter.next() {
+        let succ = succ.collect::<Vec<_>>();
+        assert_eq!(labeling.labeled_successors(node).collect::<Vec<_>>(), succ);
+        assert_eq!(labeling.outdegree(node), succ.len());
+        let (seq_node, labels) = seq.next().unwrap();
+        assert_eq!(seq_node, node);
+        assert_eq!(
+            labels.into_iter().collect::<Vec<_>>(),
+            succ.iter().map(|&(_, label)| label).collect::<Vec<_>>()
+        );
+    }
+    Ok(())
+}
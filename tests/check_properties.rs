@@ -0,0 +1,57 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::BE;
+use std::path::Path;
+use webgraph::cli::check::properties::check_properties;
+use webgraph::prelude::*;
+
+/// Copies cnr-2000 in `dir`, replacing `from` with `to` in its properties.
+fn copy_with(dir: &Path, from: &str, to: &str) -> Result<std::path::PathBuf> {
+    let basename = dir.join("cnr-2000");
+    std::fs::copy(
+        "tests/data/cnr-2000.graph",
+        basename.with_extension(GRAPH_EXTENSION),
+    )?;
+    let properties = std::fs::read_to_string("tests/data/cnr-2000.properties")?;
+    assert!(properties.contains(from));
+    std::fs::write(
+        basename.with_extension(PROPERTIES_EXTENSION),
+        properties.replace(from, to),
+    )?;
+    Ok(basename)
+}
+
+#[test]
+fn test_check_properties() -> Result<()> {
+    check_properties::<BE>("tests/data/cnr-2000")?;
+    webgraph::cli::main(vec![
+        "webgraph",
+        "check",
+        "properties",
+        "tests/data/cnr-2000",
+    ])
+}
+
+#[test]
+fn test_check_wrong_properties() -> Result<()> {
+    let tmp = tempfile::tempdir()?;
+
+    let basename = copy_with(tmp.path(), "arcs=3216152", "arcs=3216153")?;
+    let err = check_properties::<BE>(&basename).unwrap_err().to_string();
+    assert!(err.contains("3216153 arcs"), "{}", err);
+    assert!(err.contains("3216152 arcs"), "{}", err);
+
+    let basename = copy_with(tmp.path(), "nodes=325557", "nodes=325558")?;
+    let err = check_properties::<BE>(&basename).unwrap_err().to_string();
+    assert!(err.contains("325558 nodes"), "{}", err);
+    assert!(err.contains("325557 nodes"), "{}", err);
+
+    let basename = copy_with(tmp.path(), "nodes=325557", "nodes=325556")?;
+    assert!(check_properties::<BE>(&basename).is_err());
+    Ok(())
+}
@@ -0,0 +1,439 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! End-to-end tests running the `webgraph` binary and checking its output.
+
+use anyhow::{ensure, Result};
+use dsi_bitstream::prelude::BE;
+use epserde::prelude::*;
+use lender::*;
+use std::process::{Command, Output};
+use webgraph::prelude::*;
+
+const TEST_GRAPH: &str = "tests/data/cnr-2000";
+
+/// Runs the `webgraph` binary with the given arguments.
+fn webgraph(args: &[&str]) -> Result<Output> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_webgraph"))
+        .args(args)
+        .output()?)
+}
+
+/// Runs the `webgraph` binary with the given arguments, checks that it
+/// succeeds, and returns its standard output.
+fn webgraph_stdout(args: &[&str]) -> Result<String> {
+    let output = webgraph(args)?;
+    ensure!(
+        output.status.success(),
+        "webgraph {} failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Parses lines of tab-separated integers.
+fn parse_tsv(output: &str) -> Result<Vec<Vec<u64>>> {
+    output
+        .lines()
+        .map(|line| line.split('\t').map(|field| Ok(field.parse()?)).collect())
+        .collect()
+}
+
+/// Returns the outdegrees and the indegrees of cnr-2000.
+fn degrees() -> Result<(Vec<u64>, Vec<u64>)> {
+    let graph = BVGraph::with_basename(TEST_GRAPH)
+        .endianness::<BE>()
+        .load()?;
+    let mut outdegrees = vec![0; graph.num_nodes()];
+    let mut indegrees = vec![0; graph.num_nodes()];
+    for node in 0..graph.num_nodes() {
+        for succ in graph.successors(node) {
+            outdegrees[node] += 1;
+            indegrees[succ] += 1;
+        }
+    }
+    Ok((outdegrees, indegrees))
+}
+
+#[test]
+fn test_warm() -> Result<()> {
+    let page_size = mmap_rs::MmapOptions::page_size();
+    for will_need in [false, true] {
+        let mut args = vec!["bench", "warm", TEST_GRAPH];
+        if will_need {
+            args.push("--will-need");
+        }
+        let output = webgraph(&args)?;
+        assert!(output.status.success());
+        let log = String::from_utf8(output.stderr)?;
+        for extension in ["graph", "ef"] {
+            let path = format!("{}.{}", TEST_GRAPH, extension);
+            let pages = std::fs::metadata(&path)?.len().div_ceil(page_size as u64);
+            assert!(
+                log.contains(&format!("Touched {} pages of {} ", pages, path)),
+                "{}",
+                log
+            );
+        }
+    }
+
+    // Missing files are skipped
+    let tmp = tempfile::tempdir()?;
+    std::fs::copy(
+        format!("{}.graph", TEST_GRAPH),
+        tmp.path().join("cnr-2000.graph"),
+    )?;
+    let basename = tmp.path().join("cnr-2000");
+    let output = webgraph(&["bench", "warm", basename.to_str().unwrap()])?;
+    assert!(output.status.success());
+    let log = String::from_utf8(output.stderr)?;
+    assert!(
+        log.contains("cnr-2000.ef does not exist, skipping"),
+        "{}",
+        log
+    );
+    Ok(())
+}
+
+#[test]
+fn test_topk_degree() -> Result<()> {
+    let (outdegrees, indegrees) = degrees()?;
+    for (degrees, extra_args) in [(outdegrees, vec![]), (indegrees, vec!["--indegree"])] {
+        let mut expected = degrees
+            .iter()
+            .enumerate()
+            .map(|(node, &degree)| vec![node as u64, degree])
+            .collect::<Vec<_>>();
+        expected.sort_by(|a, b| b[1].cmp(&a[1]).then(a[0].cmp(&b[0])));
+        expected.truncate(20);
+
+        let mut args = vec!["analyze", "topk-degree", "-k", "20", TEST_GRAPH];
+        args.extend(extra_args);
+        assert_eq!(parse_tsv(&webgraph_stdout(&args)?)?, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_analyze_degrees() -> Result<()> {
+    let (outdegrees, indegrees) = degrees()?;
+    for (mut degrees, extra_args) in [
+        (outdegrees, vec!["--threshold", "16"]),
+        (indegrees, vec!["--indegree", "--log-bins"]),
+    ] {
+        let mut args = vec!["analyze", "degrees", TEST_GRAPH];
+        args.extend(&extra_args);
+        let output = webgraph_stdout(&args)?;
+        let (stats, histogram): (Vec<_>, Vec<_>) =
+            output.lines().partition(|line| line.starts_with('#'));
+
+        degrees.sort_unstable();
+        let num_arcs = degrees.iter().sum::<u64>();
+        assert_eq!(
+            stats,
+            vec![
+                "# nodes\t325557".to_string(),
+                format!("# arcs\t{}", num_arcs),
+                format!("# min\t{}", degrees[0]),
+                format!("# max\t{}", degrees[degrees.len() - 1]),
+                format!("# mean\t{}", num_arcs as f64 / degrees.len() as f64),
+                format!("# median\t{}", degrees[(degrees.len() - 1) / 2]),
+            ]
+        );
+
+        let histogram = parse_tsv(&histogram.join("\n"))?;
+        assert_eq!(
+            histogram.iter().map(|bin| bin[2]).sum::<u64>(),
+            degrees.len() as u64
+        );
+        for bin in histogram {
+            let (lower, upper, count) = (bin[0], bin[1], bin[2]);
+            if lower < 16 && extra_args.contains(&"--threshold") {
+                assert_eq!(lower, upper);
+            } else if lower > 0 {
+                // Powers-of-two bins
+                assert!(lower.is_power_of_two());
+                assert_eq!(upper, (lower + 1).next_power_of_two() - 1);
+            }
+            assert_eq!(
+                degrees
+                    .iter()
+                    .filter(|&&degree| lower <= degree && degree <= upper)
+                    .count() as u64,
+                count
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_perm_invert() -> Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let path = |name: &str| tmp.path().join(name).to_str().unwrap().to_owned();
+    let (perm, inv) = (path("perm"), path("inv"));
+
+    webgraph_stdout(&["perm", "rand", TEST_GRAPH, &perm])?;
+    webgraph_stdout(&["perm", "invert", &perm, &inv])?;
+    let read = |path: &str| -> Result<Vec<u64>> {
+        Ok(std::fs::read(path)?
+            .chunks(8)
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap()))
+            .collect())
+    };
+    let (perm_values, inv_values) = (read(&perm)?, read(&inv)?);
+    assert_eq!(perm_values.len(), 325_557);
+    assert_eq!(inv_values.len(), 325_557);
+    for (i, &v) in perm_values.iter().enumerate() {
+        assert_eq!(inv_values[v as usize], i as u64);
+    }
+
+    // Inverting twice gives back the permutation
+    let inv_inv = path("inv-inv");
+    webgraph_stdout(&["perm", "invert", &inv, &inv_inv])?;
+    assert_eq!(read(&inv_inv)?, perm_values);
+
+    // The same, in ε-serde format
+    webgraph_stdout(&["perm", "by-degree", "--epserde", TEST_GRAPH, &perm])?;
+    webgraph_stdout(&["perm", "invert", "--epserde", &perm, &inv])?;
+    let perm_values = <Vec<usize>>::load_full(&perm)?;
+    let inv_values = <Vec<usize>>::load_full(&inv)?;
+    assert_eq!(inv_values.len(), 325_557);
+    for (i, &v) in perm_values.iter().enumerate() {
+        assert_eq!(inv_values[v], i);
+    }
+    webgraph_stdout(&["perm", "rand", TEST_GRAPH, &perm])?;
+    let perm_values = read(&perm)?;
+
+    // Repeated values are rejected
+    let mut bytes = std::fs::read(&perm)?;
+    bytes.copy_within(0..8, 8);
+    std::fs::write(&perm, bytes)?;
+    let output = webgraph(&["perm", "invert", &perm, &inv])?;
+    assert!(!output.status.success());
+    let err = String::from_utf8(output.stderr)?;
+    assert!(
+        err.contains(&format!(
+            "Not a permutation: {} appears at positions 0 and 1",
+            perm_values[0]
+        )),
+        "{}",
+        err
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_diff() -> Result<()> {
+    // The same graph with different compression parameters
+    assert_eq!(
+        webgraph_stdout(&["check", "diff", TEST_GRAPH, "tests/data/cnr-2000-hc"])?,
+        "The graphs are identical\n"
+    );
+
+    let tmp = tempfile::tempdir()?;
+    let transposed = tmp.path().join("cnr-2000-t");
+    let transposed = transposed.to_str().unwrap();
+    webgraph_stdout(&["transform", "transpose", TEST_GRAPH, transposed])?;
+
+    let graph = BVGraph::with_basename(TEST_GRAPH)
+        .endianness::<BE>()
+        .load()?;
+    let mut preds = vec![vec![]; graph.num_nodes()];
+    for node in 0..graph.num_nodes() {
+        for succ in graph.successors(node) {
+            preds[succ].push(node);
+        }
+    }
+    let mut first_diff = None;
+    let mut diff_nodes = 0;
+    let mut only_succ = 0;
+    let mut only_pred = 0;
+    for (node, preds) in preds.iter().enumerate() {
+        let succs = graph.successors(node).into_iter().collect::<Vec<_>>();
+        if &succs != preds {
+            first_diff.get_or_insert(node);
+            diff_nodes += 1;
+            only_succ += succs
+                .iter()
+                .filter(|x| preds.binary_search(x).is_err())
+                .count();
+            only_pred += preds
+                .iter()
+                .filter(|x| succs.binary_search(x).is_err())
+                .count();
+        }
+    }
+
+    let output = webgraph(&["check", "diff", TEST_GRAPH, transposed])?;
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        format!(
+            "First differing node: {}\nDiffering nodes: {}\nArcs only in {}: {}\nArcs only in {}: {}\n",
+            first_diff.unwrap(),
+            diff_nodes,
+            TEST_GRAPH,
+            only_succ,
+            transposed,
+            only_pred
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_analyze_codes_tsv() -> Result<()> {
+    let output = webgraph_stdout(&["analyze", "codes", "--tsv", TEST_GRAPH])?;
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("field\tbits\tfraction\tbits/arc"));
+    let rows = lines
+        .map(|line| line.split('\t').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows.iter().map(|row| row[0]).collect::<Vec<_>>(),
+        [
+            "outdegrees",
+            "references",
+            "blocks",
+            "intervals",
+            "residuals",
+            "total"
+        ]
+    );
+    let bits = rows
+        .iter()
+        .map(|row| row[1].parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The fields match the statistics of the Java implementation
+    let properties = std::fs::read_to_string(format!("{}.properties", TEST_GRAPH))?;
+    for (field, bits) in [
+        "outdegrees",
+        "references",
+        "blocks",
+        "intervals",
+        "residuals",
+    ]
+    .iter()
+    .zip(&bits)
+    {
+        assert!(
+            properties.contains(&format!("bitsfor{}={}\n", field, bits)),
+            "{}: {}",
+            field,
+            bits
+        );
+    }
+    // The total is the length of the bitstream
+    let total = bits[5];
+    assert_eq!(total, bits[..5].iter().sum::<u64>());
+    assert_eq!(
+        total.div_ceil(8),
+        std::fs::metadata(format!("{}.graph", TEST_GRAPH))?.len()
+    );
+    assert_eq!(rows[5][2], "1.000000");
+    assert_eq!(rows[5][3], format!("{:.3}", total as f64 / 3_216_152_f64));
+    Ok(())
+}
+
+#[test]
+fn test_check_graph() -> Result<()> {
+    let output = webgraph(&["check", "graph", TEST_GRAPH])?;
+    assert!(output.status.success());
+    let log = String::from_utf8(output.stderr)?;
+    assert!(log.contains("The graph is coherent"), "{}", log);
+
+    // Offsets of a differently compressed graph
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("cnr-2000");
+    for extension in ["graph", "properties"] {
+        std::fs::copy(
+            format!("{}.{}", TEST_GRAPH, extension),
+            basename.with_extension(extension),
+        )?;
+    }
+    std::fs::copy(
+        "tests/data/cnr-2000-hc.offsets",
+        basename.with_extension("offsets"),
+    )?;
+    let basename = basename.to_str().unwrap();
+    webgraph_stdout(&["build", "ef", basename])?;
+    let output = webgraph(&["check", "graph", basename])?;
+    assert!(!output.status.success());
+    let err = String::from_utf8(output.stderr)?;
+    assert!(
+        err.contains(
+            "Node 8: the random-access outdegree is 0, but the sequential outdegree is 18"
+        ),
+        "{}",
+        err
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_random() -> Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let path = |name: &str| tmp.path().join(name).to_str().unwrap().to_owned();
+    for model in ["er", "powerlaw"] {
+        let (a, b, c) = (path("a"), path("b"), path("c"));
+        for (dst, seed) in [(&a, "1"), (&b, "1"), (&c, "2")] {
+            webgraph_stdout(&[
+                "from", "random", "-n", "10000", "-d", "8", "-m", model, "-s", seed, dst,
+            ])?;
+        }
+        // The same seed yields the same graph
+        let graph = std::fs::read(format!("{}.graph", a))?;
+        assert_eq!(graph, std::fs::read(format!("{}.graph", b))?);
+        assert_ne!(graph, std::fs::read(format!("{}.graph", c))?);
+
+        let graph = BVGraphSeq::with_basename(&a).endianness::<BE>().load()?;
+        assert_eq!(graph.num_nodes(), 10_000);
+        let mut num_arcs = 0;
+        let mut iter = graph.iter();
+        while let Some((node, succ)) = iter.next() {
+            let succ = succ.into_iter().collect::<Vec<_>>();
+            // Sorted, without duplicates and without loops
+            assert!(succ.windows(2).all(|w| w[0] < w[1]));
+            assert!(succ.iter().all(|&s| s != node && s < 10_000));
+            num_arcs += succ.len() as u64;
+        }
+        assert_eq!(Some(num_arcs), graph.num_arcs_hint());
+        assert!(
+            (75_000..=85_000).contains(&num_arcs),
+            "{}: {} arcs",
+            model,
+            num_arcs
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_check_ef() -> Result<()> {
+    webgraph_stdout(&["check", "ef", TEST_GRAPH])?;
+
+    // An .offsets file not matching the .ef file
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("cnr-2000");
+    for extension in ["graph", "properties", "ef"] {
+        std::fs::copy(
+            format!("{}.{}", TEST_GRAPH, extension),
+            basename.with_extension(extension),
+        )?;
+    }
+    std::fs::copy(
+        "tests/data/cnr-2000-hc.offsets",
+        basename.with_extension("offsets"),
+    )?;
+    let output = webgraph(&["check", "ef", basename.to_str().unwrap()])?;
+    assert!(!output.status.success());
+    let err = String::from_utf8(output.stderr)?;
+    assert!(err.contains("node_id: "), "{}", err);
+    Ok(())
+}
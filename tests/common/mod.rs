@@ -0,0 +1,30 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Fixtures shared by the tests of algorithms on undirected graphs.
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use webgraph::{graphs::vec_graph::VecGraph, labels::proj::Left};
+
+/// Returns a symmetric graph with the given undirected edges.
+pub fn symmetric(num_nodes: usize, edges: &[(usize, usize)]) -> Left<VecGraph<()>> {
+    let mut graph = VecGraph::empty(num_nodes);
+    for &(u, v) in edges {
+        graph.add_arc(u, v);
+        graph.add_arc(v, u);
+    }
+    Left(graph)
+}
+
+/// Returns the undirected edges `(u, v)`, with `u < v < num_nodes`, of a
+/// random graph in which each edge is present with probability `p`.
+pub fn random_edges(num_nodes: usize, p: f64, seed: u64) -> Vec<(usize, usize)> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..num_nodes)
+        .flat_map(|u| (u + 1..num_nodes).map(move |v| (u, v)))
+        .filter(|_| rng.gen_bool(p))
+        .collect()
+}
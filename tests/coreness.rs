@@ -0,0 +1,66 @@
+mod common;
+
+use common::{random_edges, symmetric};
+use webgraph::algo::coreness;
+
+#[test]
+fn test_coreness() {
+    // A triangle 0 1 2 with a tail 2 - 3 - 4, a 4-clique on 5..9 with a
+    // self-loop on 5, and an isolated node 9
+    let graph = symmetric(
+        10,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (5, 5),
+            (5, 6),
+            (5, 7),
+            (5, 8),
+            (6, 7),
+            (6, 8),
+            (7, 8),
+        ],
+    );
+    assert_eq!(coreness(&graph), vec![2, 2, 2, 1, 1, 3, 3, 3, 3, 0]);
+}
+
+#[test]
+fn test_coreness_random() {
+    let n = 60;
+    let edges = random_edges(n, 0.1, 0);
+
+    // Naive peeling: for each k, repeatedly remove nodes of degree < k
+    let mut expected = vec![0; n];
+    for k in 1..n {
+        let mut alive = vec![true; n];
+        loop {
+            let mut deg = vec![0; n];
+            for &(u, v) in &edges {
+                if alive[u] && alive[v] {
+                    deg[u] += 1;
+                    deg[v] += 1;
+                }
+            }
+            let mut changed = false;
+            for node in 0..n {
+                if alive[node] && deg[node] < k {
+                    alive[node] = false;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        for node in 0..n {
+            if alive[node] {
+                expected[node] = k;
+            }
+        }
+    }
+
+    assert_eq!(coreness(&symmetric(n, &edges)), expected);
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use webgraph::{
+    algo::{diameter_lower_bound, DiameterBound},
+    graphs::vec_graph::VecGraph,
+    labels::proj::Left,
+};
+
+#[test]
+fn test_path_diameter() -> Result<()> {
+    // An undirected path on 10 nodes has diameter 9
+    let mut arcs = vec![];
+    for i in 0..9 {
+        arcs.push((i, i + 1));
+        arcs.push((i + 1, i));
+    }
+    let graph = Left(VecGraph::from_arc_list(arcs));
+    for seed in 0..10 {
+        let bound = diameter_lower_bound(&graph, 1, seed).unwrap();
+        assert_eq!(bound.lower_bound, 9);
+        let mut ends = [bound.source, bound.target];
+        ends.sort();
+        assert_eq!(ends, [0, 9]);
+    }
+    // The result is reproducible
+    assert_eq!(
+        diameter_lower_bound(&graph, 3, 42),
+        diameter_lower_bound(&graph, 3, 42)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_directed_path_diameter() -> Result<()> {
+    // On a directed path the bound is the distance from a random node to the
+    // end of the path
+    let graph = Left(VecGraph::from_arc_list((0..9).map(|i| (i, i + 1))));
+    let DiameterBound {
+        lower_bound,
+        source,
+        target,
+    } = diameter_lower_bound(&graph, 5, 0).unwrap();
+    assert_eq!(target, 9);
+    assert_eq!(lower_bound, 9 - source);
+    Ok(())
+}
+
+#[test]
+fn test_empty_diameter() {
+    let graph = Left(VecGraph::<()>::new());
+    assert_eq!(diameter_lower_bound(&graph, 1, 0), None);
+    let graph = Left(VecGraph::from_arc_list([(0, 0)]));
+    assert_eq!(
+        diameter_lower_bound(&graph, 1, 0),
+        Some(DiameterBound {
+            lower_bound: 0,
+            source: 0,
+            target: 0
+        })
+    );
+}
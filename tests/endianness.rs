@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::*;
+use webgraph::prelude::*;
+
+#[test]
+fn test_default_endianness() {
+    // Little endian is the default only if big endian is not compiled in
+    if cfg!(all(feature = "le_bins", not(feature = "be_bins"))) {
+        assert_eq!(DEFAULT_ENDIANNESS, LE::NAME);
+    } else {
+        assert_eq!(DEFAULT_ENDIANNESS, BE::NAME);
+    }
+}
+
+#[test]
+fn test_missing_endianness() -> Result<()> {
+    // The properties of cnr-2000 were written by the Java implementation
+    // and have no endianness key
+    let properties = std::fs::read_to_string("tests/data/cnr-2000.properties")?;
+    assert!(!properties.contains("endianness"));
+    assert_eq!(get_endianness("tests/data/cnr-2000")?, DEFAULT_ENDIANNESS);
+
+    // Little-endian graphs must have version 1
+    let tmp = tempfile::tempdir()?;
+    let path = tmp.path().join("cnr-2000.properties");
+    std::fs::write(&path, properties.replace("version=0", "version=1"))?;
+    let properties = GraphProperties::load(&path)?;
+    assert_eq!(properties.endianness, DEFAULT_ENDIANNESS);
+    Ok(())
+}
+
+#[test]
+fn test_unknown_endianness() -> Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("cnr-2000");
+    let mut properties = std::fs::read_to_string("tests/data/cnr-2000.properties")?;
+    properties.push_str("endianness=middle\n");
+    std::fs::write(basename.with_extension(PROPERTIES_EXTENSION), properties)?;
+
+    assert_eq!(get_endianness(&basename)?, "middle");
+    assert!(GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION)).is_err());
+
+    let err = webgraph::cli::main(vec![
+        "webgraph",
+        "bench",
+        "bvgraph",
+        basename.to_str().unwrap(),
+    ])
+    .unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("middle"), "{}", message);
+    assert!(message.contains(DEFAULT_ENDIANNESS), "{}", message);
+    Ok(())
+}
+
+#[test]
+fn test_endianness_not_compiled_in() {
+    for (endianness, feature) in [(BE::NAME, "be_bins"), (LE::NAME, "le_bins")] {
+        let message = webgraph::cli::unknown_endianness(endianness).to_string();
+        assert!(
+            message.contains(&format!("not compiled in (enable feature {})", feature)),
+            "{}",
+            message
+        );
+    }
+    let message = webgraph::cli::unknown_endianness("middle").to_string();
+    assert!(!message.contains("not compiled in"), "{}", message);
+}
@@ -0,0 +1,137 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::BE;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use webgraph::cli::main as cli_main;
+use webgraph::prelude::*;
+
+const ARCS: &str = "# a comment\n0 1\n0 3\n1 2\n1 2\n\n2 0\n2 4\n4 4\n";
+
+fn check_graph(basename: &Path, num_nodes: usize) -> Result<()> {
+    load_or_build_ef(basename)?;
+    let graph = BVGraph::with_basename(basename).endianness::<BE>().load()?;
+    assert_eq!(graph.num_nodes(), num_nodes);
+    assert_eq!(
+        collect_arcs(&graph),
+        vec![(0, 1), (0, 3), (1, 2), (2, 0), (2, 4), (4, 4)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_arcs() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let input = tmp_dir.path().join("arcs.txt");
+    let mut lines = ARCS.lines().collect::<Vec<_>>();
+    lines.reverse();
+    std::fs::write(&input, lines.join("\n"))?;
+    let input = input.display().to_string();
+    let basename = tmp_dir.path().join("graph");
+    let dst = basename.display().to_string();
+
+    cli_main(vec!["webgraph", "from", "arcs", "--input", &input, &dst])?;
+    check_graph(&basename, 5)
+}
+
+#[test]
+fn test_from_arcs_presorted() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let input = tmp_dir.path().join("arcs.txt");
+    std::fs::write(&input, ARCS)?;
+    let input = input.display().to_string();
+    let basename = tmp_dir.path().join("graph");
+    let dst = basename.display().to_string();
+
+    cli_main(vec![
+        "webgraph",
+        "from",
+        "arcs",
+        "--presorted",
+        "-n",
+        "6",
+        "--input",
+        &input,
+        &dst,
+    ])?;
+    check_graph(&basename, 6)
+}
+
+#[test]
+fn test_from_arcs_presorted_errors() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let dst = tmp_dir.path().join("graph").display().to_string();
+
+    for (arcs, num_nodes) in [("0 1\n1 2\n0 3\n", "4"), ("0 1\n1 4\n", "4")] {
+        let input = tmp_dir.path().join("arcs.txt");
+        std::fs::write(&input, arcs)?;
+        let input = input.display().to_string();
+        assert!(cli_main(vec![
+            "webgraph",
+            "from",
+            "arcs",
+            "--presorted",
+            "-n",
+            num_nodes,
+            "--input",
+            &input,
+            &dst,
+        ])
+        .is_err());
+    }
+
+    // The number of nodes is required
+    assert!(webgraph::cli::from::arcs::cli(clap::Command::new("from"))
+        .try_get_matches_from(["from", "arcs", "--presorted", &dst])
+        .is_err());
+    Ok(())
+}
+
+/// Runs `webgraph from arcs` with the given arguments, piping `arcs` to its
+/// standard input, and returns whether it succeeded.
+fn from_arcs_stdin(args: &[&str], arcs: &str) -> Result<bool> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_webgraph"))
+        .args(["from", "arcs"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(arcs.as_bytes())?;
+    Ok(child.wait()?.success())
+}
+
+#[test]
+fn test_from_arcs_stdin() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let basename = tmp_dir.path().join("graph");
+    let dst = basename.display().to_string();
+
+    assert!(from_arcs_stdin(&[&dst], ARCS)?);
+    check_graph(&basename, 5)?;
+    assert!(from_arcs_stdin(&["--presorted", "-n", "6", &dst], ARCS)?);
+    check_graph(&basename, 6)?;
+
+    // Unsorted arcs are detected only after compression has started, so the
+    // partial output must be removed
+    let basename = tmp_dir.path().join("unsorted");
+    let dst = basename.display().to_string();
+    assert!(!from_arcs_stdin(
+        &["--presorted", "-n", "4", &dst],
+        "0 1\n1 2\n0 3\n"
+    )?);
+    for extension in [GRAPH_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION] {
+        assert!(
+            !basename.with_extension(extension).exists(),
+            "{} exists",
+            extension
+        );
+    }
+    Ok(())
+}
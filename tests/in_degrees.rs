@@ -0,0 +1,27 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use lender::*;
+use webgraph::{algo::in_degrees, graphs::random::ErdosRenyi, prelude::*};
+
+#[test]
+fn test_in_degrees() -> Result<()> {
+    let graph = ErdosRenyi::new(1000, 0.01, 0);
+    let transposed = webgraph::transform::transpose(&graph, 1000)?;
+
+    let mut expected = vec![];
+    for_!( (node, succ) in transposed.iter() {
+        assert_eq!(node, expected.len());
+        expected.push(succ.into_iter().count());
+    });
+    assert_eq!(in_degrees(&graph), expected);
+    assert_eq!(
+        in_degrees(&graph).iter().sum::<usize>() as u64,
+        graph.count_arcs()
+    );
+    Ok(())
+}
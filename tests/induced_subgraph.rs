@@ -0,0 +1,60 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use lender::*;
+use webgraph::graphs::vec_graph::VecGraph;
+use webgraph::prelude::*;
+use webgraph::transform::induced_subgraph;
+
+#[test]
+fn test_induced_subgraph() -> Result<()> {
+    let graph = Left(VecGraph::from_arc_list([
+        (0, 2),
+        (0, 3),
+        (2, 4),
+        (2, 0),
+        (3, 5),
+        (4, 6),
+        (5, 1),
+        (6, 2),
+        (6, 7),
+    ]));
+    // keep every other node
+    let keep = (0..graph.num_nodes())
+        .map(|v| v % 2 == 0)
+        .collect::<Vec<_>>();
+    let (subgraph, map) = induced_subgraph(&graph, &keep, 2)?;
+    assert_eq!(map, vec![0, 2, 4, 6]);
+    assert_eq!(subgraph.num_nodes(), 4);
+
+    let mut arcs = vec![];
+    for_!( (src, succ) in subgraph.iter() {
+        for dst in succ {
+            arcs.push((map[src], map[dst]));
+        }
+    });
+    assert_eq!(arcs, vec![(0, 2), (2, 0), (2, 4), (4, 6), (6, 2)]);
+    Ok(())
+}
+
+#[test]
+fn test_induced_subgraph_isolated() -> Result<()> {
+    let graph = Left(VecGraph::from_arc_list([(0, 1), (1, 2), (3, 0)]));
+    // node 2 is kept but all its arcs go to removed nodes
+    let keep = [true, false, true, false];
+    let (subgraph, map) = induced_subgraph(&graph, &keep, 10)?;
+    assert_eq!(map, vec![0, 2]);
+    assert_eq!(subgraph.num_nodes(), 2);
+    let mut num_arcs = 0;
+    for_!( (_src, succ) in subgraph.iter() {
+        num_arcs += succ.into_iter().count();
+    });
+    assert_eq!(num_arcs, 0);
+
+    assert!(induced_subgraph(&graph, &keep[..3], 10).is_err());
+    Ok(())
+}
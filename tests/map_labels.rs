@@ -0,0 +1,52 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use lender::*;
+use webgraph::graphs::vec_graph::VecGraph;
+use webgraph::labels::MapLabels;
+use webgraph::traits::{collect_labeled_arcs, SequentialLabeling};
+
+#[test]
+fn test_map_labels() {
+    let arcs = [(0, 1, 3_u32), (0, 2, 0), (1, 2, 7), (2, 0, 1), (4, 3, 2)];
+    let g = VecGraph::<u32>::from_labeled_arc_list(arcs);
+    let halved = MapLabels::new(&g, |w: u32| w as f64 / 2.0);
+
+    // Successors, nodes and arcs are those of the underlying graph
+    assert_eq!(halved.num_nodes(), 5);
+    assert_eq!(halved.num_arcs_hint(), g.num_arcs_hint());
+    assert_eq!(
+        collect_labeled_arcs(&halved),
+        arcs.iter()
+            .map(|&(src, dst, w)| (src, dst, w as f64 / 2.0))
+            .collect::<Vec<_>>()
+    );
+
+    // Iteration from a node, including nodes without successors
+    let mut iter = halved.iter_from(2);
+    let mut nodes = vec![];
+    while let Some((node, succ)) = iter.next() {
+        nodes.push((node, succ.into_iter().collect::<Vec<_>>()));
+    }
+    assert_eq!(
+        nodes,
+        vec![(2, vec![(0, 0.5)]), (3, vec![]), (4, vec![(3, 1.0)])]
+    );
+
+    // Maps can be composed, and the underlying graph is untouched
+    let negated = MapLabels::new(halved, |w: f64| -w);
+    assert_eq!(
+        collect_labeled_arcs(&negated)
+            .into_iter()
+            .map(|(_, _, w)| w)
+            .collect::<Vec<_>>(),
+        vec![-1.5, -0.0, -3.5, -0.5, -1.0]
+    );
+    assert_eq!(
+        collect_labeled_arcs(negated.into_inner().into_inner()),
+        arcs
+    );
+}
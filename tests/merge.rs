@@ -0,0 +1,58 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::BE;
+use webgraph::cli::main as cli_main;
+use webgraph::graphs::vec_graph::VecGraph;
+use webgraph::prelude::*;
+
+#[test]
+fn test_merge() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let graphs = [
+        VecGraph::from_arc_list([(0, 1), (1, 2), (2, 0), (2, 2)]),
+        VecGraph::from_arc_list([(0, 1), (1, 0), (3, 1)]),
+    ];
+    let mut basenames = vec![];
+    for (i, graph) in graphs.iter().enumerate() {
+        let basename = tmp_dir.path().join(format!("graph-{}", i));
+        BVComp::single_thread::<BE, _>(
+            &basename,
+            &Left(graph.clone()),
+            CompFlags::default(),
+            false,
+            None,
+        )?;
+        basenames.push(basename.display().to_string());
+    }
+
+    let merged = tmp_dir.path().join("merged").display().to_string();
+    let mut args = vec!["webgraph", "transform", "merge", &merged];
+    args.extend(basenames.iter().map(String::as_str));
+    cli_main(args)?;
+    cli_main(vec!["webgraph", "build", "ef", &merged])?;
+
+    let merged = BVGraph::with_basename(&merged).endianness::<BE>().load()?;
+    assert_eq!(merged.num_nodes(), 7);
+    assert_eq!(merged.num_arcs(), 7);
+    let mut shift = 0;
+    for graph in &graphs {
+        let graph = Left(graph.clone());
+        for node in 0..graph.num_nodes() {
+            assert_eq!(
+                merged.successors(node + shift).collect::<Vec<_>>(),
+                graph
+                    .successors(node)
+                    .into_iter()
+                    .map(|succ| succ + shift)
+                    .collect::<Vec<_>>()
+            );
+        }
+        shift += graph.num_nodes();
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::*;
+use epserde::prelude::*;
+use mmap_rs::MmapFlags;
+use sux::traits::bit_field_slice::BitFieldSlice;
+use webgraph::prelude::*;
+
+const TEST_GRAPH: &str = "tests/data/cnr-2000";
+
+/// Checks that `perm` is a permutation of the nodes listing them by
+/// decreasing degree, with ties broken by node.
+fn check_perm(perm: &[usize], degrees: &[usize]) {
+    assert_eq!(perm.len(), degrees.len());
+    let mut nodes = vec![usize::MAX; perm.len()];
+    for (node, &pos) in perm.iter().enumerate() {
+        assert!(pos < perm.len(), "node {} has position {}", node, pos);
+        assert_eq!(nodes[pos], usize::MAX, "position {} is repeated", pos);
+        nodes[pos] = node;
+    }
+    for w in nodes.windows(2) {
+        assert!(
+            degrees[w[0]] > degrees[w[1]] || (degrees[w[0]] == degrees[w[1]] && w[0] < w[1]),
+            "node {} (degree {}) precedes node {} (degree {})",
+            w[0],
+            degrees[w[0]],
+            w[1],
+            degrees[w[1]]
+        );
+    }
+}
+
+#[test]
+fn test_by_degree() -> Result<()> {
+    let graph = BVGraph::with_basename(TEST_GRAPH)
+        .endianness::<BE>()
+        .load()?;
+    let tmp = tempfile::tempdir()?;
+    let perm_path = tmp.path().join("perm");
+    let perm_path = perm_path.to_str().unwrap();
+
+    webgraph::cli::main(vec!["webgraph", "perm", "by-degree", TEST_GRAPH, perm_path])?;
+    let perm = JavaPermutation::mmap(perm_path, MmapFlags::RANDOM_ACCESS)?;
+    let perm = (0..graph.num_nodes())
+        .map(|node| perm.get(node))
+        .collect::<Vec<_>>();
+    let outdegrees = (0..graph.num_nodes())
+        .map(|node| graph.outdegree(node))
+        .collect::<Vec<_>>();
+    check_perm(&perm, &outdegrees);
+
+    webgraph::cli::main(vec![
+        "webgraph",
+        "perm",
+        "by-degree",
+        "--indegree",
+        "--epserde",
+        TEST_GRAPH,
+        perm_path,
+    ])?;
+    let perm = <Vec<usize>>::load_full(perm_path)?;
+    let mut indegrees = vec![0; graph.num_nodes()];
+    for node in 0..graph.num_nodes() {
+        for succ in graph.successors(node) {
+            indegrees[succ] += 1;
+        }
+    }
+    check_perm(&perm, &indegrees);
+    Ok(())
+}
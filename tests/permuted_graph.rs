@@ -48,3 +48,45 @@ fn test_permuted() {
         ]
     );
 }
+
+#[test]
+fn test_permute() -> anyhow::Result<()> {
+    use webgraph::graphs::vec_graph::VecGraph;
+
+    let graph = Left(VecGraph::from_arc_list([(4, 0), (0, 2), (0, 3), (1, 5)]));
+    let perm = [1, 2, 3, 4, 5, 0];
+    let permuted = webgraph::transform::permute(&graph, &perm, 2)?;
+    assert_eq!(
+        Left(VecGraph::from_lender(&permuted)),
+        Left(VecGraph::from_arc_list([(5, 1), (1, 3), (1, 4), (2, 0)]))
+    );
+    assert!(webgraph::transform::permute(&graph, &perm[..5].to_vec(), 2).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_permute_inverse() -> anyhow::Result<()> {
+    use dsi_bitstream::traits::BE;
+    use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+    use webgraph::graphs::vec_graph::VecGraph;
+
+    let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let mut perm = (0..graph.num_nodes()).collect::<Vec<_>>();
+    perm.shuffle(&mut SmallRng::seed_from_u64(0));
+    let mut inv = vec![0; perm.len()];
+    for (i, &v) in perm.iter().enumerate() {
+        inv[v] = i;
+    }
+
+    let permuted = Left(VecGraph::from_lender(&webgraph::transform::permute(
+        &graph, &perm, 100_000,
+    )?));
+    let restored = webgraph::transform::permute(&permuted, &inv, 100_000)?;
+    assert_eq!(
+        Left(VecGraph::from_lender(&restored)),
+        Left(VecGraph::from_lender(&graph))
+    );
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+use dsi_bitstream::prelude::BE;
+use itertools::Itertools;
+use lender::*;
+use webgraph::{
+    graphs::{
+        bvgraph::BVGraph,
+        predecessors::{DiskPredecessors, Predecessors},
+        vec_graph::VecGraph,
+    },
+    labels::proj::Left,
+    traits::SequentialLabeling,
+    transform::transpose,
+};
+
+#[test]
+fn test_predecessors_cnr_2000() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let num_nodes = graph.num_nodes();
+
+    // The predecessors of each node, computed by brute force
+    let mut expected = vec![vec![]; num_nodes];
+    for_!( (src, succ) in graph.iter() {
+        for dst in succ {
+            expected[dst].push(src);
+        }
+    });
+
+    let lazy = Predecessors::new(&graph, 10_000);
+    for (node, pred) in expected.iter().enumerate() {
+        assert_eq!(&lazy.predecessors(node)?.collect_vec(), pred);
+    }
+
+    let transposed = Left(VecGraph::from_lender(&transpose(&graph, 10_000)?));
+    let eager = Predecessors::with_transpose(&graph, transposed);
+    for (node, pred) in expected.iter().enumerate() {
+        assert_eq!(&eager.predecessors(node)?.collect_vec(), pred);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_disk_predecessors_cnr_2000() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+
+    let disk = DiskPredecessors::new(&graph, 10_000)?;
+    assert_eq!(disk.transpose().num_nodes(), graph.num_nodes());
+    let in_memory = Predecessors::new(&graph, 10_000);
+    for node in 0..1000 {
+        assert_eq!(
+            disk.predecessors(node).collect_vec(),
+            in_memory.predecessors(node)?.collect_vec()
+        );
+    }
+
+    // The temporary files are removed on drop
+    let basename = disk.basename().to_owned();
+    drop(disk);
+    assert!(!basename.with_extension("graph").exists());
+    Ok(())
+}
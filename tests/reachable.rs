@@ -0,0 +1,58 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use webgraph::{
+    algo::{reachable_from, reachable_from_any},
+    graphs::vec_graph::VecGraph,
+    labels::proj::Left,
+};
+
+#[test]
+fn test_reachable_from() {
+    // 0 -> 1 -> 3 -> 5
+    // |         ^
+    // v         |
+    // 2 --------'
+    // 4 -> 2
+    // 6 -> 7
+    let graph = Left(VecGraph::from_arc_list([
+        (0, 1),
+        (0, 2),
+        (1, 3),
+        (2, 3),
+        (3, 5),
+        (4, 2),
+        (6, 7),
+    ]));
+    let nodes = |bits: &sux::prelude::BitVec| (0..8).filter(|&i| bits[i]).collect::<Vec<_>>();
+
+    let (reached, count) = reachable_from(&graph, 0);
+    assert_eq!(nodes(&reached), vec![0, 1, 2, 3, 5]);
+    assert_eq!(count, 5);
+
+    let (reached, count) = reachable_from(&graph, 4);
+    assert_eq!(nodes(&reached), vec![2, 3, 4, 5]);
+    assert_eq!(count, 4);
+
+    let (reached, count) = reachable_from(&graph, 5);
+    assert_eq!(nodes(&reached), vec![5]);
+    assert_eq!(count, 1);
+
+    let (reached, count) = reachable_from_any(&graph, [4, 6, 4]);
+    assert_eq!(nodes(&reached), vec![2, 3, 4, 5, 6, 7]);
+    assert_eq!(count, 6);
+
+    let (reached, count) = reachable_from_any(&graph, []);
+    assert_eq!(nodes(&reached), Vec::<usize>::new());
+    assert_eq!(count, 0);
+}
+
+#[test]
+#[should_panic]
+fn test_reachable_from_out_of_range() {
+    let graph = Left(VecGraph::from_arc_list([(0, 1)]));
+    reachable_from(&graph, 2);
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use dsi_bitstream::prelude::BE;
+use webgraph::{
+    algo::sample_arcs,
+    graphs::{bvgraph::BVGraph, vec_graph::VecGraph},
+    labels::proj::Left,
+    traits::{RandomAccessGraph, SequentialLabeling},
+};
+
+#[test]
+fn test_sample_all_arcs() -> Result<()> {
+    let graph = Left(VecGraph::from_arc_list([(0, 1), (1, 2), (2, 0), (2, 1)]));
+    let mut sample = sample_arcs(&graph, 10, 0);
+    sample.sort();
+    assert_eq!(sample, vec![(0, 1), (1, 2), (2, 0), (2, 1)]);
+    assert!(sample_arcs(&graph, 0, 0).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_sample_uniform() -> Result<()> {
+    // With 4 arcs and samples of size 2, each arc appears in half of the samples
+    let graph = Left(VecGraph::from_arc_list([(0, 1), (1, 2), (2, 0), (2, 1)]));
+    let mut counts = [0_usize; 4];
+    let arcs = [(0, 1), (1, 2), (2, 0), (2, 1)];
+    for seed in 0..4000 {
+        for arc in sample_arcs(&graph, 2, seed) {
+            counts[arcs.iter().position(|&a| a == arc).unwrap()] += 1;
+        }
+    }
+    for count in counts {
+        assert!((1800..2200).contains(&count), "{:?}", counts);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sample_arcs_cnr_2000() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let sample = sample_arcs(&graph, 1000, 42);
+    assert_eq!(sample.len(), 1000);
+    assert_eq!(sample, sample_arcs(&graph, 1000, 42));
+    for &(src, dst) in &sample {
+        assert!(graph.successors(src).any(|succ| succ == dst));
+    }
+    // Arcs are sampled without replacement
+    let mut sorted = sample.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), sample.len());
+    // The sample is not concentrated on the first nodes
+    assert!(sample.iter().any(|&(src, _)| src > graph.num_nodes() / 2));
+    Ok(())
+}
@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use webgraph::{
+    algo::count_self_loops,
+    graphs::{arc_list_graph::ArcListGraph, vec_graph::VecGraph},
+    labels::proj::Left,
+    prelude::*,
+};
+
+#[test]
+fn test_count_self_loops() -> Result<()> {
+    let graph = Left(VecGraph::from_arc_list([
+        (0, 0),
+        (0, 1),
+        (1, 2),
+        (2, 2),
+        (2, 0),
+        (3, 1),
+        (4, 4),
+    ]));
+    assert_eq!(count_self_loops(&graph), (3, 3));
+
+    let graph = Left(VecGraph::from_arc_list([(0, 1), (1, 2), (2, 0)]));
+    assert_eq!(count_self_loops(&graph), (0, 0));
+
+    // Duplicate self-loops are counted with their multiplicity
+    let graph = Left(ArcListGraph::new(3, [(0, 0), (0, 0), (0, 1), (2, 2)]));
+    assert_eq!(count_self_loops(&graph), (2, 3));
+    Ok(())
+}
+
+#[test]
+fn test_self_loops_cli() -> Result<()> {
+    use dsi_bitstream::prelude::BE;
+    use webgraph::cli::analyze::self_loops::{self_loops, CliArgs};
+
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let expected = (0..graph.num_nodes())
+        .filter(|&node| graph.successors(node).any(|succ| succ == node))
+        .count();
+    assert!(expected > 0);
+
+    let counts = self_loops::<BE>(CliArgs {
+        src: "tests/data/cnr-2000".into(),
+    })?;
+    assert_eq!(counts, (expected, expected));
+
+    webgraph::cli::main(vec![
+        "webgraph",
+        "analyze",
+        "self-loops",
+        "tests/data/cnr-2000",
+    ])
+}
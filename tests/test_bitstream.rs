@@ -0,0 +1,260 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Round-trip tests for the bit readers used to decode graphs, run for both
+//! endiannesses, and for the Golomb, Rice, γ, δ and ζ codes on both the
+//! buffered and the unbuffered reader.
+//!
+//! Values of every width from 1 to 64 bits are written starting at every
+//! possible bit offset within a word, so that reads straddling two words
+//! (the case in which big- and little-endian readers shift in opposite
+//! directions) are exercised for all alignments.
+
+use anyhow::Result;
+use dsi_bitstream::prelude::*;
+
+/// Returns the value of width `n_bits` written for the given test case.
+fn pattern(n_bits: usize, i: usize) -> u64 {
+    let v = 0xA5C3_F00F_9669_1EE1_u64.rotate_left(i as u32) ^ i as u64;
+    if n_bits == 64 {
+        v
+    } else {
+        v & ((1 << n_bits) - 1)
+    }
+}
+
+fn test_roundtrip<E: Endianness>() -> Result<()>
+where
+    BufBitWriter<E, MemWordWriterVec<u64, Vec<u64>>>: BitWrite<E>,
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: BitRead<E> + BitSeek,
+{
+    for offset in 0..64 {
+        let mut writer = <BufBitWriter<E, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+        writer.write_bits(0, offset)?;
+        for n_bits in 1..=64 {
+            writer.write_bits(pattern(n_bits, offset + n_bits), n_bits)?;
+        }
+        let data = writer.into_inner()?.into_inner();
+        let data: &[u32] = unsafe { data.align_to().1 };
+
+        let mut reader = <BufBitReader<E, _>>::new(MemWordReader::new(data));
+        reader.set_bit_pos(offset as u64)?;
+        let mut pos = offset as u64;
+        for n_bits in 1..=64 {
+            assert_eq!(
+                reader.read_bits(n_bits)?,
+                pattern(n_bits, offset + n_bits),
+                "offset: {}, n_bits: {}",
+                offset,
+                n_bits
+            );
+            pos += n_bits as u64;
+            assert_eq!(reader.bit_pos()?, pos);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_roundtrip_be() -> Result<()> {
+    test_roundtrip::<BE>()
+}
+
+#[test]
+fn test_roundtrip_le() -> Result<()> {
+    test_roundtrip::<LE>()
+}
+
+fn test_golomb_rice<E: Endianness>() -> Result<()>
+where
+    BufBitWriter<E, MemWordWriterVec<u64, Vec<u64>>>: BitWrite<E> + GolombWrite<E> + RiceWrite<E>,
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>:
+        BitRead<E> + BitSeek + GolombRead<E> + RiceRead<E>,
+    for<'a> BitReader<E, MemWordReader<u64, &'a [u64]>>:
+        BitRead<E> + BitSeek + GolombRead<E> + RiceRead<E>,
+{
+    // b = 1 degenerates to unary; 3, 5 and 1000 exercise truncated binary
+    for b in [1, 2, 3, 5, 8, 1000] {
+        let mut writer = <BufBitWriter<E, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+        let mut len = 0;
+        for n in 0..1000 {
+            let written = writer.write_golomb(n, b)?;
+            assert_eq!(written, len_golomb(n, b));
+            len += written;
+        }
+        let data = writer.into_inner()?.into_inner();
+
+        let data32: &[u32] = unsafe { data.align_to().1 };
+        let mut buf_reader = <BufBitReader<E, _>>::new(MemWordReader::new(data32));
+        let mut reader = <BitReader<E, _>>::new(MemWordReader::new(data.as_slice()));
+        for n in 0..1000 {
+            assert_eq!(buf_reader.read_golomb(b)?, n, "b: {}", b);
+            assert_eq!(reader.read_golomb(b)?, n, "b: {}", b);
+        }
+        assert_eq!(buf_reader.bit_pos()?, len as u64);
+        assert_eq!(reader.bit_pos()?, len as u64);
+    }
+
+    for log2_b in [0, 1, 3, 10] {
+        let mut writer = <BufBitWriter<E, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+        for n in 0..1000 {
+            assert_eq!(writer.write_rice(n, log2_b)?, len_rice(n, log2_b));
+        }
+        let data = writer.into_inner()?.into_inner();
+
+        let data32: &[u32] = unsafe { data.align_to().1 };
+        let mut buf_reader = <BufBitReader<E, _>>::new(MemWordReader::new(data32));
+        let mut reader = <BitReader<E, _>>::new(MemWordReader::new(data.as_slice()));
+        for n in 0..1000 {
+            assert_eq!(buf_reader.read_rice(log2_b)?, n, "log2_b: {}", log2_b);
+            assert_eq!(reader.read_rice(log2_b)?, n, "log2_b: {}", log2_b);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_golomb_rice_be() -> Result<()> {
+    test_golomb_rice::<BE>()?;
+
+    // 5 with b = 3: quotient 1 in unary (01), remainder 2 in truncated
+    // binary (11); 2 with b = 3: quotient 0 (1), remainder 2 (11)
+    let mut writer = <BufBitWriter<BE, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+    writer.write_golomb(5, 3)?;
+    writer.write_golomb(2, 3)?;
+    let data = writer.into_inner()?.into_inner();
+    let data: &[u32] = unsafe { data.align_to().1 };
+    let mut reader = <BufBitReader<BE, _>>::new(MemWordReader::new(data));
+    assert_eq!(reader.read_bits(7)?, 0b011_1111);
+    Ok(())
+}
+
+#[test]
+fn test_golomb_rice_le() -> Result<()> {
+    test_golomb_rice::<LE>()
+}
+
+/// Values exercising the tables, the fallback paths, and the full 64-bit
+/// width (gamma and delta codes write `n + 1`, so `u64::MAX` is excluded).
+///
+/// See [`zeta_fits`] for the values representable by ζ codes.
+fn instantaneous_values() -> Vec<u64> {
+    let mut values: Vec<u64> = (0..1000).collect();
+    for shift in [20, 32, 40, 63] {
+        values.extend([(1 << shift) - 1, 1 << shift, (1 << shift) + 1]);
+    }
+    values.push(u64::MAX - 1);
+    values
+}
+
+/// Returns whether `n` can be written in ζ code with parameter `k`, as the
+/// upper bound of the interval containing `n + 1`, that is, 2<sup>(*h* +
+/// 1)*k*</sup>, must fit in a `u64`.
+fn zeta_fits(n: u64, k: u64) -> bool {
+    (u64::from((n + 1).ilog2()) / k + 1) * k < 64
+}
+
+fn test_gamma_delta_zeta<E: Endianness>() -> Result<()>
+where
+    BufBitWriter<E, MemWordWriterVec<u64, Vec<u64>>>:
+        BitWrite<E> + GammaWrite<E> + DeltaWrite<E> + ZetaWrite<E>,
+    for<'a> BufBitReader<E, MemWordReader<u32, &'a [u32]>>: GammaRead<E>,
+    for<'a> BitReader<E, MemWordReader<u64, &'a [u64]>>:
+        BitRead<E> + BitSeek + GammaRead<E> + DeltaRead<E> + ZetaRead<E>,
+{
+    let values = instantaneous_values();
+
+    let mut writer = <BufBitWriter<E, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+    let mut len = 0;
+    for &n in &values {
+        assert_eq!(writer.write_gamma(n)?, len_gamma(n));
+        assert_eq!(writer.write_delta(n)?, len_delta(n));
+        len += len_gamma(n) + len_delta(n);
+        for k in (1..=5).filter(|&k| zeta_fits(n, k)) {
+            assert_eq!(writer.write_zeta(n, k)?, len_zeta(n, k));
+            len += len_zeta(n, k);
+        }
+    }
+    let data = writer.into_inner()?.into_inner();
+
+    let mut reader = <BitReader<E, _>>::new(MemWordReader::new(data.as_slice()));
+    for &n in &values {
+        assert_eq!(reader.read_gamma()?, n);
+        assert_eq!(reader.read_delta()?, n);
+        for k in (1..=5).filter(|&k| zeta_fits(n, k)) {
+            assert_eq!(reader.read_zeta(k)?, n, "n: {}, k: {}", n, k);
+        }
+    }
+    assert_eq!(reader.bit_pos()?, len as u64);
+
+    // ζ₁ is γ, so each code can be read as the other
+    let values: Vec<_> = values.into_iter().filter(|&n| zeta_fits(n, 1)).collect();
+    let mut writer = <BufBitWriter<E, _>>::new(MemWordWriterVec::new(Vec::<u64>::new()));
+    for &n in &values {
+        writer.write_zeta(n, 1)?;
+        writer.write_gamma(n)?;
+    }
+    let data = writer.into_inner()?.into_inner();
+    let data32: &[u32] = unsafe { data.align_to().1 };
+    let mut buf_reader = <BufBitReader<E, _>>::new(MemWordReader::new(data32));
+    let mut reader = <BitReader<E, _>>::new(MemWordReader::new(data.as_slice()));
+    for &n in &values {
+        assert_eq!(buf_reader.read_gamma()?, n);
+        assert_eq!(buf_reader.read_gamma()?, n);
+        assert_eq!(reader.read_zeta(1)?, n);
+        assert_eq!(reader.read_zeta(1)?, n);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_gamma_delta_zeta_be() -> Result<()> {
+    test_gamma_delta_zeta::<BE>()
+}
+
+#[test]
+fn test_gamma_delta_zeta_le() -> Result<()> {
+    test_gamma_delta_zeta::<LE>()
+}
+
+#[test]
+fn test_unbuffered_graph() -> Result<()> {
+    use epserde::prelude::MemCase;
+    use lender::*;
+    use webgraph::prelude::*;
+
+    // The same graph decoded by buffered and unbuffered readers
+    let basename = std::path::Path::new("tests/data/cnr-2000");
+    let (num_nodes, num_arcs, comp_flags) =
+        parse_properties::<BE>(basename.with_extension(PROPERTIES_EXTENSION))?;
+    let data = MmapHelper::<u64>::mmap(
+        basename.with_extension(GRAPH_EXTENSION),
+        MemoryFlags::SEQUENTIAL.into(),
+    )?;
+    let unbuffered = BVGraphSeq::new(
+        DynCodesDecoderFactory::<BE, _, _>::new(
+            data,
+            MemCase::from(EmptyDict::default()),
+            comp_flags,
+        )?,
+        num_nodes,
+        Some(num_arcs),
+        comp_flags.compression_window,
+        comp_flags.min_interval_length,
+    );
+    let buffered = BVGraphSeq::with_basename(basename)
+        .endianness::<BE>()
+        .load()?;
+
+    let mut num_arcs_read = 0;
+    for_!( (_node, succ, unbuffered_succ) in webgraph::labels::zip_nodes(&buffered, &unbuffered) {
+        let succ = succ.into_iter().collect::<Vec<_>>();
+        assert_eq!(succ, unbuffered_succ.into_iter().collect::<Vec<_>>());
+        num_arcs_read += succ.len() as u64;
+    });
+    assert_eq!(num_arcs_read, num_arcs);
+    Ok(())
+}
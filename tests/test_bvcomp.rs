@@ -15,7 +15,7 @@ use anyhow::Result;
 use dsi_bitstream::prelude::*;
 use dsi_progress_logger::prelude::*;
 use std::path::Path;
-use webgraph::{graphs::random::ErdosRenyi, prelude::*};
+use webgraph::{graphs::random::ErdosRenyi, labels::zip_nodes, prelude::*};
 use Code::{Delta, Gamma, Unary, Zeta};
 
 #[cfg_attr(feature = "slow_tests", test)]
@@ -143,6 +143,212 @@ where
     Ok(())
 }
 
+#[test]
+fn test_compression_parameters() -> Result<()> {
+    let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let tmp_dir = tempfile::tempdir()?;
+
+    let mut lengths = vec![];
+    for min_interval_length in [0, 4] {
+        let compression_flags = CompFlags {
+            min_interval_length,
+            residuals: Delta,
+            ..CompFlags::default()
+        };
+        let basename = tmp_dir.path().join(format!("cnr-{}", min_interval_length));
+        let bits = BVComp::single_thread::<BE, _>(
+            &basename,
+            &graph,
+            compression_flags,
+            false,
+            Some(NODES),
+        )?;
+
+        // The readers pick up the parameters from the .properties file
+        let properties = GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION))?;
+        assert_eq!(properties.comp_flags, compression_flags);
+        let compressed = BVGraphSeq::with_basename(&basename)
+            .endianness::<BE>()
+            .load()?;
+        for_!( (_, succ, compressed_succ) in zip_nodes(&graph, &compressed) {
+            assert!(itertools::equal(succ, compressed_succ));
+        });
+        lengths.push(bits);
+    }
+    assert_ne!(lengths[0], lengths[1]);
+    Ok(())
+}
+
+#[test]
+fn test_no_intervals_no_references() -> Result<()> {
+    // A random graph with some runs of consecutive successors, shared in
+    // part by consecutive nodes, so that both intervals and references are
+    // used when enabled
+    let mut graph = VecGraph::from_lender(ErdosRenyi::new(100, 0.1, 0).iter());
+    for u in (0..100).step_by(3) {
+        for v in u / 2..u / 2 + 8 {
+            graph.add_arc(u, v);
+            if u + 1 < 100 {
+                graph.add_arc(u + 1, v + 1);
+            }
+        }
+    }
+    let graph = webgraph::labels::proj::Left(graph);
+    let tmp_dir = tempfile::tempdir()?;
+
+    for compression_window in [0, 1, 7] {
+        for min_interval_length in [0, 1, 4] {
+            for max_ref_count in [0, 3] {
+                let compression_flags = CompFlags {
+                    compression_window,
+                    min_interval_length,
+                    max_ref_count,
+                    ..CompFlags::default()
+                };
+                let basename = tmp_dir.path().join(format!(
+                    "edge-{}-{}-{}",
+                    compression_window, min_interval_length, max_ref_count
+                ));
+                BVComp::single_thread::<BE, _>(
+                    &basename,
+                    &graph,
+                    compression_flags,
+                    true,
+                    Some(graph.num_nodes()),
+                )?;
+                load_or_build_ef(&basename)?;
+
+                // Sequential decoding
+                let seq_graph = BVGraphSeq::with_basename(&basename)
+                    .endianness::<BE>()
+                    .load()?;
+                for_!( (_, succ, compressed_succ) in zip_nodes(&graph, &seq_graph) {
+                    assert!(itertools::equal(succ, compressed_succ));
+                });
+
+                // Random-access decoding
+                let compressed = BVGraph::with_basename(&basename)
+                    .endianness::<BE>()
+                    .load()?;
+                let mut buf = vec![];
+                for node in 0..graph.num_nodes() {
+                    assert!(itertools::equal(
+                        graph.successors(node),
+                        compressed.successors(node)
+                    ));
+                    assert_eq!(graph.outdegree(node), compressed.outdegree(node));
+                    compressed.successors_into(node, &mut buf);
+                    assert!(itertools::equal(
+                        graph.successors(node),
+                        buf.iter().copied()
+                    ));
+                }
+
+                // Degree-only decoding, from the start and from the middle
+                let mut deg_iter = compressed.offset_deg_iter();
+                for node in 0..graph.num_nodes() {
+                    assert_eq!(deg_iter.next_degree()?, graph.outdegree(node));
+                }
+                let mut deg_iter = compressed.offset_deg_iter_from(50);
+                for node in 50..graph.num_nodes() {
+                    assert_eq!(deg_iter.next_degree()?, graph.outdegree(node));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_estimate_size() -> Result<()> {
+    let graph = webgraph::labels::proj::Left(VecGraph::from_arc_list([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 0),
+        (2, 1),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (4, 4),
+    ]));
+    let tmp_dir = tempfile::tempdir()?;
+    let basename = tmp_dir.path().join("tiny");
+
+    for compression_flags in [
+        CompFlags::default(),
+        CompFlags {
+            compression_window: 0,
+            residuals: Delta,
+            ..CompFlags::default()
+        },
+        CompFlags {
+            min_interval_length: 2,
+            outdegrees: Unary,
+            references: Gamma,
+            ..CompFlags::default()
+        },
+    ] {
+        let estimate = BVComp::estimate_size(&graph, compression_flags)?;
+        let bits =
+            BVComp::single_thread::<BE, _>(&basename, &graph, compression_flags, false, None)?;
+        assert_eq!(estimate, bits);
+
+        // The .graph file is padded to a multiple of the word size
+        let word_bits = usize::BITS as u64;
+        let len = std::fs::metadata(basename.with_extension(GRAPH_EXTENSION))?.len();
+        assert_eq!(len, estimate.div_ceil(word_bits) * word_bits / 8);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dry_run_cli() -> Result<()> {
+    use clap::FromArgMatches;
+    use webgraph::cli::to::bvgraph;
+
+    let dir = tempfile::tempdir()?;
+    let dst = dir.path().join("cnr-2000");
+    let dst = dst.to_str().unwrap();
+    for num_threads in ["1", "3"] {
+        let matches = bvgraph::cli(clap::Command::new("to")).try_get_matches_from([
+            "to",
+            "bvgraph",
+            "--dry-run",
+            "-j",
+            num_threads,
+            "tests/data/cnr-2000",
+        ])?;
+        let args =
+            bvgraph::CliArgs::from_arg_matches(matches.subcommand_matches("bvgraph").unwrap())?;
+        let estimate = bvgraph::estimate::<BE>(args, None)?;
+
+        webgraph::cli::main(vec![
+            "webgraph",
+            "to",
+            "bvgraph",
+            "-j",
+            num_threads,
+            "tests/data/cnr-2000",
+            dst,
+        ])?;
+        // The .graph file is padded to a multiple of the word size
+        let word_bits = usize::BITS as u64;
+        let len = std::fs::metadata(Path::new(dst).with_extension(GRAPH_EXTENSION))?.len();
+        assert_eq!(len, estimate.div_ceil(word_bits) * word_bits / 8);
+        // The estimate is exact
+        let graph = BVGraphSeq::with_basename(dst).endianness::<BE>().load()?;
+        let mut iter = graph.offset_deg_iter();
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.get_pos(), estimate);
+    }
+    Ok(())
+}
+
 pub struct EncoderValidator<E: Encode> {
     encoder: E,
     start_nodes: usize,
@@ -232,7 +438,8 @@ impl<E: Encode> Encode for EncoderValidator<E> {
 }
 
 impl<E: EncodeAndEstimate> EncodeAndEstimate for EncoderValidator<E> {
-    type Estimator<'a> = EncoderValidator<E::Estimator<'a>>
+    type Estimator<'a>
+        = EncoderValidator<E::Estimator<'a>>
     where
         Self: 'a;
 
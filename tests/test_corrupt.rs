@@ -0,0 +1,183 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use dsi_bitstream::prelude::*;
+use webgraph::graphs::bvgraph::sequential::Iter;
+use webgraph::prelude::*;
+
+const NUM_NODES: usize = 3;
+
+/// Writes the codes of a bitstream by hand and returns the result of decoding
+/// its nodes until the first error.
+fn decode(write: impl FnOnce(&mut dyn Encode<Error = std::convert::Infallible>)) -> Result<()> {
+    let mut iter = iter(write)?;
+    for _ in 0..NUM_NODES {
+        iter.next_successors()?;
+    }
+    Ok(())
+}
+
+/// Writes the codes of a bitstream by hand and returns an iterator on it.
+fn iter(
+    write: impl FnOnce(&mut dyn Encode<Error = std::convert::Infallible>),
+) -> Result<Iter<impl Decode>> {
+    let cf = CompFlags::default();
+    let writer = <BufBitWriter<BE, _>>::new(MemWordWriterVec::new(Vec::<u32>::new()));
+    let mut encoder = DynCodesEncoder::new(writer, &cf);
+    write(&mut encoder);
+    encoder.flush()?;
+    let mut words = encoder.into_inner().into_inner()?.into_inner();
+    // Padding, so that reading past the written codes does not fail
+    words.extend([u32::MAX; 4]);
+
+    let decoder = DynCodesDecoder::new(BufBitReader::<BE, _>::new(MemWordReader::new(words)), &cf)?;
+    Ok(Iter::new(
+        decoder,
+        NUM_NODES,
+        cf.compression_window,
+        cf.min_interval_length,
+    ))
+}
+
+#[test]
+fn test_well_formed() -> Result<()> {
+    // 0 -> 2, 1 -> (), 2 -> 0 1
+    decode(|e| {
+        e.write_outdegree(1).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(0).unwrap();
+        e.write_first_residual(int2nat(2)).unwrap();
+        e.write_outdegree(0).unwrap();
+        e.write_outdegree(2).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(0).unwrap();
+        e.write_first_residual(int2nat(-2)).unwrap();
+        e.write_residual(0).unwrap();
+    })
+}
+
+#[test]
+fn test_negative_residual() {
+    let err = decode(|e| {
+        e.write_outdegree(1).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(0).unwrap();
+        e.write_first_residual(int2nat(-1)).unwrap();
+    });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_outdegree_out_of_range() {
+    let err = decode(|e| {
+        e.write_outdegree(u32::MAX as u64).unwrap();
+    });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_residual_out_of_range() {
+    let err = decode(|e| {
+        e.write_outdegree(2).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(0).unwrap();
+        e.write_first_residual(int2nat(2)).unwrap();
+        e.write_residual(0).unwrap();
+    });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_interval_out_of_range() {
+    let err = decode(|e| {
+        e.write_outdegree(4).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(1).unwrap();
+        e.write_interval_start(int2nat(0)).unwrap();
+        e.write_interval_len(0).unwrap();
+    });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_reference_out_of_range() {
+    let err = decode(|e| {
+        e.write_outdegree(0).unwrap();
+        e.write_outdegree(1).unwrap();
+        e.write_reference_offset(2).unwrap();
+    });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_block_out_of_range() {
+    let err = decode(|e| {
+        e.write_outdegree(1).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(0).unwrap();
+        e.write_first_residual(int2nat(1)).unwrap();
+        e.write_outdegree(1).unwrap();
+        e.write_reference_offset(1).unwrap();
+        e.write_block_count(1).unwrap();
+        e.write_block(2).unwrap();
+    });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_try_next_inconsistent() -> Result<()> {
+    // The encoding of node 1 stops after its outdegree, so the rest of it is
+    // decoded from the padding: as all residuals are decoded from the same
+    // bits, the three successors cannot be 0 1 2
+    let mut iter = iter(|e| {
+        e.write_outdegree(1).unwrap();
+        e.write_reference_offset(0).unwrap();
+        e.write_interval_count(0).unwrap();
+        e.write_first_residual(int2nat(2)).unwrap();
+        e.write_outdegree(3).unwrap();
+    })?;
+    let (node_id, succ) = iter.try_next().unwrap()?;
+    assert_eq!(node_id, 0);
+    assert_eq!(succ.collect::<Vec<_>>(), vec![2]);
+    assert!(matches!(iter.try_next(), Some(Err(_))));
+    Ok(())
+}
+
+#[test]
+fn test_try_next_truncated() -> Result<()> {
+    let (num_nodes, _, cf) = parse_properties::<BE>("tests/data/cnr-2000.properties")?;
+    // The bitstream of cnr-2000, truncated halfway
+    let bytes = std::fs::read("tests/data/cnr-2000.graph")?;
+    let words = bytes[..bytes.len() / 2]
+        .chunks_exact(4)
+        .map(|w| u32::from_ne_bytes(w.try_into().unwrap()))
+        .collect::<Vec<_>>();
+
+    // The readers of the load modes extend the bitstream with zeros, so we
+    // use a strict reader, which returns an error at its end
+    let decoder = DynCodesDecoder::new(
+        BufBitReader::<BE, _>::new(MemWordReader::new_strict(words.as_slice())),
+        &cf,
+    )?;
+    let mut iter = Iter::new(
+        decoder,
+        num_nodes,
+        cf.compression_window,
+        cf.min_interval_length,
+    );
+    let mut node = 0;
+    loop {
+        match iter.try_next() {
+            Some(Ok((node_id, _))) => assert_eq!(node_id, node),
+            Some(Err(_)) => break,
+            None => panic!("The truncated bitstream was decoded up to its end"),
+        }
+        node += 1;
+    }
+    assert!(node < num_nodes);
+    Ok(())
+}
@@ -76,3 +76,5 @@ impl_fuzz_repr!(
     fuzz_bvcomp_and_read_zip,
     bvcomp_and_read
 );
+
+impl_fuzz_repr!(fuzz_decode, fuzz_decode_zip, decode);
@@ -32,6 +32,24 @@ fn test_iter_nodes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_outdegree() -> Result<()> {
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+
+    for node_id in 0..bvgraph.num_nodes() {
+        assert_eq!(
+            bvgraph.outdegree(node_id),
+            bvgraph.successors(node_id).count(),
+            "node_id: {}",
+            node_id
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_iter_nodes_from() -> Result<()> {
     let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
@@ -59,6 +77,164 @@ fn test_iter_nodes_from() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_try_iter_from() -> Result<()> {
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let bvgraph_seq = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let num_nodes = bvgraph.num_nodes();
+
+    assert!(bvgraph.try_iter_from(num_nodes + 1).is_err());
+    assert!(bvgraph_seq.try_iter_from(num_nodes + 1).is_err());
+    assert!(bvgraph.try_iter_from(num_nodes)?.next().is_none());
+    assert!(bvgraph_seq.try_iter_from(num_nodes)?.next().is_none());
+
+    let from = num_nodes - 10;
+    let mut iter = bvgraph.try_iter_from(from)?;
+    let mut seq_iter = bvgraph_seq.try_iter_from(from)?;
+    for node in from..num_nodes {
+        let (node_id, succ) = iter.next().unwrap();
+        assert_eq!(node_id, node);
+        let succ = succ.into_iter().collect::<Vec<_>>();
+        let (seq_node_id, seq_succ) = seq_iter.next().unwrap();
+        assert_eq!(seq_node_id, node);
+        assert_eq!(seq_succ.into_iter().collect::<Vec<_>>(), succ);
+    }
+    assert!(iter.next().is_none());
+    assert!(seq_iter.next().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_seq_iter_from_reference_chains() -> Result<()> {
+    // Nodes with similar successors, so that references form long chains
+    // whose intervals overlap
+    let mut arcs = vec![];
+    for node in 0..100 {
+        for succ in [1, 3, 5, 7, 11, 13] {
+            arcs.push((node, succ + node % 3));
+        }
+    }
+    let graph = Left(webgraph::graphs::vec_graph::VecGraph::from_arc_list(arcs));
+
+    let tmp_dir = tempfile::tempdir()?;
+    let basename = tmp_dir.path().join("chains");
+    for max_ref_count in [1, 3, usize::MAX] {
+        let compression_flags = CompFlags {
+            max_ref_count,
+            ..CompFlags::default()
+        };
+        BVComp::single_thread::<BE, _>(&basename, &graph, compression_flags, false, None)?;
+        let seq_graph = BVGraphSeq::with_basename(&basename)
+            .endianness::<BE>()
+            .load()?;
+        for from in [0, 1, 6, 7, 8, 50, 99, 100] {
+            let mut iter = seq_graph.iter_from(from);
+            for node in from..graph.num_nodes() {
+                let (node_id, succ) = iter.next().unwrap();
+                assert_eq!(node_id, node);
+                assert_eq!(
+                    succ.into_iter().collect::<Vec<_>>(),
+                    graph.successors(node).into_iter().collect::<Vec<_>>()
+                );
+            }
+            assert!(iter.next().is_none());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_iter_from_seek() -> Result<()> {
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let bvgraph_seq = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let num_nodes = bvgraph.num_nodes();
+
+    let mut expected = Vec::with_capacity(num_nodes);
+    for_!( (_, succ) in bvgraph_seq.iter() {
+        expected.push(succ.into_iter().collect::<Vec<_>>());
+    });
+
+    // Seeking must not disturb the references of the nodes right after
+    // the starting node, which might point before it
+    for from in [1, 6, 7, 8, 1000, num_nodes / 2, num_nodes - 1] {
+        let mut iter = bvgraph.iter_from(from);
+        for (node, succ) in expected.iter().enumerate().skip(from) {
+            let (node_id, seek_succ) = iter.next().unwrap();
+            assert_eq!(node_id, node);
+            assert_eq!(&seek_succ.into_iter().collect::<Vec<_>>(), succ);
+        }
+        assert!(iter.next().is_none());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_offset_deg_iter_from() -> Result<()> {
+    use rand::Rng;
+
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let num_nodes = bvgraph.num_nodes();
+    let expected = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?
+        .offset_deg_iter()
+        .collect::<Vec<_>>();
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    let froms = [0, 1, num_nodes - 1]
+        .into_iter()
+        .chain((0..10).map(|_| rng.gen_range(0..num_nodes)));
+    for from in froms {
+        let actual = bvgraph
+            .offset_deg_iter_from(from)
+            .take(1000)
+            .collect::<Vec<_>>();
+        let to = (from + 1000).min(num_nodes);
+        assert_eq!(actual, expected[from..to], "from: {}", from);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_len() -> Result<()> {
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let num_nodes = bvgraph.num_nodes();
+
+    let mut iter = bvgraph.iter();
+    assert_eq!(iter.len(), num_nodes);
+    assert_eq!(iter.size_hint(), (num_nodes, Some(num_nodes)));
+    for i in 1..=5 {
+        iter.next();
+        assert_eq!(iter.len(), num_nodes - i);
+    }
+
+    for from in [0, 1, 10, num_nodes - 1, num_nodes] {
+        assert_eq!(bvgraph.iter_from(from).len(), num_nodes - from);
+    }
+
+    let mut iter = bvgraph.iter_from(num_nodes - 1);
+    assert!(iter.next().is_some());
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+
+    Ok(())
+}
+
 #[test]
 fn test_split_iter_bv() -> Result<()> {
     let bvgraph_seq = BVGraphSeq::with_basename("tests/data/cnr-2000")
@@ -113,6 +289,29 @@ fn test_split_iter_arc_list() -> Result<()> {
     test_split_iter(&Left(arc_list_graph))
 }
 
+#[test]
+fn test_count_arcs() -> Result<()> {
+    let bvgraph_seq = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    assert_eq!(bvgraph_seq.num_arcs_hint(), Some(3_216_152));
+    assert_eq!(bvgraph_seq.count_arcs(), 3_216_152);
+
+    let arc_list_graph = Left(ArcListGraph::new(
+        bvgraph_seq.num_nodes(),
+        bvgraph_seq
+            .iter()
+            .map_into_iter(|(node_id, succ)| {
+                succ.into_iter().map(|s| (node_id, s)).collect::<Vec<_>>()
+            })
+            .flatten(),
+    ));
+    // No hint is available, so the arcs are counted by a scan
+    assert_eq!(arc_list_graph.num_arcs_hint(), None);
+    assert_eq!(arc_list_graph.count_arcs(), 3_216_152);
+    Ok(())
+}
+
 fn test_split_iter<'a, S: SequentialGraph + SplitLabeling>(g: &'a S) -> anyhow::Result<()>
 where
     <S as SplitLabeling>::SplitLender<'a>: Clone,
@@ -130,3 +329,286 @@ where
     assert!(iter.next().is_none(), "Too few nodes in split_iter");
     Ok(())
 }
+
+#[test]
+fn test_successors_filtered() -> Result<()> {
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let set = (0..bvgraph.num_nodes()).step_by(3).collect::<Vec<_>>();
+
+    for node_id in (0..bvgraph.num_nodes()).step_by(97) {
+        let expected = bvgraph
+            .successors(node_id)
+            .filter(|x| x % 3 == 0)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bvgraph
+                .successors_filtered(node_id, |x| x % 3 == 0)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            bvgraph.successors_in(node_id, &set).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_successors_into() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let mut buf = vec![usize::MAX; 10];
+    for node in 0..graph.num_nodes() {
+        let len = graph.successors_into(node, &mut buf);
+        assert_eq!(len, graph.outdegree(node));
+        assert_eq!(buf, graph.successors(node).collect::<Vec<_>>());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_successors_bitset() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let mut bits = sux::bits::BitVec::new(graph.num_nodes());
+    for node in (0..graph.num_nodes()).step_by(97) {
+        let len = graph.successors_into_bitset(node, &mut bits);
+        assert_eq!(len, graph.outdegree(node));
+        assert_eq!(
+            bits.iter_ones().collect::<Vec<_>>(),
+            graph.successors(node).collect::<Vec<_>>()
+        );
+        assert_eq!(graph.successors_bitset(node), bits);
+        // Unset the bits of the successors to reuse the bit vector
+        for succ in graph.successors(node) {
+            bits.set(succ, false);
+        }
+    }
+    assert!(std::panic::catch_unwind(|| {
+        graph.successors_into_bitset(0, &mut sux::bits::BitVec::new(0))
+    })
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_successor_gaps() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    // The cumulative sum of the gaps reconstructs the successors
+    let prefix_sums = |gaps: &mut dyn Iterator<Item = usize>| {
+        gaps.scan(0, |sum, gap| {
+            *sum += gap;
+            Some(*sum)
+        })
+        .collect::<Vec<_>>()
+    };
+
+    for node in (0..graph.num_nodes()).step_by(97) {
+        assert_eq!(graph.successor_gaps(node).len(), graph.outdegree(node));
+        assert_eq!(
+            prefix_sums(&mut graph.successor_gaps(node)),
+            graph.successors(node).collect::<Vec<_>>()
+        );
+    }
+
+    let seq_graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let mut iter = seq_graph.iter();
+    for node in 0..seq_graph.num_nodes() {
+        let gaps = prefix_sums(&mut iter.next_successor_gaps()?);
+        assert!(itertools::equal(gaps, graph.successors(node)));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_collect_arcs() {
+    use webgraph::graphs::vec_graph::VecGraph;
+
+    let arcs = vec![(0, 1), (0, 3), (1, 2), (2, 0), (2, 4), (4, 4)];
+    let graph = Left(VecGraph::from_arc_list(arcs.iter().copied()));
+    assert_eq!(collect_arcs(&graph), arcs);
+
+    // The order of the arc list does not matter
+    let mut shuffled = arcs.clone();
+    shuffled.reverse();
+    let graph = Left(VecGraph::from_arc_list(shuffled));
+    assert_eq!(collect_arcs(&graph), arcs);
+
+    let labeled = vec![(0, 2, 'a'), (1, 0, 'b'), (1, 2, 'c'), (3, 1, 'd')];
+    let graph = VecGraph::from_labeled_arc_list(labeled.iter().copied());
+    assert_eq!(collect_labeled_arcs(&graph), labeled);
+}
+
+#[test]
+fn test_file_mode() -> Result<()> {
+    // Reading the graph through a buffered file reader, without memory
+    // mapping, must give the same result as the memory-mapped graph
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let file_graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .mode::<webgraph::graphs::bvgraph::File>()
+        .load()?;
+    let file_seq_graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .mode::<webgraph::graphs::bvgraph::File>()
+        .load()?;
+    assert_eq!(file_seq_graph.num_nodes(), graph.num_nodes());
+
+    for_!( (node, succ) in file_seq_graph.iter() {
+        assert!(itertools::equal(succ, graph.successors(node)));
+    });
+    for node in (0..graph.num_nodes()).step_by(97) {
+        assert!(itertools::equal(
+            file_graph.successors(node),
+            graph.successors(node)
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_cached_successors() -> Result<()> {
+    use rand::Rng;
+
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let cached = CachedGraph::new(&bvgraph, 100);
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    for _ in 0..10_000 {
+        // Mostly a few hot nodes, so that there are both hits and evictions
+        let node = if rng.gen_bool(0.5) {
+            rng.gen_range(0..50)
+        } else {
+            rng.gen_range(0..bvgraph.num_nodes())
+        };
+        assert!(itertools::equal(
+            cached.successors(node),
+            bvgraph.successors(node)
+        ));
+        assert_eq!(cached.outdegree(node), bvgraph.outdegree(node));
+        assert!(cached.len() <= cached.capacity());
+    }
+    assert_eq!(cached.hits() + cached.misses(), 10_000);
+    assert!(cached.hits() > 0);
+    Ok(())
+}
+
+#[test]
+fn test_into_owned_iter() -> Result<()> {
+    let bvgraph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let owned = bvgraph.iter_from(1000).take(100).into_owned_iter();
+    assert_eq!(owned.size_hint(), (100, Some(100)));
+    for (i, (node, succ)) in owned.enumerate() {
+        assert_eq!(node, 1000 + i);
+        assert_eq!(succ, bvgraph.successors(node).collect::<Vec<_>>());
+    }
+    Ok(())
+}
+
+/// Counts the common elements of two sorted iterators by merging them.
+fn count_common(
+    mut i: impl SortedIterator<Item = usize>,
+    mut j: impl SortedIterator<Item = usize>,
+) -> usize {
+    let (mut x, mut y) = (i.next(), j.next());
+    let mut count = 0;
+    while let (Some(a), Some(b)) = (x, y) {
+        match a.cmp(&b) {
+            core::cmp::Ordering::Less => x = i.next(),
+            core::cmp::Ordering::Greater => y = j.next(),
+            core::cmp::Ordering::Equal => {
+                count += 1;
+                x = i.next();
+                y = j.next();
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn test_sorted_successors() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    // Random-access and sequential successors can be passed interchangeably
+    // where a sorted iterator is required
+    let mut iter = graph.iter();
+    while let Some((node, succ)) = iter.next() {
+        let degree = graph.outdegree(node);
+        assert_eq!(count_common(graph.successors(node), succ), degree);
+        if node > 1000 {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_par_for_each_node() -> Result<()> {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let num_nodes = graph.num_nodes();
+    // A hash of the successors of each node
+    let hash = |succ: &[usize]| {
+        succ.iter().fold(succ.len() as u64, |h, &s| {
+            h.wrapping_mul(31).wrapping_add(s as u64)
+        })
+    };
+    let mut expected = vec![0; num_nodes];
+    let mut iter = graph.iter();
+    while let Some((node, succ)) = iter.next() {
+        expected[node] = hash(&succ.collect::<Vec<_>>()) + 1;
+    }
+
+    for num_chunks in [1, 2, 7, 100, num_nodes + 3] {
+        let hashes = (0..num_nodes)
+            .map(|_| AtomicU64::new(0))
+            .collect::<Vec<_>>();
+        graph.par_for_each_node(num_chunks, |node, succ| {
+            // each node must be visited exactly once
+            let old = hashes[node].swap(hash(succ) + 1, Ordering::Relaxed);
+            assert_eq!(old, 0);
+        })?;
+        let hashes = hashes
+            .into_iter()
+            .map(AtomicU64::into_inner)
+            .collect::<Vec<_>>();
+        assert_eq!(hashes, expected, "{} chunks", num_chunks);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_first_successor() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    for node in 0..graph.num_nodes() {
+        assert_eq!(
+            graph.first_successor(node)?,
+            graph.successors(node).next(),
+            "node {}",
+            node
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+/*
+ * SPDX-FileCopyrightText: 2024 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use webgraph::utils::{int2nat, nat2int};
+
+#[test]
+fn test_nat2int_int2nat() {
+    // Values around zero and around the extremes, where sign handling and
+    // overflow might go wrong
+    for y in (-1000..=1000)
+        .chain(i64::MIN..=i64::MIN + 1000)
+        .chain(i64::MAX - 1000..=i64::MAX)
+    {
+        assert_eq!(nat2int(int2nat(y)), y);
+    }
+    for x in (0..=2000).chain(u64::MAX - 2000..=u64::MAX) {
+        assert_eq!(int2nat(nat2int(x)), x);
+    }
+
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..1_000_000 {
+        let x: u64 = rng.gen();
+        assert_eq!(int2nat(nat2int(x)), x);
+        let y: i64 = rng.gen();
+        assert_eq!(nat2int(int2nat(y)), y);
+    }
+}
+
+#[test]
+fn test_int2nat_zigzag() {
+    // Integers are interleaved by absolute value, nonnegative ones first
+    for y in -1000_i64..=1000 {
+        let expected = if y >= 0 { 2 * y } else { -2 * y - 1 };
+        assert_eq!(int2nat(y), expected as u64);
+    }
+    assert_eq!(int2nat(i64::MAX), u64::MAX - 1);
+    assert_eq!(int2nat(i64::MIN), u64::MAX);
+}
@@ -26,10 +26,10 @@ fn test_offsets() -> Result<()> {
 
     let mut offsets = Vec::with_capacity(graph.num_nodes());
     let mut reader = BufBitReader::<BE, _>::new(MemWordReader::new(&offsets_data));
-    let mut offset = 0;
+    let mut offset = 0_u64;
     for _ in 0..graph.num_nodes() + 1 {
-        offset += reader.read_gamma().unwrap() as usize;
-        offsets.push(offset as u64);
+        offset += reader.read_gamma().unwrap();
+        offsets.push(offset);
     }
     println!("{:?}", offsets.len());
 
@@ -64,3 +64,341 @@ fn test_offsets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_iter_degrees() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let seq_graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let ef_offsets = <webgraph::graphs::bvgraph::EF>::mmap(
+        "tests/data/cnr-2000.ef",
+        deser::Flags::TRANSPARENT_HUGE_PAGES,
+    )?;
+
+    let mut iter = seq_graph.iter_degrees();
+    assert_eq!(iter.len(), graph.num_nodes());
+    let mut next_node = 0;
+    for (offset, node_id, degree) in iter.by_ref() {
+        assert_eq!(node_id, next_node);
+        assert_eq!(offset, ef_offsets.get(node_id) as u64);
+        assert_eq!(degree, graph.outdegree(node_id));
+        next_node += 1;
+    }
+    assert_eq!(next_node, graph.num_nodes());
+    // After the last node, the position is the length of the bitstream
+    assert_eq!(iter.get_pos(), ef_offsets.get(graph.num_nodes()) as u64);
+
+    // The random-access graph returns the same triples
+    assert!(graph.iter_degrees().eq(seq_graph.iter_degrees()));
+    Ok(())
+}
+
+#[test]
+fn test_next_degree_and_ref_delta() -> Result<()> {
+    let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let mut degrees = graph.offset_deg_iter();
+    let mut iter = graph.offset_deg_iter();
+    let compression_window = iter.compression_window();
+    let mut num_references = 0;
+    for node in 0..graph.num_nodes() {
+        let (degree, ref_delta) = iter.next_degree_and_ref_delta()?;
+        assert_eq!(degree, degrees.next_degree()?);
+        assert!(ref_delta <= compression_window.min(node));
+        if degree == 0 {
+            assert_eq!(ref_delta, 0);
+        }
+        if ref_delta != 0 {
+            num_references += 1;
+        }
+    }
+    assert!(num_references > 0);
+    Ok(())
+}
+
+#[test]
+fn test_references_cli() -> Result<()> {
+    webgraph::cli::main(vec![
+        "webgraph",
+        "analyze",
+        "references",
+        "tests/data/cnr-2000",
+    ])
+}
+
+#[test]
+fn test_offsets_from_graph() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+
+    // Copy the graph without its offsets
+    let tmp_dir = tempfile::tempdir()?;
+    let basename = tmp_dir.path().join("cnr-2000");
+    for extension in [GRAPH_EXTENSION, PROPERTIES_EXTENSION] {
+        std::fs::copy(
+            std::path::Path::new("tests/data/cnr-2000").with_extension(extension),
+            basename.with_extension(extension),
+        )?;
+    }
+    assert!(BVGraph::with_basename(&basename)
+        .endianness::<BE>()
+        .load()
+        .is_err());
+
+    let mmap = BVGraph::with_basename(&basename)
+        .endianness::<BE>()
+        .offsets_from_graph()
+        .load()?;
+    let file = BVGraph::with_basename(&basename)
+        .endianness::<BE>()
+        .mode::<webgraph::graphs::bvgraph::File>()
+        .offsets_from_graph()
+        .load()?;
+    for node in (0..graph.num_nodes()).step_by(97) {
+        let succ = graph.successors(node).collect::<Vec<_>>();
+        assert_eq!(mmap.outdegree(node), succ.len());
+        assert_eq!(mmap.successors(node).collect::<Vec<_>>(), succ);
+        assert_eq!(file.successors(node).collect::<Vec<_>>(), succ);
+    }
+    // Offsets built in memory cannot be memory mapped
+    assert!(BVGraph::with_basename(&basename)
+        .endianness::<BE>()
+        .offsets_from_graph()
+        .offsets_mode::<Mmap>()
+        .load()
+        .is_err());
+    // No file has been written
+    assert!(!basename.with_extension(EF_EXTENSION).exists());
+    assert!(!basename.with_extension(OFFSETS_EXTENSION).exists());
+    Ok(())
+}
+
+#[test]
+fn test_peek_degree() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let mut iter = graph.offset_deg_iter();
+    let mut expected = Vec::with_capacity(graph.num_nodes());
+    for _ in 0..graph.num_nodes() {
+        expected.push(iter.next_degree()?);
+    }
+
+    // Peek once, twice, or not at all before each node
+    let mut iter = graph.offset_deg_iter();
+    for (node, &degree) in expected.iter().enumerate() {
+        let pos = iter.get_pos();
+        for _ in 0..node % 3 {
+            assert_eq!(iter.peek_degree()?, degree);
+            assert_eq!(iter.get_pos(), pos);
+        }
+        assert_eq!(iter.next_degree()?, degree);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bit_offset() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let ef_offsets = <webgraph::graphs::bvgraph::EF>::mmap(
+        "tests/data/cnr-2000.ef",
+        deser::Flags::TRANSPARENT_HUGE_PAGES,
+    )?;
+    for node in (0..=graph.num_nodes()).step_by(97) {
+        assert_eq!(graph.bit_offset(node), ef_offsets.get(node) as u64);
+    }
+
+    // The encodings of the nodes tile the bitstream, which is padded to a
+    // whole number of words
+    assert_eq!(graph.bit_offset(0), 0);
+    let total_bits = (0..graph.num_nodes())
+        .map(|node| graph.node_bit_len(node))
+        .sum::<u64>();
+    assert_eq!(total_bits, graph.bit_offset(graph.num_nodes()));
+    let graph_bits = std::fs::metadata("tests/data/cnr-2000.graph")?.len() * 8;
+    assert!(total_bits <= graph_bits && graph_bits - total_bits < 64);
+    Ok(())
+}
+
+#[test]
+fn test_delta_offsets() -> Result<()> {
+    use std::io::BufWriter;
+    use webgraph::cli::build::ef::{build_eliasfano, CliArgs};
+
+    let mut graph = VecGraph::new();
+    for i in 0..10 {
+        graph.add_node(i);
+    }
+    for (src, dst) in [
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (2, 0),
+        (2, 4),
+        (5, 9),
+        (7, 7),
+    ] {
+        graph.add_arc(src, dst);
+    }
+    let graph = Left(graph);
+
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("delta");
+    BVComp::in_memory::<BE>(&basename, &graph, CompFlags::default())?;
+
+    let seq_graph = BVGraphSeq::with_basename(&basename)
+        .endianness::<BE>()
+        .load()?;
+    let mut iter = seq_graph.offset_deg_iter();
+    let mut offsets: Vec<u64> = iter.by_ref().map(|(offset, _)| offset).collect();
+    offsets.push(iter.get_pos() as u64);
+
+    // Rewrite the offsets using δ codes, and declare it in the properties
+    let mut writer = <BufBitWriter<BE, _>>::new(<WordAdapter<u32, _>>::new(BufWriter::new(
+        std::fs::File::create(basename.with_extension(OFFSETS_EXTENSION))?,
+    )));
+    let mut prev = 0;
+    for &offset in &offsets {
+        writer.write_delta(offset - prev)?;
+        prev = offset;
+    }
+    writer.flush()?;
+    drop(writer);
+
+    let properties_path = basename.with_extension(PROPERTIES_EXTENSION);
+    let properties = std::fs::read_to_string(&properties_path)?;
+    std::fs::write(
+        &properties_path,
+        properties.replace("compressionflags=", "compressionflags=OFFSETS_DELTA|"),
+    )?;
+
+    build_eliasfano::<BE>(CliArgs {
+        src: basename.clone(),
+        n: None,
+        check: true,
+        num_threads: 3,
+    })?;
+
+    let ef = <webgraph::graphs::bvgraph::EF>::mmap(
+        basename.with_extension(EF_EXTENSION),
+        deser::Flags::empty(),
+    )?;
+    assert_eq!(ef.len(), offsets.len());
+    for (i, &offset) in offsets.iter().enumerate() {
+        assert_eq!(offset, ef.get(i) as u64);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_single_thread_offsets() -> Result<()> {
+    use webgraph::cli::build::ef::{build_eliasfano, CliArgs};
+
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("cnr-2000");
+    BVComp::single_thread::<BE, _>(
+        &basename,
+        &graph,
+        CompFlags::default(),
+        true,
+        Some(graph.num_nodes()),
+    )?;
+
+    // Build the Elias-Fano from the freshly written offsets and check it
+    // against the offsets obtained by scanning the graph
+    // Decode the offsets in parallel
+    build_eliasfano::<BE>(CliArgs {
+        src: basename.clone(),
+        n: None,
+        check: true,
+        num_threads: 4,
+    })?;
+
+    let ef = <webgraph::graphs::bvgraph::EF>::mmap(
+        basename.with_extension(EF_EXTENSION),
+        deser::Flags::empty(),
+    )?;
+    assert_eq!(ef.len(), graph.num_nodes() + 1);
+    let graph_bits = std::fs::metadata(basename.with_extension(GRAPH_EXTENSION))?.len() * 8;
+    let total_bits = ef.get(graph.num_nodes()) as u64;
+    assert!(total_bits <= graph_bits && graph_bits - total_bits < 64);
+
+    Ok(())
+}
+
+#[test]
+fn test_delta_label_offsets() -> Result<()> {
+    use std::io::BufWriter;
+    use webgraph::cli::build::ef::{build_eliasfano, CliArgs};
+
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("labels");
+    std::fs::write(basename.with_extension(LABELS_EXTENSION), [0_u8; 64])?;
+    std::fs::write(
+        basename.with_extension(PROPERTIES_EXTENSION),
+        "graphclass=it.unimi.dsi.webgraph.labelling.BitStreamArcLabelledImmutableGraph\n\
+         compressionflags=OFFSETS_DELTA\n",
+    )?;
+
+    // The label offsets are δ-coded, as declared in the properties
+    let offsets = [0_u64, 10, 35, 42, 500];
+    let mut writer = <BufBitWriter<BE, _>>::new(<WordAdapter<u32, _>>::new(BufWriter::new(
+        std::fs::File::create(basename.with_extension(LABELOFFSETS_EXTENSION))?,
+    )));
+    let mut prev = 0;
+    for &offset in &offsets {
+        writer.write_delta(offset - prev)?;
+        prev = offset;
+    }
+    writer.flush()?;
+    drop(writer);
+
+    build_eliasfano::<BE>(CliArgs {
+        src: basename.clone(),
+        n: Some(offsets.len()),
+        check: false,
+        num_threads: 1,
+    })?;
+
+    let ef = <webgraph::graphs::bvgraph::EF>::mmap(
+        basename.with_extension(EF_EXTENSION),
+        deser::Flags::empty(),
+    )?;
+    assert_eq!(ef.len(), offsets.len());
+    for (i, &offset) in offsets.iter().enumerate() {
+        assert_eq!(offset, ef.get(i) as u64);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_skip_successors() -> Result<()> {
+    let graph = BVGraphSeq::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let ef_offsets = <webgraph::graphs::bvgraph::EF>::mmap(
+        "tests/data/cnr-2000.ef",
+        deser::Flags::TRANSPARENT_HUGE_PAGES,
+    )?;
+    for k in [0, 1, 2, 1000, graph.num_nodes() - 1, graph.num_nodes()] {
+        let mut iter = graph.offset_deg_iter();
+        for _ in 0..k {
+            iter.skip_successors()?;
+        }
+        assert_eq!(iter.node_id(), k);
+        assert_eq!(iter.get_pos(), ef_offsets.get(k) as u64, "k = {}", k);
+    }
+    Ok(())
+}
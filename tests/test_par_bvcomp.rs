@@ -124,3 +124,87 @@ fn _test_par_bvcomp(basename: &str) -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_in_memory() -> Result<()> {
+    let graph = Left(webgraph::graphs::vec_graph::VecGraph::from_arc_list([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 0),
+        (2, 4),
+        (4, 0),
+        (4, 1),
+        (4, 2),
+        (4, 3),
+    ]));
+    let dir = tempfile::tempdir()?;
+    let in_memory = dir.path().join("in-memory");
+    let on_disk = dir.path().join("on-disk");
+
+    let bits = BVComp::in_memory::<BE>(&in_memory, &graph, CompFlags::default())?;
+    let expected_bits = BVComp::parallel_iter::<BE, _>(
+        &on_disk,
+        std::iter::once(graph.iter()),
+        graph.num_nodes(),
+        CompFlags::default(),
+        rayon::ThreadPoolBuilder::new().num_threads(1).build()?,
+        temp_dir(dir.path())?,
+    )?;
+    assert_eq!(bits, expected_bits);
+
+    for extension in [GRAPH_EXTENSION, OFFSETS_EXTENSION, PROPERTIES_EXTENSION] {
+        assert_eq!(
+            std::fs::read(in_memory.with_extension(extension))?,
+            std::fs::read(on_disk.with_extension(extension))?,
+            "{}",
+            extension
+        );
+    }
+
+    let comp_graph = webgraph::graphs::bvgraph::sequential::BVGraphSeq::with_basename(&in_memory)
+        .endianness::<BE>()
+        .load()?;
+    assert_eq!(
+        Left(webgraph::graphs::vec_graph::VecGraph::from_lender(
+            &comp_graph
+        )),
+        graph
+    );
+    Ok(())
+}
+
+#[test]
+fn test_num_arcs_check() -> Result<()> {
+    let graph = Left(webgraph::graphs::vec_graph::VecGraph::from_arc_list([
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (2, 0),
+        (3, 3),
+    ]));
+    let dir = tempfile::tempdir()?;
+    let basename = dir.path().join("graph");
+
+    BVComp::in_memory::<BE>(&basename, &graph, CompFlags::default())?;
+    let properties = GraphProperties::load(basename.with_extension(PROPERTIES_EXTENSION))?;
+    assert_eq!(properties.num_arcs, graph.num_arcs_hint().unwrap());
+
+    // A wrong number of arcs is an error, and no properties are written
+    let wrong = dir.path().join("wrong");
+    let err = BVComp::parallel_iter_checked::<BE, _>(
+        &wrong,
+        std::iter::once(graph.iter()),
+        graph.num_nodes(),
+        Some(4),
+        CompFlags::default(),
+        rayon::ThreadPoolBuilder::new().num_threads(1).build()?,
+        temp_dir(dir.path())?,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("5 arcs"), "{}", err);
+    assert!(!wrong.with_extension(PROPERTIES_EXTENSION).exists());
+    Ok(())
+}
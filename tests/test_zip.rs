@@ -8,7 +8,7 @@
 use lender::*;
 use webgraph::graphs::vec_graph::VecGraph;
 use webgraph::labels::proj::LeftIntoIter;
-use webgraph::labels::Zip;
+use webgraph::labels::{zip_nodes, Zip};
 use webgraph::traits::RandomAccessLabeling;
 
 #[test]
@@ -26,3 +26,26 @@ fn test_zip() {
         )
     }
 }
+
+#[test]
+fn test_zip_nodes() {
+    let v = VecGraph::from_arc_list([(0, 1), (0, 2), (1, 2), (2, 0), (3, 3)]);
+    let mut lender = zip_nodes(&v, &v);
+    let mut count = 0;
+    while let Some((x, a, b)) = lender.next() {
+        assert_eq!(x, count);
+        let a = a.into_iter().collect::<Vec<_>>();
+        assert_eq!(a, b.into_iter().collect::<Vec<_>>());
+        assert_eq!(a, v.labels(x).collect::<Vec<_>>());
+        count += 1;
+    }
+    assert_eq!(count, 4);
+
+    // The successor lists of the two graphs are independent
+    let t = VecGraph::from_arc_list([(1, 0), (2, 0), (2, 1), (0, 2), (3, 3)]);
+    let mut balance = vec![];
+    for_!( (_, succ, pred) in zip_nodes(&v, &t) {
+        balance.push(pred.into_iter().count() as isize - succ.into_iter().count() as isize);
+    });
+    assert_eq!(balance, vec![-1, 0, 1, 0]);
+}
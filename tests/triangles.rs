@@ -0,0 +1,63 @@
+mod common;
+
+use common::{random_edges, symmetric};
+use webgraph::algo::count_triangles;
+
+#[test]
+fn test_count_triangles() {
+    // A 4-clique on 0..4 (four triangles), the triangle 3 4 5 sharing node 3,
+    // a self-loop on 6 and the path 6 - 7 - 8
+    let graph = symmetric(
+        9,
+        &[
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (3, 5),
+            (4, 5),
+            (6, 6),
+            (6, 7),
+            (7, 8),
+        ],
+    );
+
+    assert_eq!(count_triangles(&graph, false), (5, None));
+    assert_eq!(
+        count_triangles(&graph, true),
+        (5, Some(vec![3, 3, 3, 4, 1, 1, 0, 0, 0]))
+    );
+}
+
+#[test]
+fn test_count_triangles_random() {
+    let n = 40;
+    let edges = random_edges(n, 0.2, 0);
+    let mut adj = vec![vec![false; n]; n];
+    for &(u, v) in &edges {
+        adj[u][v] = true;
+        adj[v][u] = true;
+    }
+
+    let mut expected = 0;
+    let mut expected_per_node = vec![0; n];
+    for u in 0..n {
+        for v in u + 1..n {
+            for w in v + 1..n {
+                if adj[u][v] && adj[v][w] && adj[u][w] {
+                    expected += 1;
+                    expected_per_node[u] += 1;
+                    expected_per_node[v] += 1;
+                    expected_per_node[w] += 1;
+                }
+            }
+        }
+    }
+
+    let (triangles, per_node) = count_triangles(&symmetric(n, &edges), true);
+    assert_eq!(triangles, expected);
+    assert_eq!(per_node, Some(expected_per_node));
+}
@@ -0,0 +1,181 @@
+use anyhow::Result;
+use clap::FromArgMatches;
+use dsi_bitstream::prelude::BE;
+use epserde::prelude::*;
+use lender::*;
+use webgraph::{
+    algo::{
+        component_sizes, large_components, partition_by_component, partition_large_components, wcc,
+    },
+    graphs::{bvgraph::BVGraph, vec_graph::VecGraph},
+    labels::proj::Left,
+    prelude::*,
+    transform::transpose,
+};
+
+#[test]
+fn test_wcc() -> Result<()> {
+    // 4 -> 0 -> 2    1 -> 5    3    6 -> 6
+    let mut graph = VecGraph::new();
+    for i in 0..=6 {
+        graph.add_node(i);
+    }
+    graph.add_arc(4, 0);
+    graph.add_arc(0, 2);
+    graph.add_arc(5, 1);
+    graph.add_arc(6, 6);
+    let graph = Left(graph);
+
+    let labels = wcc(&graph);
+    assert_eq!(labels, vec![0, 1, 0, 3, 0, 1, 6]);
+    assert_eq!(
+        component_sizes(&labels),
+        vec![(0, 3), (1, 2), (3, 1), (6, 1)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wcc_cnr_2000() -> Result<()> {
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let labels = wcc(&graph);
+    // Components do not depend on the direction of arcs
+    assert_eq!(labels, wcc(&transpose(&graph, 10_000)?));
+
+    let sizes = component_sizes(&labels);
+    assert_eq!(sizes.iter().map(|&(_, size)| size).sum::<usize>(), 325_557);
+    for (node, &label) in labels.iter().enumerate() {
+        assert!(label <= node);
+        assert_eq!(labels[label], label);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_partition_by_component() -> Result<()> {
+    // Components of sizes 4, 3, 2, 2 and 1
+    let labels = [0, 0, 0, 0, 4, 4, 4, 7, 7, 9, 9, 11];
+    assert_eq!(
+        partition_by_component(&labels, 2),
+        vec![0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1]
+    );
+    assert_eq!(partition_by_component(&labels, 1), vec![0; 12]);
+    // More shards than components
+    assert_eq!(
+        partition_by_component(&labels, 6),
+        vec![0, 0, 0, 0, 1, 1, 1, 2, 2, 3, 3, 4]
+    );
+
+    let graph = BVGraph::with_basename("tests/data/cnr-2000")
+        .endianness::<BE>()
+        .load()?;
+    let labels = wcc(&graph);
+    let shards = partition_by_component(&labels, 4);
+    let mut loads = [0; 4];
+    for (node, &shard) in shards.iter().enumerate() {
+        // Components are not split
+        assert_eq!(shard, shards[labels[node]]);
+        loads[shard] += 1;
+    }
+    assert_eq!(loads.iter().sum::<usize>(), 325_557);
+    Ok(())
+}
+
+#[test]
+fn test_large_components() {
+    let labels = [0, 1, 0, 3, 0, 1, 6];
+    assert_eq!(
+        large_components(&labels, 2),
+        vec![true, true, true, false, true, true, false]
+    );
+    assert_eq!(large_components(&labels, 4), vec![false; 7]);
+
+    // Components of sizes 4, 3, 2, 2 and 1
+    let labels = [0, 0, 0, 0, 4, 4, 4, 7, 7, 9, 9, 11];
+    assert_eq!(
+        partition_large_components(&labels, 2, 3),
+        vec![
+            0,
+            0,
+            0,
+            0,
+            1,
+            1,
+            1,
+            usize::MAX,
+            usize::MAX,
+            usize::MAX,
+            usize::MAX,
+            usize::MAX
+        ]
+    );
+    assert_eq!(
+        partition_large_components(&labels, 2, 1),
+        partition_by_component(&labels, 2)
+    );
+}
+
+#[test]
+fn test_min_size_cli() -> Result<()> {
+    // Components {0, 1, 2, 3}, {4, 5, 6}, {7, 8} and {9}
+    let graph = VecGraph::from_arc_list([(0, 1), (1, 2), (3, 2), (4, 5), (6, 5), (7, 8), (9, 9)]);
+    let tmp = tempfile::tempdir()?;
+    let basename = tmp.path().join("graph");
+    BVComp::single_thread::<BE, _>(&basename, &Left(graph), CompFlags::default(), false, None)?;
+    let basename = basename.to_str().unwrap();
+
+    // Components with less than three nodes are dropped
+    let induced = tmp.path().join("induced");
+    let induced = induced.to_str().unwrap();
+    let matches = webgraph::cli::analyze::wcc::cli(clap::Command::new("analyze"))
+        .try_get_matches_from([
+            "analyze",
+            "wcc",
+            "--min-size",
+            "3",
+            "--induced",
+            induced,
+            basename,
+        ])?;
+    let args = webgraph::cli::analyze::wcc::CliArgs::from_arg_matches(
+        matches.subcommand_matches("wcc").unwrap(),
+    )?;
+    assert_eq!(
+        webgraph::cli::analyze::wcc::wcc::<BE>(args)?,
+        vec![(0, 4), (4, 3)]
+    );
+
+    let induced = BVGraphSeq::with_basename(induced)
+        .endianness::<BE>()
+        .load()?;
+    assert_eq!(induced.num_nodes(), 7);
+    let mut arcs = vec![];
+    for_!( (src, succ) in induced.iter() {
+        arcs.extend(succ.into_iter().map(|dst| (src, dst)));
+    });
+    assert_eq!(arcs, vec![(0, 1), (1, 2), (3, 2), (4, 5), (6, 5)]);
+
+    let shards = tmp.path().join("shards");
+    let shards = shards.to_str().unwrap();
+    webgraph::cli::main(vec![
+        "webgraph",
+        "run",
+        "partition",
+        "--shards",
+        "2",
+        "--min-size",
+        "3",
+        "--epserde",
+        basename,
+        shards,
+    ])?;
+    assert_eq!(
+        <Vec<usize>>::load_full(shards)?,
+        vec![0, 0, 0, 0, 1, 1, 1, usize::MAX, usize::MAX, usize::MAX]
+    );
+    Ok(())
+}